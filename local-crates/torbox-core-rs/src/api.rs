@@ -24,6 +24,33 @@ where
 }
 
 impl<T> ApiResponse<T> {
+    /// Turns a `success == false` response into `Err(ApiError::Failure)`.
+    ///
+    /// The returned `ErrorValue` is the parsed `error` field, falling back to the `detail` message
+    /// when TorBox did not send an error code.
+    pub fn check(self) -> Result<Self, ApiError> {
+        if self.success {
+            return Ok(self);
+        }
+
+        let error = match self.error {
+            Some(ErrorValue::Bool(_)) | None => {
+                ErrorValue::Message(self.detail.unwrap_or_default())
+            }
+            Some(error) => error,
+        };
+
+        Err(ApiError::Failure(error))
+    }
+
+    /// Checks the response like [`check`](Self::check) and unwraps `data`.
+    ///
+    /// Returns `ApiError::UnexpectedPayload` if the request succeeded but no data was sent back,
+    /// use [`check`](Self::check) instead for endpoints that never return any data.
+    pub fn into_data(self) -> Result<T, ApiError> {
+        self.check()?.data.ok_or(ApiError::UnexpectedPayload)
+    }
+
     pub fn map<U, F: FnOnce(T) -> U>(self, f: F) -> ApiResponse<U> {
         ApiResponse {
             success: self.success,
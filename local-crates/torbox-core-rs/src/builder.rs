@@ -0,0 +1,213 @@
+use std::{sync::Arc, time::Duration};
+
+use reqwest::{Certificate, Client, ClientBuilder, Proxy};
+
+use crate::{
+    client::{TorboxClient, bearer},
+    error::ApiError,
+    network::{
+        constants::BASE_URL,
+        credentials::{CredentialProvider, InMemoryCredentials},
+        rate_limit::RateLimiter,
+        retry::RetryPolicy,
+    },
+};
+
+/// Default `User-Agent` sent by the SDK when none is configured.
+pub const DEFAULT_USER_AGENT: &str = concat!("torbox-rs/", env!("CARGO_PKG_VERSION"));
+
+/// Builder for [`TorboxClient`], created with [`TorboxClient::builder`].
+///
+/// Every option of the underlying `reqwest::Client` (timeouts, proxy, user agent, TLS) is applied on top of a
+/// client that never follows redirects, since download links are read from the `Location` header.
+///
+/// ```no_run
+/// # use std::time::Duration;
+/// # use torbox_core_rs::client::TorboxClient;
+/// let client = TorboxClient::builder("your_api_token")
+///     .user_agent("my-app/1.0")
+///     .connect_timeout(Duration::from_secs(5))
+///     .timeout(Duration::from_secs(30))
+///     .build()?;
+/// # Ok::<(), torbox_core_rs::error::ApiError>(())
+/// ```
+#[derive(Debug)]
+pub struct TorboxClientBuilder {
+    token: String,
+    credentials: Option<Arc<dyn CredentialProvider>>,
+    base_url: String,
+    user_agent: String,
+    connect_timeout: Option<Duration>,
+    timeout: Option<Duration>,
+    proxies: Vec<Proxy>,
+    no_proxy: bool,
+    root_certificates: Vec<Certificate>,
+    accept_invalid_certs: bool,
+    http_builder: Option<ClientBuilder>,
+    http_client: Option<Client>,
+    strict_errors: bool,
+    retry_policy: RetryPolicy,
+    rate_limiter: RateLimiter,
+}
+
+impl TorboxClientBuilder {
+    pub fn new(token: impl Into<String>) -> Self {
+        Self {
+            token: token.into(),
+            credentials: None,
+            base_url: BASE_URL.to_string(),
+            user_agent: DEFAULT_USER_AGENT.to_string(),
+            connect_timeout: None,
+            timeout: None,
+            proxies: Vec::new(),
+            no_proxy: false,
+            root_certificates: Vec::new(),
+            accept_invalid_certs: false,
+            http_builder: None,
+            http_client: None,
+            strict_errors: false,
+            retry_policy: RetryPolicy::none(),
+            rate_limiter: RateLimiter::new(),
+        }
+    }
+
+    /// Overrides the API base URL, `https://api.torbox.app/v1` by default.
+    pub fn base_url(mut self, base_url: impl Into<String>) -> Self {
+        self.base_url = base_url.into().trim_end_matches('/').to_string();
+        self
+    }
+
+    /// `User-Agent` sent with every request, it is nice to identify your app here.
+    pub fn user_agent(mut self, user_agent: impl Into<String>) -> Self {
+        self.user_agent = user_agent.into();
+        self
+    }
+
+    /// Timeout for establishing the connection only.
+    pub fn connect_timeout(mut self, timeout: Duration) -> Self {
+        self.connect_timeout = Some(timeout);
+        self
+    }
+
+    /// Timeout for the whole request, from connecting to reading the end of the body.
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Routes requests through a proxy. Can be called several times to add more proxies.
+    pub fn proxy(mut self, proxy: Proxy) -> Self {
+        self.proxies.push(proxy);
+        self
+    }
+
+    /// Ignores proxies set through environment variables (`HTTP_PROXY`, `HTTPS_PROXY`, ...).
+    pub fn no_proxy(mut self) -> Self {
+        self.no_proxy = true;
+        self
+    }
+
+    /// Trusts an additional root certificate, useful behind TLS intercepting proxies.
+    pub fn add_root_certificate(mut self, certificate: Certificate) -> Self {
+        self.root_certificates.push(certificate);
+        self
+    }
+
+    /// Disables certificate validation. Only meant for local testing.
+    pub fn danger_accept_invalid_certs(mut self, accept: bool) -> Self {
+        self.accept_invalid_certs = accept;
+        self
+    }
+
+    /// Starts from your own `reqwest::ClientBuilder` instead of a default one.
+    ///
+    /// The options of this builder are applied on top of it, and redirects are always disabled.
+    pub fn http_builder(mut self, builder: ClientBuilder) -> Self {
+        self.http_builder = Some(builder);
+        self
+    }
+
+    /// Uses a prebuilt `reqwest::Client` as is, every HTTP option of this builder is then ignored.
+    ///
+    /// The client **must** be built with `redirect(reqwest::redirect::Policy::none())`, otherwise
+    /// `request_download_link` with `redirect: true` cannot read the `Location` header.
+    pub fn http_client(mut self, client: Client) -> Self {
+        self.http_client = Some(client);
+        self
+    }
+
+    /// Gets the token from `credentials` before every request, instead of the one given to the builder.
+    ///
+    /// See [`CredentialProvider`].
+    pub fn credentials(mut self, credentials: Arc<dyn CredentialProvider>) -> Self {
+        self.credentials = Some(credentials);
+        self
+    }
+
+    /// See [`TorboxClient::with_strict_errors`].
+    pub fn strict_errors(mut self, strict: bool) -> Self {
+        self.strict_errors = strict;
+        self
+    }
+
+    /// See [`TorboxClient::with_retry_policy`].
+    pub fn retry_policy(mut self, policy: RetryPolicy) -> Self {
+        self.retry_policy = policy;
+        self
+    }
+
+    /// See [`TorboxClient::with_rate_limiter`].
+    pub fn rate_limiter(mut self, limiter: RateLimiter) -> Self {
+        self.rate_limiter = limiter;
+        self
+    }
+
+    pub fn build(self) -> Result<TorboxClient, ApiError> {
+        let credentials = match self.credentials {
+            Some(credentials) => credentials,
+            None => {
+                bearer(&self.token)?;
+                Arc::new(InMemoryCredentials::new(self.token))
+            }
+        };
+
+        let client = match self.http_client {
+            Some(client) => client,
+            None => {
+                let mut builder = self
+                    .http_builder
+                    .unwrap_or_default()
+                    .redirect(reqwest::redirect::Policy::none())
+                    .user_agent(self.user_agent)
+                    .danger_accept_invalid_certs(self.accept_invalid_certs);
+
+                if let Some(timeout) = self.connect_timeout {
+                    builder = builder.connect_timeout(timeout);
+                }
+                if let Some(timeout) = self.timeout {
+                    builder = builder.timeout(timeout);
+                }
+                if self.no_proxy {
+                    builder = builder.no_proxy();
+                }
+                for proxy in self.proxies {
+                    builder = builder.proxy(proxy);
+                }
+                for certificate in self.root_certificates {
+                    builder = builder.add_root_certificate(certificate);
+                }
+
+                builder.build()?
+            }
+        };
+
+        Ok(TorboxClient {
+            client,
+            credentials,
+            base_url: self.base_url,
+            strict_errors: self.strict_errors,
+            retry_policy: self.retry_policy,
+            rate_limiter: self.rate_limiter,
+        })
+    }
+}
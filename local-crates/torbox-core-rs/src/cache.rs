@@ -0,0 +1,83 @@
+use std::collections::{BTreeSet, HashMap};
+
+use futures::{StreamExt, TryStreamExt, stream};
+
+use crate::{
+    data::{
+        cache::{CacheAvailability, CacheInfo},
+        hash::InfoHash,
+    },
+    error::ApiError,
+};
+
+/// Number of hashes the `checkcached` endpoints accept per request, longer queries hit HTTP limits.
+pub const MAX_HASHES_PER_REQUEST: usize = 100;
+
+/// How `check_cached_all` splits its hashes into `checkcached` requests.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CacheCheck {
+    /// Number of hashes sent per request, capped to [`MAX_HASHES_PER_REQUEST`].
+    pub batch_size: usize,
+    /// Number of requests in flight at the same time, `4` by default.
+    pub concurrency: usize,
+    /// Also list the files of every cached download.
+    pub list_files: bool,
+}
+
+impl Default for CacheCheck {
+    fn default() -> Self {
+        Self {
+            batch_size: MAX_HASHES_PER_REQUEST,
+            concurrency: 4,
+            list_files: false,
+        }
+    }
+}
+
+impl CacheCheck {
+    pub fn with_batch_size(mut self, batch_size: usize) -> Self {
+        self.batch_size = batch_size;
+        self
+    }
+
+    pub fn with_concurrency(mut self, concurrency: usize) -> Self {
+        self.concurrency = concurrency;
+        self
+    }
+
+    pub fn with_list_files(mut self, list_files: bool) -> Self {
+        self.list_files = list_files;
+        self
+    }
+}
+
+/// Checks any number of hashes by splitting them into batches and merging the answers.
+///
+/// Duplicate hashes are only sent once. `fetch` is called with each batch and runs up to `concurrency` times
+/// at once, the first error is returned as soon as it arrives. Hashes that are not cached are missing from the map.
+pub async fn check_cached_batched<F, Fut>(
+    hashes: impl IntoIterator<Item = InfoHash>,
+    check: CacheCheck,
+    fetch: F,
+) -> Result<HashMap<InfoHash, CacheInfo>, ApiError>
+where
+    F: FnMut(Vec<InfoHash>) -> Fut,
+    Fut: Future<Output = Result<Option<CacheAvailability>, ApiError>>,
+{
+    let hashes: Vec<InfoHash> = hashes
+        .into_iter()
+        .collect::<BTreeSet<_>>()
+        .into_iter()
+        .collect();
+    let batch_size = check.batch_size.clamp(1, MAX_HASHES_PER_REQUEST);
+    let batches: Vec<Vec<InfoHash>> = hashes.chunks(batch_size).map(<[_]>::to_vec).collect();
+
+    stream::iter(batches)
+        .map(fetch)
+        .buffer_unordered(check.concurrency.max(1))
+        .try_fold(HashMap::new(), |mut cached, batch| async move {
+            cached.extend(batch.map(CacheAvailability::into_map).unwrap_or_default());
+            Ok(cached)
+        })
+        .await
+}
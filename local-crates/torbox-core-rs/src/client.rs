@@ -1,13 +1,17 @@
 use std::marker::PhantomData;
+use std::sync::Arc;
 
 use crate::api::ApiResponse;
 use crate::body::ToMultipart;
-use crate::error::ApiError;
+use crate::builder::TorboxClientBuilder;
+use crate::error::{ApiError, ApiErrorCode, ErrorValue};
+use crate::network::credentials::{CredentialProvider, InMemoryCredentials};
+use crate::network::rate_limit::{RateLimiter, parse_retry_after};
+use crate::network::retry::RetryPolicy;
 use crate::traits::FromBytes;
-use reqwest::header::{ACCEPT, AUTHORIZATION, CONTENT_TYPE, HeaderMap, HeaderName, HeaderValue};
-use reqwest::multipart::Form;
-use reqwest::{Client, Method};
-use serde::{Serialize, de::DeserializeOwned};
+use reqwest::header::{ACCEPT, AUTHORIZATION, CONTENT_TYPE, HeaderMap, HeaderValue};
+use reqwest::{Client, Method, RequestBuilder, StatusCode};
+use serde::{Deserialize, Serialize, de::DeserializeOwned};
 
 pub trait EndpointSpec {
     /// JSON body you send - Use `()` to not send anything.
@@ -48,32 +52,39 @@ impl<'c, S: EndpointSpec> Endpoint<'c, S> {
         S::Resp:,
         <S as EndpointSpec>::Resp: std::fmt::Debug,
     {
-        self.client.request(S::METHOD, url_suffix).await
+        let resp = self.client.request(S::METHOD, url_suffix).await?;
+        self.client.checked(resp)
     }
 
     pub async fn call_json(&self, body: S::Req) -> Result<ApiResponse<S::Resp>, ApiError> {
-        self.client
+        let resp = self
+            .client
             .request_with_json(S::METHOD, S::PATH, body)
-            .await
+            .await?;
+        self.client.checked(resp)
     }
 
     pub async fn call_query(&self, query: S::Req) -> Result<ApiResponse<S::Resp>, ApiError>
     where
         S::Req: Serialize,
     {
-        self.client
+        let resp = self
+            .client
             .request_with_query(S::METHOD, S::PATH, &query)
-            .await
+            .await?;
+        self.client.checked(resp)
     }
 
+    /// The form is built again from `body` each time the request is sent again after a HTTP 429.
     pub async fn call_multipart(&self, body: S::Req) -> Result<ApiResponse<S::Resp>, ApiError>
     where
-        S::Req: ToMultipart + Send + Sync,
+        S::Req: ToMultipart + Clone + Send + Sync,
     {
-        let form = body.to_multipart().await;
-        self.client
-            .request_multipart(S::METHOD, S::PATH, form)
-            .await
+        let resp = self
+            .client
+            .request_multipart(S::METHOD, S::PATH, body)
+            .await?;
+        self.client.checked(resp)
     }
 
     pub async fn call_query_json<Q, B>(
@@ -90,14 +101,16 @@ impl<'c, S: EndpointSpec> Endpoint<'c, S> {
             .client
             .client
             .request(S::METHOD, &url)
-            .headers(self.client.headers("application/json"))
+            .headers(self.client.headers("application/json")?)
             .query(&query)
             .json(&body);
 
-        let res = req.send().await?;
-        self.client
+        let res = self.client.send(&S::METHOD, S::PATH, req).await?;
+        let resp = self
+            .client
             .parse_response::<ApiResponse<S::Resp>>(res)
-            .await
+            .await?;
+        self.client.checked(resp)
     }
 
     pub async fn call_query_bytes(&self, query: S::Req) -> Result<Vec<u8>, ApiError>
@@ -105,14 +118,14 @@ impl<'c, S: EndpointSpec> Endpoint<'c, S> {
         S::Req: Serialize,
     {
         let url = format!("{}/{}", self.client.base_url, S::PATH);
-        let response = self
+        let req = self
             .client
             .client
             .request(S::METHOD, &url)
-            .headers(self.client.headers("application/json"))
-            .query(&query)
-            .send()
-            .await?;
+            .headers(self.client.headers("application/json")?)
+            .query(&query);
+
+        let response = self.client.send(&S::METHOD, S::PATH, req).await?;
 
         Ok(response.bytes().await?.to_vec())
     }
@@ -124,20 +137,19 @@ impl<'c, S: EndpointSpec> Endpoint<'c, S> {
     {
         let mut header_map = HeaderMap::new();
         header_map.insert(
-            CONTENT_TYPE, 
+            CONTENT_TYPE,
             HeaderValue::from_bytes(content_type)
                 .expect("Wrong CONTENT_TYPE, if you are unsure refer to the developer.mozilla.org documentation or use the provided CONTENT_XML or CONTENT_JSON constants")
         );
 
-        let res = self
+        let req = self
             .client
             .client
             .request(S::METHOD, format!("{}/{}", self.client.base_url, S::PATH))
             .headers(header_map)
-            .query(&query)
-            .send()
-            .await?;
+            .query(&query);
 
+        let res = self.client.send(&S::METHOD, S::PATH, req).await?;
         self.client.parse_response::<T>(res).await
     }
 }
@@ -148,21 +160,36 @@ pub struct TorboxClient {
     /// Client can be specta skipped because TorboxClient should NEVER be used in any frontend, type is only used to be able to derive the APIs built from it.
     #[cfg_attr(feature = "specta", specta(skip))]
     pub client: Client,
-    pub(crate) token: String,
+    /// Source of the API token, see [`CredentialProvider`].
+    #[cfg_attr(feature = "specta", specta(skip))]
+    pub(crate) credentials: Arc<dyn CredentialProvider>,
     pub base_url: String,
+    /// When enabled, every `Endpoint` call turns `success == false` into `Err(ApiError::Failure)`.
+    pub strict_errors: bool,
+    /// Retry behaviour for transient failures, see [`RetryPolicy`].
+    #[cfg_attr(feature = "specta", specta(skip))]
+    pub retry_policy: RetryPolicy,
+    /// Client-side rate limiting, shared between clones of this client. See [`RateLimiter`].
+    #[cfg_attr(feature = "specta", specta(skip))]
+    pub rate_limiter: RateLimiter,
 }
 
 impl TorboxClient {
+    /// Creates a client with the default settings.
+    ///
+    /// # Panics
+    ///
+    /// If the token is not a valid header value or the TLS backend cannot be initialized,
+    /// use [`TorboxClient::builder`] to handle these errors instead.
     pub fn new(token: String) -> Self {
-        let client = Client::builder()
-            .redirect(reqwest::redirect::Policy::none())
+        Self::builder(token)
             .build()
-            .unwrap();
-        Self {
-            client,
-            token,
-            base_url: "https://api.torbox.app/v1".to_string(),
-        }
+            .expect("Failed to build TorboxClient")
+    }
+
+    /// Starts a [`TorboxClientBuilder`] to configure timeouts, proxy, user agent, TLS and more.
+    pub fn builder(token: impl Into<String>) -> TorboxClientBuilder {
+        TorboxClientBuilder::new(token)
     }
 
     pub fn with_base_url(&self, new_base: impl Into<String>) -> Self {
@@ -171,8 +198,210 @@ impl TorboxClient {
         new
     }
 
-    pub fn token(&self) -> &str {
-        &self.token
+    /// Returns a client that reports API failures as `Err(ApiError::Failure)` instead of
+    /// `Ok(ApiResponse { success: false, .. })`, so responses can be used with `?`.
+    ///
+    /// Combine it with [`ApiResponse::into_data`] to get the payload directly.
+    pub fn with_strict_errors(&self, strict: bool) -> Self {
+        let mut new = self.clone();
+        new.strict_errors = strict;
+        new
+    }
+
+    /// Returns a client that retries transient failures according to `policy`.
+    pub fn with_retry_policy(&self, policy: RetryPolicy) -> Self {
+        let mut new = self.clone();
+        new.retry_policy = policy;
+        new
+    }
+
+    /// Returns a client that throttles its requests with `limiter`.
+    ///
+    /// The returned client and its future clones share the limiter's buckets.
+    pub fn with_rate_limiter(&self, limiter: RateLimiter) -> Self {
+        let mut new = self.clone();
+        new.rate_limiter = limiter;
+        new
+    }
+
+    /// Returns a client authenticated with `token`, keeping the other settings of this one.
+    ///
+    /// Fails with `ApiError::Config` if the token cannot be sent in a header.
+    pub fn with_token(&self, token: impl Into<String>) -> Result<Self, ApiError> {
+        let token = token.into();
+        bearer(&token)?;
+
+        Ok(self.with_credentials(Arc::new(InMemoryCredentials::new(token))))
+    }
+
+    /// Returns a client that gets its token from `credentials` before every request.
+    pub fn with_credentials(&self, credentials: Arc<dyn CredentialProvider>) -> Self {
+        let mut new = self.clone();
+        new.credentials = credentials;
+        new
+    }
+
+    /// The token the next request will be sent with.
+    pub fn token(&self) -> Result<String, ApiError> {
+        self.credentials.token()
+    }
+
+    /// Applies the client's error mode to a response, see [`with_strict_errors`](Self::with_strict_errors).
+    pub fn checked<T>(&self, resp: ApiResponse<T>) -> Result<ApiResponse<T>, ApiError> {
+        if self.strict_errors {
+            resp.check()
+        } else {
+            Ok(resp)
+        }
+    }
+
+    /// Sends a request to `path`, going through the client's [`RateLimiter`] and retrying it according to
+    /// its [`RetryPolicy`].
+    ///
+    /// HTTP 429 responses are waited out using their `Retry-After` header, and reported as
+    /// `ApiError::RateLimited` once the limiter gives up.
+    /// Requests whose body cannot be cloned (multipart uploads) are sent once and a 429 response is
+    /// returned as is, see [`request_multipart`](Self::request_multipart) which rebuilds them.
+    ///
+    /// A request rejected with `BAD_TOKEN` or `AUTH_ERROR` is sent again, once, with the token returned by
+    /// [`CredentialProvider::refresh`], if any.
+    pub async fn send(
+        &self,
+        method: &Method,
+        path: &str,
+        req: RequestBuilder,
+    ) -> Result<reqwest::Response, ApiError> {
+        let replay = req.try_clone();
+        let res = self.send_attempts(method, path, req).await?;
+
+        let (Some(replay), StatusCode::UNAUTHORIZED | StatusCode::FORBIDDEN) =
+            (replay, res.status())
+        else {
+            return Ok(res);
+        };
+        let (res, code) = Self::peek_error_code(res).await?;
+        if !matches!(code, Some(ApiErrorCode::BadToken | ApiErrorCode::AuthError)) {
+            return Ok(res);
+        }
+
+        let mut request = replay.build()?;
+        let Some(rejected) = request
+            .headers()
+            .get(AUTHORIZATION)
+            .and_then(|v| v.to_str().ok())
+            .and_then(|v| v.strip_prefix("Bearer "))
+            .map(str::to_string)
+        else {
+            return Ok(res);
+        };
+        let Some(token) = self.credentials.refresh(&rejected).await? else {
+            return Ok(res);
+        };
+
+        request.headers_mut().insert(AUTHORIZATION, bearer(&token)?);
+        let req = RequestBuilder::from_parts(self.client.clone(), request);
+        self.send_attempts(method, path, req).await
+    }
+
+    async fn send_attempts(
+        &self,
+        method: &Method,
+        path: &str,
+        req: RequestBuilder,
+    ) -> Result<reqwest::Response, ApiError> {
+        let policy = &self.retry_policy;
+        let limiter = &self.rate_limiter;
+        let mut attempt = 1;
+        let mut rate_limited = 0;
+
+        loop {
+            limiter.acquire(path).await;
+
+            let Some(next) = req.try_clone() else {
+                return Ok(req.send().await?);
+            };
+
+            let can_retry = attempt < policy.max_attempts;
+
+            match next.send().await {
+                Ok(res) if res.status() == StatusCode::TOO_MANY_REQUESTS => {
+                    self.pause_rate_limited(path, &res, &mut rate_limited)?;
+                    continue;
+                }
+                Ok(res) if can_retry && policy.should_retry_status(method, res.status()) => {}
+                Ok(res) if can_retry && !policy.retry_codes.is_empty() && policy.allows(method) => {
+                    let (res, code) = Self::peek_error_code(res).await?;
+                    match code {
+                        Some(code) if policy.should_retry_code(method, &code) => {}
+                        _ => return Ok(res),
+                    }
+                }
+                Ok(res) => return Ok(res),
+                Err(err) if can_retry && policy.should_retry_transport(method, &err) => {}
+                Err(err) => return Err(err.into()),
+            }
+
+            tokio::time::sleep(policy.backoff(attempt)).await;
+            attempt += 1;
+        }
+    }
+
+    /// Pauses the bucket of `path` for the `Retry-After` of a HTTP 429 response, or fails with
+    /// `ApiError::RateLimited` once the limiter gives up.
+    fn pause_rate_limited(
+        &self,
+        path: &str,
+        res: &reqwest::Response,
+        rate_limited: &mut u32,
+    ) -> Result<(), ApiError> {
+        let limiter = &self.rate_limiter;
+        let retry_after = parse_retry_after(res.headers());
+        let wait = retry_after.unwrap_or(limiter.default_retry_after);
+
+        if *rate_limited >= limiter.max_rate_limited_retries || wait > limiter.max_retry_after {
+            return Err(ApiError::RateLimited(retry_after));
+        }
+
+        limiter.pause(path, wait);
+        *rate_limited += 1;
+        Ok(())
+    }
+
+    /// Reads the `error` field of a JSON response without consuming it for the caller.
+    async fn peek_error_code(
+        res: reqwest::Response,
+    ) -> Result<(reqwest::Response, Option<ApiErrorCode>), ApiError> {
+        #[derive(Deserialize)]
+        struct ErrorProbe {
+            error: Option<ErrorValue>,
+        }
+
+        let is_json = res
+            .headers()
+            .get(CONTENT_TYPE)
+            .and_then(|v| v.to_str().ok())
+            .is_some_and(|v| v.starts_with("application/json"));
+
+        if !is_json {
+            return Ok((res, None));
+        }
+
+        let status = res.status();
+        let version = res.version();
+        let headers = res.headers().clone();
+        let bytes = res.bytes().await?;
+
+        let code = serde_json::from_slice::<ErrorProbe>(&bytes)
+            .ok()
+            .and_then(|probe| probe.error)
+            .and_then(|error| error.code().cloned());
+
+        let mut rebuilt = http::Response::new(bytes);
+        *rebuilt.status_mut() = status;
+        *rebuilt.version_mut() = version;
+        *rebuilt.headers_mut() = headers;
+
+        Ok((reqwest::Response::from(rebuilt), code))
     }
 
     async fn parse_response<T>(&self, res: reqwest::Response) -> Result<T, ApiError>
@@ -195,31 +424,36 @@ impl TorboxClient {
         }
     }
 
-    fn headers(&self, _content_type: &'static str) -> HeaderMap {
+    pub(crate) fn headers(&self, _content_type: &'static str) -> Result<HeaderMap, ApiError> {
         let mut headers = HeaderMap::new();
         headers.insert(ACCEPT, HeaderValue::from_static("application/json"));
-        headers.insert(
-            AUTHORIZATION,
-            HeaderValue::from_str(&format!("Bearer {}", self.token)).unwrap(),
-        );
-        headers
+        headers.insert(AUTHORIZATION, bearer(&self.token()?)?);
+        Ok(headers)
     }
 
-    pub async fn request_multipart<T: DeserializeOwned>(
+    /// Sends `body` as a multipart form, built again for each attempt since a form cannot be cloned.
+    pub async fn request_multipart<T: DeserializeOwned, B: ToMultipart + Clone>(
         &self,
         method: Method,
         endpoint: &str,
-        form: Form,
+        body: B,
     ) -> Result<T, ApiError> {
         let url = format!("{}/{}", self.base_url, endpoint);
-
-        let res = self
-            .client
-            .request(method, url)
-            .headers(self.headers("multipart/form-data"))
-            .multipart(form)
-            .send()
-            .await?;
+        let mut rate_limited = 0;
+
+        let res = loop {
+            let req = self
+                .client
+                .request(method.clone(), &url)
+                .headers(self.headers("multipart/form-data")?)
+                .multipart(body.clone().to_multipart().await);
+
+            let res = self.send(&method, endpoint, req).await?;
+            if res.status() != StatusCode::TOO_MANY_REQUESTS {
+                break res;
+            }
+            self.pause_rate_limited(endpoint, &res, &mut rate_limited)?;
+        };
 
         let text = res.text().await?;
 
@@ -232,12 +466,12 @@ impl TorboxClient {
         method: Method,
         endpoint: &str,
     ) -> Result<T, ApiError> {
-        let res = self
+        let req = self
             .client
-            .request(method, format!("{}/{}", self.base_url, endpoint))
-            .headers(self.headers("application/json"))
-            .send()
-            .await?;
+            .request(method.clone(), format!("{}/{}", self.base_url, endpoint))
+            .headers(self.headers("application/json")?);
+
+        let res = self.send(&method, endpoint, req).await?;
 
         self.parse_response::<T>(res).await
     }
@@ -248,13 +482,13 @@ impl TorboxClient {
         endpoint: &str,
         body: B,
     ) -> Result<T, ApiError> {
-        let res = self
+        let req = self
             .client
-            .request(method, format!("{}/{}", self.base_url, endpoint))
-            .headers(self.headers("application/json"))
-            .json(&body)
-            .send()
-            .await?;
+            .request(method.clone(), format!("{}/{}", self.base_url, endpoint))
+            .headers(self.headers("application/json")?)
+            .json(&body);
+
+        let res = self.send(&method, endpoint, req).await?;
 
         let text = res.text().await?;
 
@@ -268,13 +502,13 @@ impl TorboxClient {
         endpoint: &str,
         query: &Q,
     ) -> Result<T, ApiError> {
-        let res = self
+        let req = self
             .client
-            .request(method, format!("{}/{}", self.base_url, endpoint))
-            .headers(self.headers("application/json"))
-            .query(query)
-            .send()
-            .await?;
+            .request(method.clone(), format!("{}/{}", self.base_url, endpoint))
+            .headers(self.headers("application/json")?)
+            .query(query);
+
+        let res = self.send(&method, endpoint, req).await?;
 
         let text = res.text().await?;
         // eprintln!("Raw API response: {}", text);
@@ -283,3 +517,9 @@ impl TorboxClient {
         Ok(parsed)
     }
 }
+
+/// `Authorization` header value for `token`.
+pub(crate) fn bearer(token: &str) -> Result<HeaderValue, ApiError> {
+    HeaderValue::from_str(&format!("Bearer {token}"))
+        .map_err(|_| ApiError::Config("API token contains invalid header characters".into()))
+}
@@ -0,0 +1,53 @@
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::data::hash::InfoHash;
+
+/// A file of a cached download, only listed when `list_files` is requested.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "specta", derive(specta::Type))]
+pub struct CachedFile {
+    pub name: String,
+    pub size: u64,
+}
+
+/// A download TorBox already has cached, as returned by the torrent and usenet `checkcached` endpoints.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "specta", derive(specta::Type))]
+pub struct CacheInfo {
+    pub name: String,
+    pub size: u64,
+    pub hash: InfoHash,
+
+    #[serde(default)]
+    pub files: Vec<CachedFile>,
+}
+
+/// Data of a `checkcached` response, a list or an object keyed by hash depending on the requested format.
+///
+/// Hashes that are not cached are left out of both.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "specta", derive(specta::Type))]
+#[serde(untagged)]
+pub enum CacheAvailability {
+    List(Vec<CacheInfo>),
+    Object(HashMap<String, CacheInfo>),
+}
+
+impl CacheAvailability {
+    /// The cached downloads keyed by their hash, whatever the format.
+    pub fn into_map(self) -> HashMap<InfoHash, CacheInfo> {
+        self.into_list()
+            .into_iter()
+            .map(|info| (info.hash.clone(), info))
+            .collect()
+    }
+
+    pub fn into_list(self) -> Vec<CacheInfo> {
+        match self {
+            CacheAvailability::List(list) => list,
+            CacheAvailability::Object(map) => map.into_values().collect(),
+        }
+    }
+}
@@ -0,0 +1,215 @@
+use std::{fmt, str::FromStr};
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::error::ApiError;
+
+const BASE32_ALPHABET: &[u8; 32] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZ234567";
+
+/// A torrent info hash, or the MD5 TorBox identifies web and usenet downloads with.
+///
+/// Always stored as lowercase hex so hashes compare equal whatever case or encoding they were given in.
+/// Accepted inputs are hex encoded MD5 (32 characters), SHA1 (40) and SHA256 (64), and base32 encoded
+/// SHA1 (32 characters, as found in some magnet links). A 32 character string that is valid hex is read
+/// as an MD5.
+///
+/// Hashes built with [`new`](Self::new) or parsed are always valid. Hashes deserialized from an API response are
+/// normalized when valid and otherwise kept as sent, so one empty or odd hash does not fail a whole list, see
+/// [`is_valid`](Self::is_valid).
+///
+/// ```
+/// # use torbox_core_rs::data::hash::InfoHash;
+/// let hex: InfoHash = "A492F8B92A25B0399C87715FC228C864AC5A7BFB".parse().unwrap();
+/// let base32: InfoHash = "USJPROJKEWYDTHEHOFP4EKGIMSWFU673".parse().unwrap();
+///
+/// assert_eq!(hex, base32);
+/// assert_eq!(hex.as_str(), "a492f8b92a25b0399c87715fc228c864ac5a7bfb");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "specta", derive(specta::Type))]
+pub struct InfoHash(String);
+
+impl InfoHash {
+    pub fn new(hash: &str) -> Result<Self, ApiError> {
+        let hash = hash.trim();
+        let is_hex = hash.chars().all(|c| c.is_ascii_hexdigit());
+
+        match hash.len() {
+            32 | 40 | 64 if is_hex => Ok(Self(hash.to_ascii_lowercase())),
+            32 => Self::from_base32(hash),
+            _ => Err(invalid(hash)),
+        }
+    }
+
+    /// A hash from unpadded base32, which is always read as such even if it is also valid hex.
+    pub fn from_base32(hash: &str) -> Result<Self, ApiError> {
+        base32_decode(hash)
+            .and_then(|bytes| Self::from_bytes(&bytes).ok())
+            .ok_or_else(|| invalid(hash))
+    }
+
+    /// A hash from its raw digest, 16, 20 or 32 bytes long.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, ApiError> {
+        match bytes.len() {
+            16 | 20 | 32 => Ok(Self(hex(bytes))),
+            len => Err(ApiError::InvalidInput(format!(
+                "A {len} bytes digest is not an MD5, SHA1 or SHA256"
+            ))),
+        }
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// The raw digest.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        (0..self.0.len())
+            .step_by(2)
+            .filter_map(|i| u8::from_str_radix(&self.0[i..i + 2], 16).ok())
+            .collect()
+    }
+
+    /// Unpadded uppercase base32 of the digest.
+    pub fn to_base32(&self) -> String {
+        let mut out = String::new();
+        let mut buffer = 0u32;
+        let mut bits = 0;
+
+        for byte in self.to_bytes() {
+            buffer = (buffer << 8) | byte as u32;
+            bits += 8;
+            while bits >= 5 {
+                bits -= 5;
+                out.push(BASE32_ALPHABET[((buffer >> bits) & 31) as usize] as char);
+            }
+            buffer &= (1 << bits) - 1;
+        }
+        if bits > 0 {
+            out.push(BASE32_ALPHABET[((buffer << (5 - bits)) & 31) as usize] as char);
+        }
+
+        out
+    }
+
+    /// Whether the hash is a well formed MD5, SHA1 or SHA256, which is only false for hashes deserialized as is.
+    pub fn is_valid(&self) -> bool {
+        matches!(self.0.len(), 32 | 40 | 64)
+            && self.0.chars().all(|c| matches!(c, '0'..='9' | 'a'..='f'))
+    }
+
+    /// Whether this is a BitTorrent v1 (SHA1) info hash.
+    pub fn is_v1(&self) -> bool {
+        self.0.len() == 40
+    }
+
+    /// Whether this is a BitTorrent v2 (SHA256) info hash.
+    pub fn is_v2(&self) -> bool {
+        self.0.len() == 64
+    }
+}
+
+impl fmt::Display for InfoHash {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl FromStr for InfoHash {
+    type Err = ApiError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::new(s)
+    }
+}
+
+impl TryFrom<String> for InfoHash {
+    type Error = ApiError;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        Self::new(&value)
+    }
+}
+
+impl TryFrom<&str> for InfoHash {
+    type Error = ApiError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        Self::new(value)
+    }
+}
+
+impl From<InfoHash> for String {
+    fn from(hash: InfoHash) -> Self {
+        hash.0
+    }
+}
+
+impl AsRef<str> for InfoHash {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl PartialEq<str> for InfoHash {
+    fn eq(&self, other: &str) -> bool {
+        Self::new(other).is_ok_and(|other| *self == other)
+    }
+}
+
+impl PartialEq<&str> for InfoHash {
+    fn eq(&self, other: &&str) -> bool {
+        *self == **other
+    }
+}
+
+impl Serialize for InfoHash {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(&self.0)
+    }
+}
+
+impl<'de> Deserialize<'de> for InfoHash {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(Self::new(&s).unwrap_or(Self(s)))
+    }
+}
+
+fn invalid(hash: &str) -> ApiError {
+    ApiError::InvalidInput(format!(
+        "{hash:?} is not a hex MD5, SHA1 or SHA256, nor a base32 SHA1"
+    ))
+}
+
+fn hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{b:02x}")).collect()
+}
+
+/// RFC 4648 base32 without padding.
+fn base32_decode(input: &str) -> Option<Vec<u8>> {
+    let mut bytes = Vec::with_capacity(input.len() * 5 / 8);
+    let mut buffer = 0u32;
+    let mut bits = 0;
+
+    for c in input.bytes() {
+        let value = BASE32_ALPHABET
+            .iter()
+            .position(|a| *a == c.to_ascii_uppercase())? as u32;
+        buffer = (buffer << 5) | value;
+        bits += 5;
+        if bits >= 8 {
+            bits -= 8;
+            bytes.push((buffer >> bits) as u8);
+            buffer &= (1 << bits) - 1;
+        }
+    }
+
+    Some(bytes)
+}
@@ -8,6 +8,14 @@ pub struct ActiveLimitStatus {
     pub current_active_downloads: u64,
 }
 
+impl ActiveLimitStatus {
+    /// Number of downloads that can still be started before hitting `ACTIVE_LIMIT`.
+    pub fn available_slots(&self) -> u64 {
+        self.active_limit
+            .saturating_sub(self.current_active_downloads)
+    }
+}
+
 #[derive(Debug, Serialize, Deserialize)]
 #[cfg_attr(feature = "specta", derive(specta::Type))]
 pub struct MonthlyLimitStatus {
@@ -1,15 +1,22 @@
+pub mod cache;
 pub mod creation;
 pub mod general;
+pub mod hash;
 pub mod limits;
 pub mod notifications;
+pub mod queued;
+pub mod rss;
 pub mod torrent;
+pub mod usenet;
 pub mod user;
+pub mod vendor;
 pub mod webdownload;
 
 use crate::data::{
     limits::{ActiveLimitStatus, CooldownLimitStatus, MonthlyLimitStatus},
     torrent::TorrentCreationResponse,
     torrent::{TorrentData, TorrentMap, TorrentMeta, TorrentStatus},
+    usenet::{UsenetCreationResponse, UsenetStatus},
     user::{Job, UserProfile},
     webdownload::{HosterInfo, WebdownloadCreationResponse, WebdownloadStatus},
 };
@@ -33,4 +40,6 @@ pub enum ApiDataResponse {
     WebdownloadCreation(WebdownloadCreationResponse),
     WebdownloadList(Box<Vec<WebdownloadStatus>>),
     HosterInfoList(Box<Vec<HosterInfo>>),
+    UsenetCreation(UsenetCreationResponse),
+    UsenetList(Box<Vec<UsenetStatus>>),
 }
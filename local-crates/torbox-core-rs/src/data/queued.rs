@@ -0,0 +1,46 @@
+use chrono::{DateTime, FixedOffset};
+use serde::{Deserialize, Serialize};
+
+/// Kind of download a queued item becomes once started.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+#[cfg_attr(feature = "specta", derive(specta::Type))]
+pub enum QueuedType {
+    Torrent,
+    Usenet,
+    Webdl,
+}
+
+/// A download waiting for an active slot, whatever its kind.
+///
+/// Torrents come with their `magnet` or `torrent_file`, web and usenet downloads with their `link`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "specta", derive(specta::Type))]
+pub struct QueuedDownload {
+    pub id: u64,
+    pub created_at: DateTime<FixedOffset>,
+    pub auth_id: String,
+    #[serde(default)]
+    pub name: Option<String>,
+    #[serde(default)]
+    pub hash: Option<String>,
+    #[serde(rename = "type")]
+    pub queued_type: QueuedType,
+    #[serde(default)]
+    pub magnet: Option<String>,
+    /// Link to the uploaded `.torrent` file.
+    #[serde(default)]
+    pub torrent_file: Option<String>,
+    #[serde(default)]
+    pub link: Option<String>,
+}
+
+impl QueuedDownload {
+    /// The magnet, torrent file or link the download will be created from.
+    pub fn source(&self) -> Option<&str> {
+        self.magnet
+            .as_deref()
+            .or(self.link.as_deref())
+            .or(self.torrent_file.as_deref())
+    }
+}
@@ -0,0 +1,56 @@
+use chrono::{DateTime, FixedOffset};
+use serde::{Deserialize, Serialize};
+
+/// Kind of download created from the items of a feed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+#[cfg_attr(feature = "specta", derive(specta::Type))]
+pub enum RssType {
+    Torrent,
+    Usenet,
+    Webdl,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "specta", derive(specta::Type))]
+pub struct RssFeed {
+    pub id: u64,
+    pub created_at: DateTime<FixedOffset>,
+    pub updated_at: Option<DateTime<FixedOffset>>,
+    pub auth_id: String,
+    pub name: String,
+    pub url: String,
+    /// Items whose title matches this regex are downloaded.
+    pub do_regex: Option<String>,
+    /// Items whose title matches this regex are skipped, even if they match `do_regex`.
+    pub dont_regex: Option<String>,
+    /// Items older than this many days are skipped, `0` to not filter on age.
+    #[serde(default)]
+    pub dont_older_than: u32,
+    /// Skip items already downloaded by the user.
+    #[serde(default)]
+    pub pass_check: bool,
+    /// Minutes between two scans of the feed.
+    pub scan_interval: u32,
+    pub rss_type: RssType,
+    /// 1 is auto. 2 is seed. 3 is don't seed.
+    pub torrent_seeding: Option<u8>,
+    /// Whether the feed is scanned, `false` once paused.
+    pub active: bool,
+    pub last_checked_at: Option<DateTime<FixedOffset>>,
+    /// Last error met while scanning, if any.
+    pub status_message: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "specta", derive(specta::Type))]
+pub struct RssFeedItem {
+    pub id: u64,
+    pub created_at: DateTime<FixedOffset>,
+    pub rss_feed_id: u64,
+    pub title: String,
+    pub link: Option<String>,
+    /// Whether the item matched the filters and was added to the account.
+    #[serde(default)]
+    pub downloaded: bool,
+}
@@ -3,11 +3,13 @@ use std::collections::HashMap;
 use chrono::{DateTime, FixedOffset};
 use serde::{Deserialize, Serialize};
 
+use crate::data::hash::InfoHash;
+
 #[derive(Debug, Serialize, Deserialize)]
 #[cfg_attr(feature = "specta", derive(specta::Type))]
 pub struct TorrentStatus {
     pub id: u64,
-    pub hash: String,
+    pub hash: InfoHash,
     pub created_at: Option<DateTime<FixedOffset>>,
     pub updated_at: Option<DateTime<FixedOffset>>,
     pub magnet: Option<String>,
@@ -37,7 +39,7 @@ pub struct TorrentStatus {
 #[cfg_attr(feature = "specta", derive(specta::Type))]
 pub struct TorrentMeta {
     pub name: String,
-    pub hash: String,
+    pub hash: InfoHash,
     pub size: u64,
     pub trackers: Vec<String>,
     pub seeds: u64,
@@ -50,13 +52,16 @@ pub struct TorrentMeta {
 pub struct TorrentData {
     pub name: String,
     pub size: u64,
-    pub hash: String,
+    pub hash: InfoHash,
     pub files: Vec<TorrentFile>,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
 #[cfg_attr(feature = "specta", derive(specta::Type))]
 pub struct TorrentFile {
+    /// Id to request a download link with, only given for files of a torrent in your account.
+    #[serde(default)]
+    pub id: Option<u64>,
     pub name: String,
     pub size: f64,
     pub hash: Option<String>,
@@ -153,5 +158,5 @@ pub struct TorrentCreationResponse {
     #[serde(alias = "queued_id")]
     pub id: u64,
     pub auth_id: String,
-    pub hash: String,
+    pub hash: InfoHash,
 }
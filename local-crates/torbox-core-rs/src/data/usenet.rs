@@ -0,0 +1,59 @@
+use chrono::{DateTime, FixedOffset};
+use serde::{Deserialize, Serialize};
+
+use crate::data::hash::InfoHash;
+
+#[derive(Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "specta", derive(specta::Type))]
+pub struct UsenetCreationResponse {
+    #[serde(alias = "usenet_id")]
+    #[serde(alias = "queued_id")]
+    pub usenetdownload_id: u64,
+    pub hash: InfoHash,
+    pub auth_id: String,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "specta", derive(specta::Type))]
+pub struct UsenetFile {
+    pub id: u64,
+    pub md5: Option<String>,
+    pub s3_path: String,
+    pub name: String,
+    pub size: u64,
+    pub mimetype: String,
+    pub short_name: String,
+    pub absolute_path: String,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "specta", derive(specta::Type))]
+pub struct UsenetStatus {
+    pub id: u64,
+    pub hash: InfoHash,
+    pub created_at: Option<DateTime<FixedOffset>>,
+    pub updated_at: Option<DateTime<FixedOffset>>,
+    pub size: u64,
+    pub active: bool,
+    pub auth_id: String,
+    pub download_state: String,
+    pub progress: f64,
+    pub download_speed: u64,
+    #[serde(default)]
+    pub original_url: Option<String>,
+    pub name: String,
+    pub eta: u64,
+    pub server: Option<u64>,
+    pub expires_at: Option<DateTime<FixedOffset>>,
+    pub download_present: bool,
+    pub download_finished: bool,
+    #[serde(default)]
+    pub cached: bool,
+    pub cached_at: Option<DateTime<FixedOffset>>,
+    pub download_id: Option<String>,
+    #[serde(default)]
+    pub inactive_check: Option<u64>,
+    #[serde(default)]
+    pub availability: Option<f64>,
+    pub files: Vec<UsenetFile>,
+}
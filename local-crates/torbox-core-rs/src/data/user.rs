@@ -32,8 +32,163 @@ pub struct UserProfile {
     pub is_vendor: bool,
     pub vendor_id: Option<String>,
     pub purchases_referred: u64,
+    /// Only given when the profile is requested with its settings.
     #[serde(default)]
-    pub settings: Option<HashMap<String, Value>>,
+    pub settings: Option<UserSettings>,
+}
+
+/// Whether TorBox keeps seeding torrents once they are downloaded.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(from = "u8", into = "u8")]
+pub enum SeedPreference {
+    /// Seeds depending on the torrent's ratio and availability.
+    Auto,
+    Seed,
+    NoSeed,
+    /// A preference added to TorBox after this version of the SDK, sent back as is.
+    Unknown(u8),
+}
+
+/// Exported as the number sent over the wire rather than as its variants.
+#[cfg(feature = "specta")]
+impl specta::Type for SeedPreference {
+    fn inline(
+        type_map: &mut specta::TypeCollection,
+        generics: specta::Generics,
+    ) -> specta::DataType {
+        <u8 as specta::Type>::inline(type_map, generics)
+    }
+}
+
+impl From<SeedPreference> for u8 {
+    fn from(preference: SeedPreference) -> Self {
+        match preference {
+            SeedPreference::Auto => 1,
+            SeedPreference::Seed => 2,
+            SeedPreference::NoSeed => 3,
+            SeedPreference::Unknown(value) => value,
+        }
+    }
+}
+
+impl From<u8> for SeedPreference {
+    fn from(value: u8) -> Self {
+        match value {
+            1 => SeedPreference::Auto,
+            2 => SeedPreference::Seed,
+            3 => SeedPreference::NoSeed,
+            _ => SeedPreference::Unknown(value),
+        }
+    }
+}
+
+/// Account settings, as shown in the settings page of <https://torbox.app>.
+///
+/// Every field is optional: when sent to `UserApi::update_settings`, only the fields set are
+/// changed. Settings not modeled here are kept in `extra`, which is also how a setting is sent as
+/// `null` to clear it.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "specta", derive(specta::Type))]
+pub struct UserSettings {
+    /// Default seeding preference of new torrents.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub seed_torrents: Option<SeedPreference>,
+    /// Whether downloads of 100 files or more are zipped by default.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub allow_zipped: Option<bool>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub email_notifications: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub web_notifications: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub mobile_notifications: Option<bool>,
+    /// Notifies when an RSS feed adds a download.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub rss_notifications: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub discord_notifications: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub telegram_notifications: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub webhook_notifications: Option<bool>,
+    /// URL notifications are posted to when `webhook_notifications` is on.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub webhook_url: Option<String>,
+
+    /// Seeding preference of torrents added from Stremio.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub stremio_seed_torrents: Option<SeedPreference>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub stremio_allow_adult: Option<bool>,
+    /// Smallest file shown in Stremio, in bytes.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub stremio_size_lower: Option<u64>,
+    /// Largest file shown in Stremio, in bytes, 0 for no limit.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub stremio_size_upper: Option<u64>,
+
+    /// Settings not modeled above, as sent by TorBox.
+    #[serde(flatten)]
+    pub extra: HashMap<String, Value>,
+}
+
+/// Cloud provider an integration job uploads to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "specta", derive(specta::Type))]
+pub enum IntegrationKind {
+    #[serde(rename = "google")]
+    GoogleDrive,
+    #[serde(rename = "dropbox")]
+    Dropbox,
+    #[serde(rename = "onedrive")]
+    OneDrive,
+    #[serde(rename = "gofile")]
+    GoFile,
+    #[serde(rename = "1fichier")]
+    OneFichier,
+    /// A provider added to TorBox after this version of the SDK.
+    #[serde(other)]
+    Unknown,
+}
+
+/// Kind of download an integration job transfers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+#[cfg_attr(feature = "specta", derive(specta::Type))]
+pub enum JobItemType {
+    Torrent,
+    Usenet,
+    Webdl,
+    /// A kind of download added to TorBox after this version of the SDK.
+    #[serde(other)]
+    Unknown,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+#[cfg_attr(feature = "specta", derive(specta::Type))]
+pub enum JobStatus {
+    /// Waiting for a worker.
+    Pending,
+    /// Downloading the files from TorBox and uploading them to the provider.
+    Uploading,
+    Completed,
+    Failed,
+    Cancelled,
+    /// A status added to TorBox after this version of the SDK.
+    #[serde(other)]
+    Unknown,
+}
+
+impl JobStatus {
+    /// Whether the job will not progress anymore.
+    pub fn is_finished(&self) -> bool {
+        matches!(
+            self,
+            JobStatus::Completed | JobStatus::Failed | JobStatus::Cancelled
+        )
+    }
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -45,18 +200,19 @@ pub struct Job {
     pub auth_id: String,
     pub hash: String,
     #[serde(rename = "type")]
-    pub job_type: String,
-    pub integration: String,
+    pub job_type: JobItemType,
+    pub integration: IntegrationKind,
     #[serde(default)]
     pub file_id: Option<u64>,
     pub zip: bool,
     pub progress: f64,
     pub detail: String,
+    /// Link to the uploaded files on the provider, once completed.
     pub download_url: Option<String>,
-    pub status: String,
+    pub status: JobStatus,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 #[cfg_attr(feature = "specta", derive(specta::Type))]
 pub struct SessionToken {
     pub token: String,
@@ -67,13 +223,24 @@ pub struct SessionToken {
 #[cfg_attr(feature = "specta", derive(specta::Type))]
 pub struct DeviceCodeAuth {
     pub device_code: String,
+    /// Minimum number of seconds to wait between two token polls, `0` if not sent.
+    #[serde(default)]
     pub interval: u64,
-    pub expires_at: String,
+    pub expires_at: DateTime<FixedOffset>,
     pub verification_url: String,
     pub friendly_verification_url: String,
+    /// Code the user has to enter at `verification_url`.
     pub code: String,
 }
 
+/// Token granted once the user approved a device code.
+#[derive(Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "specta", derive(specta::Type))]
+pub struct DeviceToken {
+    pub access_token: String,
+    pub token_type: Option<String>,
+}
+
 #[derive(Debug, Serialize, Deserialize)]
 #[cfg_attr(feature = "specta", derive(specta::Type))]
 pub struct ReferralData {
@@ -121,4 +288,3 @@ pub struct SearchEngineData {
     pub download_type: String,
     pub indexers: Vec<String>,
 }
-
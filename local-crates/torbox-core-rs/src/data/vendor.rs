@@ -0,0 +1,61 @@
+use chrono::{DateTime, FixedOffset};
+use serde::{Deserialize, Serialize};
+
+/// The vendor (reseller) account of the user, see `UserProfile::is_vendor`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "specta", derive(specta::Type))]
+pub struct VendorAccount {
+    pub id: u64,
+    pub created_at: DateTime<FixedOffset>,
+    pub updated_at: Option<DateTime<FixedOffset>>,
+    pub auth_id: String,
+    pub vendor_name: String,
+    pub vendor_url: String,
+    /// URL TorBox posts account events to.
+    #[serde(default)]
+    pub vendor_webhook: Option<String>,
+    /// `active` or `disabled`, a disabled vendor gets `VENDOR_DISABLED` on every vendor endpoint.
+    pub status: String,
+    /// Number of accounts the vendor may create.
+    pub users_allowed: u64,
+    /// Number of accounts currently registered by the vendor.
+    pub current_users: u64,
+    /// Total bytes downloaded by the vendor's accounts.
+    #[serde(default)]
+    pub total_bytes_downloaded: u64,
+    /// Total bytes uploaded by the vendor's accounts.
+    #[serde(default)]
+    pub total_bytes_uploaded: u64,
+}
+
+impl VendorAccount {
+    /// Number of accounts that can still be registered.
+    pub fn remaining_users(&self) -> u64 {
+        self.users_allowed.saturating_sub(self.current_users)
+    }
+}
+
+/// An account registered by a vendor.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "specta", derive(specta::Type))]
+pub struct VendorUser {
+    pub id: u64,
+    pub created_at: DateTime<FixedOffset>,
+    pub auth_id: String,
+    pub email: String,
+    pub plan: u8,
+    #[serde(default)]
+    pub premium_expires_at: Option<DateTime<FixedOffset>>,
+    #[serde(default)]
+    pub total_bytes_downloaded: u64,
+    #[serde(default)]
+    pub total_bytes_uploaded: u64,
+}
+
+/// API token of a vendor account, returned on registration and refresh.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "specta", derive(specta::Type))]
+pub struct VendorUserToken {
+    pub auth_id: String,
+    pub token: String,
+}
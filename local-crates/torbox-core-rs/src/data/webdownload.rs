@@ -1,10 +1,12 @@
 use chrono::{DateTime, FixedOffset};
 use serde::{Deserialize, Serialize};
 
+use crate::data::hash::InfoHash;
+
 #[derive(Debug, Serialize, Deserialize)]
 #[cfg_attr(feature = "specta", derive(specta::Type))]
 pub struct WebdownloadCreationResponse {
-    pub hash: String,
+    pub hash: InfoHash,
     pub webdownload_id: u32,
     pub auth_id: String,
 }
@@ -28,7 +30,7 @@ pub struct WebdownloadFile {
 #[cfg_attr(feature = "specta", derive(specta::Type))]
 pub struct WebdownloadStatus {
     pub id: u64,
-    pub hash: String,
+    pub hash: InfoHash,
     pub created_at: DateTime<FixedOffset>,
     pub updated_at: DateTime<FixedOffset>,
     pub size: u64,
@@ -86,7 +88,7 @@ pub struct HosterInfo {
 pub struct WebDownloadCacheAvailability {
     pub name: String,
     pub size: u64,
-    pub hash: String,
+    pub hash: InfoHash,
 
     pub files: Option<WebdownloadFile>,
 }
@@ -0,0 +1,399 @@
+use std::path::{Path, PathBuf};
+
+use reqwest::{
+    StatusCode,
+    header::{CONTENT_RANGE, LOCATION, RANGE},
+};
+use tokio::{
+    fs::{self, OpenOptions},
+    io::{AsyncReadExt, AsyncWriteExt},
+};
+
+use crate::{
+    client::TorboxClient,
+    data::{usenet::UsenetFile, webdownload::WebdownloadFile},
+    error::ApiError,
+    link::LinkBuilder,
+};
+
+/// Maximum number of CDN redirects followed before giving up.
+const MAX_REDIRECTS: usize = 5;
+
+/// The item a [`DownloadTarget`] points to.
+#[derive(Debug, Clone, PartialEq)]
+pub enum DownloadSource {
+    Torrent {
+        torrent_id: u32,
+    },
+    Webdownload {
+        web_id: u32,
+    },
+    Usenet {
+        usenet_id: u32,
+    },
+    /// A link you already requested, no call is made to the API.
+    Url(String),
+}
+
+impl DownloadSource {
+    /// `requestdl` path, id parameter name and id of the source, `None` for plain URLs.
+    pub(crate) fn request_link(&self) -> Option<(&'static str, &'static str, u32)> {
+        match self {
+            DownloadSource::Torrent { torrent_id } => {
+                Some(("api/torrents/requestdl", "torrent_id", *torrent_id))
+            }
+            DownloadSource::Webdownload { web_id } => {
+                Some(("api/webdl/requestdl", "web_id", *web_id))
+            }
+            DownloadSource::Usenet { usenet_id } => {
+                Some(("api/usenet/requestdl", "usenet_id", *usenet_id))
+            }
+            DownloadSource::Url(_) => None,
+        }
+    }
+}
+
+/// A file (or a whole item as a zip) to download with a [`Downloader`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct DownloadTarget {
+    pub source: DownloadSource,
+    /// The file to download, the whole item is downloaded as a zip when `None`.
+    pub file_id: Option<u64>,
+    /// Size checked once the download is over.
+    pub expected_size: Option<u64>,
+    /// Hex encoded MD5 checked once the download is over.
+    pub md5: Option<String>,
+}
+
+impl DownloadTarget {
+    fn new(source: DownloadSource, file_id: Option<u64>) -> Self {
+        Self {
+            source,
+            file_id,
+            expected_size: None,
+            md5: None,
+        }
+    }
+
+    pub fn torrent(torrent_id: u32, file_id: Option<u64>) -> Self {
+        Self::new(DownloadSource::Torrent { torrent_id }, file_id)
+    }
+
+    pub fn webdownload(web_id: u32, file_id: Option<u64>) -> Self {
+        Self::new(DownloadSource::Webdownload { web_id }, file_id)
+    }
+
+    pub fn usenet(usenet_id: u32, file_id: Option<u64>) -> Self {
+        Self::new(DownloadSource::Usenet { usenet_id }, file_id)
+    }
+
+    pub fn url(url: impl Into<String>) -> Self {
+        Self::new(DownloadSource::Url(url.into()), None)
+    }
+
+    /// A web download file, verified against its size and MD5.
+    pub fn webdownload_file(web_id: u32, file: &WebdownloadFile) -> Self {
+        Self {
+            expected_size: Some(file.size),
+            md5: file.md5.clone(),
+            ..Self::webdownload(web_id, Some(file.id))
+        }
+    }
+
+    /// A usenet file, verified against its size and MD5.
+    pub fn usenet_file(usenet_id: u32, file: &UsenetFile) -> Self {
+        Self {
+            expected_size: Some(file.size),
+            md5: file.md5.clone(),
+            ..Self::usenet(usenet_id, Some(file.id))
+        }
+    }
+
+    pub fn with_expected_size(mut self, size: u64) -> Self {
+        self.expected_size = Some(size);
+        self
+    }
+
+    pub fn with_md5(mut self, md5: impl Into<String>) -> Self {
+        self.md5 = Some(md5.into());
+        self
+    }
+}
+
+/// Progress reported while a file is written to disk.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DownloadProgress {
+    /// Bytes on disk, including the ones of a resumed download.
+    pub downloaded: u64,
+    /// Total size when known from the target or the CDN headers.
+    pub total: Option<u64>,
+}
+
+/// Outcome of a finished download.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DownloadReport {
+    pub path: PathBuf,
+    pub size: u64,
+    /// Bytes that were already on disk from a previous attempt.
+    pub resumed_from: u64,
+    /// Whether an MD5 was available and matched.
+    pub md5_verified: bool,
+}
+
+/// Downloads TorBox files to disk.
+///
+/// The file is first written to `<dest>.part`, which is resumed with an HTTP `Range` request if it already exists
+/// or if the transfer is interrupted, and renamed to `dest` once its size and MD5 are verified.
+///
+/// ```no_run
+/// # use torbox_core_rs::{client::TorboxClient, download::{Downloader, DownloadTarget}};
+/// # async fn run(client: TorboxClient) -> Result<(), torbox_core_rs::error::ApiError> {
+/// let report = Downloader::new(&client)
+///     .download(&DownloadTarget::torrent(42, Some(0)), "ubuntu.iso", |p| {
+///         println!("{} / {:?}", p.downloaded, p.total);
+///     })
+///     .await?;
+/// # Ok(())
+/// # }
+/// ```
+///
+/// To receive progress on a channel instead, send from the callback: `|p| { tx.send(p).ok(); }`.
+pub struct Downloader<'a> {
+    client: &'a TorboxClient,
+    max_resume_attempts: u32,
+}
+
+impl<'a> Downloader<'a> {
+    pub fn new(client: &'a TorboxClient) -> Self {
+        Self {
+            client,
+            max_resume_attempts: 3,
+        }
+    }
+
+    /// How many times an interrupted transfer is resumed before failing, `3` by default.
+    pub fn with_max_resume_attempts(mut self, attempts: u32) -> Self {
+        self.max_resume_attempts = attempts;
+        self
+    }
+
+    /// Requests a download link for the target, without following it.
+    pub async fn resolve_link(&self, target: &DownloadTarget) -> Result<String, ApiError> {
+        let builder = LinkBuilder::new(self.client, target.source.clone());
+        match target.file_id {
+            Some(file_id) => builder.file(file_id).link().await,
+            None => builder.link().await,
+        }
+    }
+
+    /// Downloads `target` to `dest`, calling `on_progress` after each chunk written.
+    pub async fn download(
+        &self,
+        target: &DownloadTarget,
+        dest: impl AsRef<Path>,
+        mut on_progress: impl FnMut(DownloadProgress),
+    ) -> Result<DownloadReport, ApiError> {
+        let dest = dest.as_ref();
+        let part = part_path(dest);
+        let url = self.resolve_link(target).await?;
+
+        let resumed_from = match fs::metadata(&part).await {
+            Ok(meta) => meta.len(),
+            Err(_) => 0,
+        };
+        let mut transfer = Transfer {
+            written: resumed_from,
+            total: target.expected_size,
+        };
+        let mut resumes = 0;
+
+        loop {
+            match self
+                .fetch(&url, &part, &mut transfer, &mut on_progress)
+                .await
+            {
+                Ok(()) => break,
+                Err(ApiError::Transport(_)) if resumes < self.max_resume_attempts => {
+                    resumes += 1;
+                }
+                Err(err) => return Err(err),
+            }
+        }
+
+        let size = fs::metadata(&part).await?.len();
+        if let Some(expected) = target.expected_size.or(transfer.total)
+            && size != expected
+        {
+            fs::remove_file(&part).await.ok();
+            return Err(ApiError::SizeMismatch {
+                expected,
+                actual: size,
+            });
+        }
+
+        let md5_verified = match &target.md5 {
+            Some(expected) => {
+                let actual = file_md5(&part).await?;
+                if !actual.eq_ignore_ascii_case(expected) {
+                    fs::remove_file(&part).await.ok();
+                    return Err(ApiError::ChecksumMismatch {
+                        expected: expected.clone(),
+                        actual,
+                    });
+                }
+                true
+            }
+            None => false,
+        };
+
+        fs::rename(&part, dest).await?;
+
+        Ok(DownloadReport {
+            path: dest.to_path_buf(),
+            size,
+            resumed_from,
+            md5_verified,
+        })
+    }
+
+    /// Appends the rest of the file to `part`, starting at `transfer.written`.
+    async fn fetch(
+        &self,
+        url: &str,
+        part: &Path,
+        transfer: &mut Transfer,
+        on_progress: &mut impl FnMut(DownloadProgress),
+    ) -> Result<(), ApiError> {
+        let mut response = self.get_following_redirects(url, transfer.written).await?;
+
+        if response.status() == StatusCode::RANGE_NOT_SATISFIABLE && transfer.written > 0 {
+            // Nothing left to send, if the `.part` has exactly the size of the file.
+            let total = content_range_total(&response);
+            if total == Some(transfer.written) {
+                transfer.total = total;
+                return Ok(());
+            }
+
+            // The `.part` is larger than the file, so it belongs to another one: start over.
+            transfer.written = 0;
+            response = self.get_following_redirects(url, 0).await?;
+        }
+
+        let append = match response.status() {
+            StatusCode::PARTIAL_CONTENT => {
+                if let Some(total) = content_range_total(&response) {
+                    transfer.total = Some(total);
+                }
+                true
+            }
+            StatusCode::OK => {
+                // The CDN ignored the range, start over.
+                transfer.written = 0;
+                if let Some(len) = response.content_length() {
+                    transfer.total = Some(len);
+                }
+                false
+            }
+            status => {
+                return Err(ApiError::Download(format!(
+                    "CDN answered with HTTP {status}"
+                )));
+            }
+        };
+
+        let mut file = OpenOptions::new()
+            .create(true)
+            .write(true)
+            .append(append)
+            .truncate(!append)
+            .open(part)
+            .await?;
+
+        while let Some(chunk) = response.chunk().await? {
+            file.write_all(&chunk).await?;
+            transfer.written += chunk.len() as u64;
+            on_progress(DownloadProgress {
+                downloaded: transfer.written,
+                total: transfer.total,
+            });
+        }
+
+        file.flush().await?;
+        Ok(())
+    }
+
+    /// The client never follows redirects on its own, so CDN redirects are followed here, resolving
+    /// relative `Location` headers against the URL that answered.
+    async fn get_following_redirects(
+        &self,
+        url: &str,
+        offset: u64,
+    ) -> Result<reqwest::Response, ApiError> {
+        let mut url = url.to_string();
+
+        for _ in 0..=MAX_REDIRECTS {
+            let mut request = self.client.client.get(&url);
+            if offset > 0 {
+                request = request.header(RANGE, format!("bytes={offset}-"));
+            }
+
+            let response = request.send().await?;
+            if !response.status().is_redirection() {
+                return Ok(response);
+            }
+
+            let location = response
+                .headers()
+                .get(LOCATION)
+                .and_then(|v| v.to_str().ok())
+                .ok_or(ApiError::RedirectError("Missing Location header".into()))?;
+            url = response
+                .url()
+                .join(location)
+                .map_err(|e| ApiError::RedirectError(format!("Invalid Location header: {e}")))?
+                .to_string();
+        }
+
+        Err(ApiError::RedirectError("Too many redirects".into()))
+    }
+}
+
+struct Transfer {
+    written: u64,
+    total: Option<u64>,
+}
+
+fn part_path(dest: &Path) -> PathBuf {
+    let mut name = dest.as_os_str().to_os_string();
+    name.push(".part");
+    PathBuf::from(name)
+}
+
+/// Reads the total size out of a `Content-Range: bytes 0-99/1234` header.
+pub fn parse_content_range_total(value: &str) -> Option<u64> {
+    value.rsplit_once('/')?.1.trim().parse().ok()
+}
+
+fn content_range_total(response: &reqwest::Response) -> Option<u64> {
+    response
+        .headers()
+        .get(CONTENT_RANGE)
+        .and_then(|v| v.to_str().ok())
+        .and_then(parse_content_range_total)
+}
+
+async fn file_md5(path: &Path) -> Result<String, ApiError> {
+    let mut file = fs::File::open(path).await?;
+    let mut context = md5::Context::new();
+    let mut buf = vec![0; 64 * 1024];
+
+    loop {
+        let read = file.read(&mut buf).await?;
+        if read == 0 {
+            break;
+        }
+        context.consume(&buf[..read]);
+    }
+
+    Ok(format!("{:x}", context.compute()))
+}
@@ -155,12 +155,33 @@ pub enum ApiError {
     #[error("Unexpected ApiDataResponse variant: {0:?}")]
     Unexpected(ApiDataResponse),
 
+    #[error("Rate limited by TorBox, retry after {0:?}")]
+    RateLimited(Option<std::time::Duration>),
+
     #[error("Unexpected Payload variant")]
     UnexpectedPayload,
 
     #[error("UTF8 encoding/decoding error: {0:?}")]
     Utf8(std::string::FromUtf8Error),
 
+    #[error("Invalid client configuration: {0}")]
+    Config(String),
+
+    #[error("Invalid input: {0}")]
+    InvalidInput(String),
+
+    #[error("IO error: {0}")]
+    Io(#[from] std::io::Error),
+
+    #[error("Download failed: {0}")]
+    Download(String),
+
+    #[error("Downloaded {actual} bytes, expected {expected}")]
+    SizeMismatch { expected: u64, actual: u64 },
+
+    #[error("MD5 mismatch, expected {expected} but got {actual}")]
+    ChecksumMismatch { expected: String, actual: String },
+
     #[error("Unknown variant: {0:?}")]
     Custom(String),
 }
@@ -190,7 +211,17 @@ impl<'de> Deserialize<'de> for ApiError {
     }
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+impl ApiError {
+    /// The TorBox error code, if this is an `ApiError::Failure` carrying a known code.
+    pub fn code(&self) -> Option<&ApiErrorCode> {
+        match self {
+            ApiError::Failure(error) => error.code(),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
 #[cfg_attr(feature = "specta", derive(specta::Type))]
 #[serde(untagged)]
 pub enum ErrorValue {
@@ -198,3 +229,12 @@ pub enum ErrorValue {
     Bool(bool),
     Message(String),
 }
+
+impl ErrorValue {
+    pub fn code(&self) -> Option<&ApiErrorCode> {
+        match self {
+            ErrorValue::Code(code) => Some(code),
+            _ => None,
+        }
+    }
+}
@@ -1,11 +1,18 @@
 pub mod api;
 pub mod body;
+pub mod builder;
+pub mod cache;
 pub mod client;
 pub mod data;
+pub mod download;
 pub mod enums;
 pub mod error;
+pub mod link;
 pub mod network;
+pub mod pagination;
+pub mod strm;
 mod tests;
 pub mod traits;
+pub mod validation;
 
 //todo: divide each category (torrent, ddl, general, etc...) with feature flags to be able to disable default features and keep only necessary types.
@@ -0,0 +1,202 @@
+use reqwest::{Method, Url, header::LOCATION};
+
+use crate::{
+    api::ApiResponse, client::TorboxClient, data::creation::DownloadLinkResponse,
+    download::DownloadSource, error::ApiError,
+};
+
+/// Builds download links for a torrent, web download or usenet download.
+///
+/// Links requested from the API are authenticated with the `Authorization` header, the token only ends up
+/// in the URL of [`permalink`](Self::permalink)s, which are meant to be shared and are built offline.
+/// Without any file the whole item is linked as a zip, with several files one link is made per file.
+///
+/// ```no_run
+/// # use torbox_core_rs::{client::TorboxClient, link::LinkBuilder};
+/// # async fn run(client: TorboxClient) -> Result<(), torbox_core_rs::error::ApiError> {
+/// // CDN links, valid for 3 hours
+/// let links = LinkBuilder::torrent(&client, 42).files([0, 1]).links().await?;
+///
+/// // Redirecting permalink, which never expires
+/// let permalink = LinkBuilder::torrent(&client, 42).file(0).permalink()?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone)]
+pub struct LinkBuilder<'a> {
+    client: &'a TorboxClient,
+    source: DownloadSource,
+    files: Vec<u64>,
+    user_ip: Option<String>,
+}
+
+impl<'a> LinkBuilder<'a> {
+    /// Links to `source`, a [`DownloadSource::Url`] is returned as is.
+    pub fn new(client: &'a TorboxClient, source: DownloadSource) -> Self {
+        Self {
+            client,
+            source,
+            files: Vec::new(),
+            user_ip: None,
+        }
+    }
+
+    pub fn torrent(client: &'a TorboxClient, torrent_id: u32) -> Self {
+        Self::new(client, DownloadSource::Torrent { torrent_id })
+    }
+
+    pub fn webdownload(client: &'a TorboxClient, web_id: u32) -> Self {
+        Self::new(client, DownloadSource::Webdownload { web_id })
+    }
+
+    pub fn usenet(client: &'a TorboxClient, usenet_id: u32) -> Self {
+        Self::new(client, DownloadSource::Usenet { usenet_id })
+    }
+
+    /// Adds a file to link, instead of the whole zip.
+    pub fn file(mut self, file_id: u64) -> Self {
+        self.files.push(file_id);
+        self
+    }
+
+    /// Adds several files to link, one link is made per file.
+    pub fn files(mut self, file_ids: impl IntoIterator<Item = u64>) -> Self {
+        self.files.extend(file_ids);
+        self
+    }
+
+    /// Links the whole item as a zip, dropping the files added so far.
+    pub fn zip(mut self) -> Self {
+        self.files.clear();
+        self
+    }
+
+    /// The user's IP, to pick the closest CDN.
+    pub fn user_ip(mut self, user_ip: impl Into<String>) -> Self {
+        self.user_ip = Some(user_ip.into());
+        self
+    }
+
+    /// Requests the CDN link of a single file, or of the zip when no file was added.
+    ///
+    /// With `redirect`, the link is read from the `Location` header of the API response.
+    pub async fn request(&self, redirect: bool) -> Result<DownloadLinkResponse, ApiError> {
+        let file_id = self.single_file()?;
+        let Some((path, _, _)) = self.source.request_link() else {
+            return Ok(DownloadLinkResponse::Redirect(self.plain_url().to_string()));
+        };
+
+        let url = format!("{}/{}", self.client.base_url, path);
+        let request = self
+            .client
+            .client
+            .get(&url)
+            .headers(self.client.headers("application/json")?)
+            .query(&self.params(file_id, redirect));
+        let response = self.client.send(&Method::GET, path, request).await?;
+
+        if redirect && response.status().is_redirection() {
+            let location = response
+                .headers()
+                .get(LOCATION)
+                .ok_or(ApiError::RedirectError("Missing Location header".into()))?
+                .to_str()
+                .map_err(|_| ApiError::RedirectError("Invalid Location header".into()))?;
+
+            return Ok(DownloadLinkResponse::Redirect(location.to_string()));
+        }
+
+        match response.json::<ApiResponse<String>>().await {
+            Ok(json) => Ok(DownloadLinkResponse::Json(self.client.checked(json)?)),
+            Err(_) if redirect => Err(ApiError::UnexpectedPayload),
+            Err(err) => Err(err.into()),
+        }
+    }
+
+    /// Requests the CDN link of a single file, or of the zip when no file was added.
+    pub async fn link(&self) -> Result<String, ApiError> {
+        match self.request(false).await? {
+            DownloadLinkResponse::Json(json) => json.into_data(),
+            DownloadLinkResponse::Redirect(url) => Ok(url),
+        }
+    }
+
+    /// Requests the CDN link of every file, in the order they were added, or of the zip.
+    pub async fn links(&self) -> Result<Vec<String>, ApiError> {
+        let mut links = Vec::with_capacity(self.files.len().max(1));
+        for builder in self.per_file() {
+            links.push(builder.link().await?);
+        }
+        Ok(links)
+    }
+
+    /// Builds, without calling the API, a permalink that redirects to a fresh CDN link whenever it is opened.
+    ///
+    /// It carries the API token, only share it with people allowed to use your account.
+    pub fn permalink(&self) -> Result<String, ApiError> {
+        let file_id = self.single_file()?;
+        let Some((path, _, _)) = self.source.request_link() else {
+            return Ok(self.plain_url().to_string());
+        };
+
+        let mut params = vec![("token", self.client.token()?)];
+        params.extend(self.params(file_id, true));
+
+        Url::parse_with_params(&format!("{}/{}", self.client.base_url, path), &params)
+            .map(String::from)
+            .map_err(|e| ApiError::Config(format!("Invalid base URL: {e}")))
+    }
+
+    /// Builds a [`permalink`](Self::permalink) for every file, in the order they were added, or for the zip.
+    pub fn permalinks(&self) -> Result<Vec<String>, ApiError> {
+        self.per_file().map(|builder| builder.permalink()).collect()
+    }
+
+    /// One builder per file, or this one if it has at most one file.
+    fn per_file(&self) -> Box<dyn Iterator<Item = Self> + '_> {
+        if self.files.len() <= 1 {
+            return Box::new(std::iter::once(self.clone()));
+        }
+
+        Box::new(self.files.iter().map(|&file_id| Self {
+            files: vec![file_id],
+            ..self.clone()
+        }))
+    }
+
+    fn single_file(&self) -> Result<Option<u64>, ApiError> {
+        match self.files.as_slice() {
+            [] => Ok(None),
+            [file_id] => Ok(Some(*file_id)),
+            _ => Err(ApiError::InvalidInput(
+                "Several files give several links, use `links` or `permalinks`".into(),
+            )),
+        }
+    }
+
+    fn plain_url(&self) -> &str {
+        match &self.source {
+            DownloadSource::Url(url) => url,
+            _ => unreachable!("only plain URLs have no requestdl endpoint"),
+        }
+    }
+
+    /// Query parameters of `requestdl`, without the token.
+    fn params(&self, file_id: Option<u64>, redirect: bool) -> Vec<(&'static str, String)> {
+        let (_, id_key, id) = self
+            .source
+            .request_link()
+            .expect("only called for sources with a requestdl endpoint");
+
+        let mut params = vec![(id_key, id.to_string())];
+        match file_id {
+            Some(file_id) => params.push(("file_id", file_id.to_string())),
+            None => params.push(("zip_link", "true".to_string())),
+        }
+        if let Some(user_ip) = &self.user_ip {
+            params.push(("user_ip", user_ip.clone()));
+        }
+        params.push(("redirect", redirect.to_string()));
+        params
+    }
+}
@@ -1,2 +1,3 @@
+pub const BASE_URL: &str = "https://api.torbox.app/v1";
 pub const CONTENT_XML: &[u8] = "application/xml".as_bytes();
 pub const CONTENT_JSON: &[u8] = "application/json".as_bytes();
@@ -0,0 +1,152 @@
+use std::{
+    env,
+    fmt::Debug,
+    fs,
+    path::{Path, PathBuf},
+    sync::RwLock,
+};
+
+use async_trait::async_trait;
+
+use crate::error::ApiError;
+
+/// Environment variable read by [`EnvCredentials::default`].
+pub const TOKEN_ENV_VAR: &str = "TORBOX_TOKEN";
+
+/// Where `TorboxClient` gets its API token from, consulted before every request.
+///
+/// When a request is rejected with `BAD_TOKEN` or `AUTH_ERROR`, the client calls
+/// [`refresh`](Self::refresh) once and, if it returns a new token, sends the request again with it.
+///
+/// ```no_run
+/// # use std::sync::Arc;
+/// # use torbox_core_rs::{client::TorboxClient, network::credentials::EnvCredentials};
+/// let client = TorboxClient::builder("")
+///     .credentials(Arc::new(EnvCredentials::default()))
+///     .build()?;
+/// # Ok::<(), torbox_core_rs::error::ApiError>(())
+/// ```
+#[async_trait]
+pub trait CredentialProvider: Debug + Send + Sync {
+    /// The token to send with the next request.
+    fn token(&self) -> Result<String, ApiError>;
+
+    /// Returns the token to retry with after `rejected` was refused, or `None` to give up.
+    ///
+    /// By default, the current token is returned if it changed since `rejected` was sent.
+    async fn refresh(&self, rejected: &str) -> Result<Option<String>, ApiError> {
+        let current = self.token()?;
+        Ok((current != rejected).then_some(current))
+    }
+}
+
+/// A token kept in memory, which can be swapped at runtime with [`set`](Self::set).
+#[derive(Debug, Default)]
+pub struct InMemoryCredentials {
+    token: RwLock<String>,
+}
+
+impl InMemoryCredentials {
+    pub fn new(token: impl Into<String>) -> Self {
+        Self {
+            token: RwLock::new(token.into()),
+        }
+    }
+
+    /// Replaces the token, requests rejected with the previous one are retried with it.
+    pub fn set(&self, token: impl Into<String>) {
+        *self.token.write().unwrap() = token.into();
+    }
+}
+
+impl CredentialProvider for InMemoryCredentials {
+    fn token(&self) -> Result<String, ApiError> {
+        Ok(self.token.read().unwrap().clone())
+    }
+}
+
+/// A token read from an environment variable on every request.
+#[derive(Debug, Clone)]
+pub struct EnvCredentials {
+    var: String,
+}
+
+impl EnvCredentials {
+    pub fn new(var: impl Into<String>) -> Self {
+        Self { var: var.into() }
+    }
+}
+
+impl Default for EnvCredentials {
+    /// Reads [`TOKEN_ENV_VAR`].
+    fn default() -> Self {
+        Self::new(TOKEN_ENV_VAR)
+    }
+}
+
+impl CredentialProvider for EnvCredentials {
+    fn token(&self) -> Result<String, ApiError> {
+        env::var(&self.var)
+            .map_err(|_| ApiError::Config(format!("Environment variable {} is not set", self.var)))
+    }
+}
+
+/// A token stored alone in a file, surrounding whitespace ignored.
+///
+/// The file is read once and cached, then read again when the token is rejected, so another
+/// process can rotate it. [`set`](Self::set) writes a new token to it.
+#[derive(Debug)]
+pub struct FileCredentials {
+    path: PathBuf,
+    cached: RwLock<Option<String>>,
+}
+
+impl FileCredentials {
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        Self {
+            path: path.into(),
+            cached: RwLock::new(None),
+        }
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Writes `token` to the file and uses it for the next requests.
+    pub fn set(&self, token: impl Into<String>) -> Result<(), ApiError> {
+        let token = token.into();
+        fs::write(&self.path, &token).map_err(|e| self.error(e))?;
+        *self.cached.write().unwrap() = Some(token);
+        Ok(())
+    }
+
+    fn read(&self) -> Result<String, ApiError> {
+        let token = fs::read_to_string(&self.path)
+            .map_err(|e| self.error(e))?
+            .trim()
+            .to_string();
+        *self.cached.write().unwrap() = Some(token.clone());
+        Ok(token)
+    }
+
+    fn error(&self, err: std::io::Error) -> ApiError {
+        ApiError::Config(format!("Token file {}: {err}", self.path.display()))
+    }
+}
+
+#[async_trait]
+impl CredentialProvider for FileCredentials {
+    fn token(&self) -> Result<String, ApiError> {
+        let cached = self.cached.read().unwrap().clone();
+        match cached {
+            Some(token) => Ok(token),
+            None => self.read(),
+        }
+    }
+
+    async fn refresh(&self, rejected: &str) -> Result<Option<String>, ApiError> {
+        let current = self.read()?;
+        Ok((current != rejected).then_some(current))
+    }
+}
@@ -1,2 +1,5 @@
 pub mod config;
 pub mod constants;
+pub mod credentials;
+pub mod rate_limit;
+pub mod retry;
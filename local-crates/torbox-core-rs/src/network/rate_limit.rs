@@ -0,0 +1,201 @@
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+
+use chrono::{DateTime, Utc};
+use reqwest::header::{HeaderMap, RETRY_AFTER};
+use tokio::time::Instant;
+
+/// A token bucket rule: at most `requests` requests every `per`, with bursts up to `requests`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RateLimit {
+    pub requests: u32,
+    pub per: Duration,
+}
+
+impl RateLimit {
+    pub fn new(requests: u32, per: Duration) -> Self {
+        Self {
+            requests: requests.max(1),
+            per,
+        }
+    }
+
+    pub fn per_second(requests: u32) -> Self {
+        Self::new(requests, Duration::from_secs(1))
+    }
+
+    pub fn per_minute(requests: u32) -> Self {
+        Self::new(requests, Duration::from_secs(60))
+    }
+
+    pub fn per_hour(requests: u32) -> Self {
+        Self::new(requests, Duration::from_secs(3600))
+    }
+
+    fn refill_rate(&self) -> f64 {
+        self.requests as f64 / self.per.as_secs_f64()
+    }
+}
+
+#[derive(Debug)]
+struct Bucket {
+    limit: Option<RateLimit>,
+    tokens: f64,
+    last_refill: Instant,
+    paused_until: Option<Instant>,
+}
+
+/// Client-side rate limiter shared by every clone of a `TorboxClient`.
+///
+/// Limits are matched against the endpoint path (`EndpointSpec::PATH`) by longest prefix, so a rule can target
+/// a single endpoint (`"api/torrents/createtorrent"`) or a whole category (`"api/webdl/"`). Requests matching the
+/// same rule share one bucket and are queued until a token is available, and so do all the requests matching no rule.
+///
+/// When TorBox answers with HTTP 429, the matching bucket is paused for the `Retry-After` duration and the request is
+/// sent again, up to `max_rate_limited_retries` times.
+#[derive(Debug, Clone)]
+pub struct RateLimiter {
+    rules: Vec<(String, RateLimit)>,
+    default_limit: Option<RateLimit>,
+    /// How many times a request answered with HTTP 429 is sent again before giving up with `ApiError::RateLimited`.
+    pub max_rate_limited_retries: u32,
+    /// Longest `Retry-After` the client accepts to wait for, anything above fails right away.
+    pub max_retry_after: Duration,
+    /// Wait used when a 429 response has no usable `Retry-After` header.
+    pub default_retry_after: Duration,
+    buckets: Arc<Mutex<HashMap<String, Bucket>>>,
+}
+
+impl Default for RateLimiter {
+    fn default() -> Self {
+        Self {
+            rules: Vec::new(),
+            default_limit: None,
+            max_rate_limited_retries: 3,
+            max_retry_after: Duration::from_secs(60),
+            default_retry_after: Duration::from_secs(5),
+            buckets: Arc::new(Mutex::new(HashMap::new())),
+        }
+    }
+}
+
+impl RateLimiter {
+    /// A limiter without any rule, only handling HTTP 429 responses. This is what `TorboxClient::new` uses.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Limits every endpoint whose path starts with `path_prefix`.
+    pub fn with_limit(mut self, path_prefix: impl Into<String>, limit: RateLimit) -> Self {
+        let prefix = path_prefix.into();
+        self.rules.retain(|(p, _)| *p != prefix);
+        self.rules.push((prefix, limit));
+        self
+    }
+
+    /// Limit of the bucket shared by every endpoint not matched by any other rule.
+    pub fn with_default_limit(mut self, limit: RateLimit) -> Self {
+        self.default_limit = Some(limit);
+        self
+    }
+
+    pub fn with_max_rate_limited_retries(mut self, retries: u32) -> Self {
+        self.max_rate_limited_retries = retries;
+        self
+    }
+
+    pub fn with_max_retry_after(mut self, max: Duration) -> Self {
+        self.max_retry_after = max;
+        self
+    }
+
+    fn bucket_for(&self, path: &str) -> (String, Option<RateLimit>) {
+        let path = path.split('?').next().unwrap_or(path);
+
+        self.rules
+            .iter()
+            .filter(|(prefix, _)| path.starts_with(prefix.as_str()))
+            .max_by_key(|(prefix, _)| prefix.len())
+            .map(|(prefix, limit)| (prefix.clone(), Some(*limit)))
+            .unwrap_or_else(|| (String::new(), self.default_limit))
+    }
+
+    /// Waits until a request to `path` is allowed to be sent.
+    pub async fn acquire(&self, path: &str) {
+        let wait = self.reserve(path);
+        if !wait.is_zero() {
+            tokio::time::sleep(wait).await;
+        }
+    }
+
+    /// Takes a token for `path` and returns how long the caller has to wait before using it.
+    fn reserve(&self, path: &str) -> Duration {
+        let (key, limit) = self.bucket_for(path);
+        let now = Instant::now();
+        let mut buckets = self.buckets.lock().unwrap();
+
+        let bucket = buckets.entry(key).or_insert_with(|| Bucket {
+            limit,
+            tokens: limit.map(|l| l.requests as f64).unwrap_or_default(),
+            last_refill: now,
+            paused_until: None,
+        });
+
+        let mut ready_at = now;
+
+        if let Some(limit) = bucket.limit {
+            let elapsed = now.duration_since(bucket.last_refill).as_secs_f64();
+            bucket.tokens =
+                (bucket.tokens + elapsed * limit.refill_rate()).min(limit.requests as f64);
+            bucket.last_refill = now;
+            bucket.tokens -= 1.0;
+
+            if bucket.tokens < 0.0 {
+                ready_at += Duration::from_secs_f64(-bucket.tokens / limit.refill_rate());
+            }
+        }
+
+        if let Some(paused_until) = bucket.paused_until {
+            if paused_until > now {
+                ready_at = ready_at.max(paused_until);
+            } else {
+                bucket.paused_until = None;
+            }
+        }
+
+        ready_at.duration_since(now)
+    }
+
+    /// Blocks every request to the bucket of `path` for `duration`, used after an HTTP 429.
+    pub fn pause(&self, path: &str, duration: Duration) {
+        let (key, limit) = self.bucket_for(path);
+        let now = Instant::now();
+        let until = now + duration;
+        let mut buckets = self.buckets.lock().unwrap();
+
+        let bucket = buckets.entry(key).or_insert_with(|| Bucket {
+            limit,
+            tokens: limit.map(|l| l.requests as f64).unwrap_or_default(),
+            last_refill: now,
+            paused_until: None,
+        });
+
+        bucket.paused_until = Some(bucket.paused_until.map_or(until, |p| p.max(until)));
+    }
+}
+
+/// Parses a `Retry-After` header, either as delay in seconds or as an HTTP date.
+pub fn parse_retry_after(headers: &HeaderMap) -> Option<Duration> {
+    let value = headers.get(RETRY_AFTER)?.to_str().ok()?.trim();
+
+    if let Ok(secs) = value.parse::<u64>() {
+        return Some(Duration::from_secs(secs));
+    }
+
+    let date = DateTime::parse_from_rfc2822(value).ok()?;
+    let delta = date.with_timezone(&Utc) - Utc::now();
+    Some(delta.to_std().unwrap_or(Duration::ZERO))
+}
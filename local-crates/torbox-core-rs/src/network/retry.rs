@@ -0,0 +1,125 @@
+use std::{
+    collections::hash_map::RandomState,
+    hash::BuildHasher,
+    time::{Duration, SystemTime},
+};
+
+use reqwest::{Method, StatusCode};
+
+use crate::error::ApiErrorCode;
+
+/// Describes when and how `TorboxClient` retries a request that failed for a transient reason.
+///
+/// A request is retried when:
+/// - the connection could not be established (always safe, the request never left the client),
+/// - the connection was reset or timed out, and the method is idempotent,
+/// - the server answered with one of `retry_statuses`, and the method is idempotent,
+/// - the server answered with one of `retry_codes` in the `error` field, and the method is idempotent.
+///
+/// `POST` requests are not idempotent (e.g. `createtorrent`) and are only retried in the last three cases
+/// if `retry_non_idempotent` is set. Multipart uploads are never retried since their body cannot be replayed.
+#[derive(Debug, Clone)]
+pub struct RetryPolicy {
+    /// Total number of attempts, including the first one. `1` disables retries.
+    pub max_attempts: u32,
+    /// Delay before the first retry.
+    pub initial_backoff: Duration,
+    /// Upper bound for the delay between two attempts.
+    pub max_backoff: Duration,
+    /// Factor applied to the delay after each failed attempt.
+    pub multiplier: f64,
+    /// Randomizes each delay between half and all of its value to avoid synchronized retries.
+    pub jitter: bool,
+    /// HTTP statuses considered transient.
+    pub retry_statuses: Vec<StatusCode>,
+    /// TorBox error codes considered transient.
+    pub retry_codes: Vec<ApiErrorCode>,
+    /// Also retry non idempotent methods (`POST`, `PATCH`) on transient failures.
+    pub retry_non_idempotent: bool,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_attempts: 3,
+            initial_backoff: Duration::from_millis(500),
+            max_backoff: Duration::from_secs(30),
+            multiplier: 2.0,
+            jitter: true,
+            retry_statuses: vec![
+                StatusCode::INTERNAL_SERVER_ERROR,
+                StatusCode::BAD_GATEWAY,
+                StatusCode::SERVICE_UNAVAILABLE,
+                StatusCode::GATEWAY_TIMEOUT,
+            ],
+            retry_codes: vec![
+                ApiErrorCode::DownloadServerError,
+                ApiErrorCode::NoServersAvailableError,
+            ],
+            retry_non_idempotent: false,
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// A policy that sends every request exactly once. This is what `TorboxClient::new` uses.
+    pub fn none() -> Self {
+        Self {
+            max_attempts: 1,
+            ..Default::default()
+        }
+    }
+
+    pub fn with_max_attempts(mut self, max_attempts: u32) -> Self {
+        self.max_attempts = max_attempts.max(1);
+        self
+    }
+
+    pub fn with_backoff(mut self, initial: Duration, max: Duration) -> Self {
+        self.initial_backoff = initial;
+        self.max_backoff = max;
+        self
+    }
+
+    pub fn with_retry_non_idempotent(mut self, retry: bool) -> Self {
+        self.retry_non_idempotent = retry;
+        self
+    }
+
+    /// Whether a transient failure on this method may be retried.
+    pub fn allows(&self, method: &Method) -> bool {
+        self.retry_non_idempotent || method.is_idempotent()
+    }
+
+    pub fn should_retry_status(&self, method: &Method, status: StatusCode) -> bool {
+        self.allows(method) && self.retry_statuses.contains(&status)
+    }
+
+    pub fn should_retry_code(&self, method: &Method, code: &ApiErrorCode) -> bool {
+        self.allows(method) && self.retry_codes.contains(code)
+    }
+
+    pub fn should_retry_transport(&self, method: &Method, err: &reqwest::Error) -> bool {
+        err.is_connect() || (self.allows(method) && (err.is_timeout() || err.is_request()))
+    }
+
+    /// Delay to wait after the given failed attempt (starting at 1).
+    pub fn backoff(&self, attempt: u32) -> Duration {
+        let exp = self.multiplier.powi(attempt.saturating_sub(1) as i32);
+        let delay = Duration::try_from_secs_f64(self.initial_backoff.as_secs_f64() * exp)
+            .unwrap_or(self.max_backoff)
+            .min(self.max_backoff);
+
+        if self.jitter {
+            delay.mul_f64(0.5 + random_fraction() / 2.0)
+        } else {
+            delay
+        }
+    }
+}
+
+/// Cheap random number in `[0, 1)`, good enough for jitter without pulling a RNG dependency.
+fn random_fraction() -> f64 {
+    let seed = RandomState::new().hash_one(SystemTime::now());
+    (seed >> 11) as f64 / (1u64 << 53) as f64
+}
@@ -0,0 +1,86 @@
+use std::sync::{
+    Arc,
+    atomic::{AtomicBool, Ordering},
+};
+
+use futures::{Stream, StreamExt, future, stream};
+
+use crate::error::ApiError;
+
+/// Page size used by the `mylist` endpoints when no limit is given.
+pub const DEFAULT_PAGE_SIZE: u32 = 1000;
+
+/// How `list_all` walks through the pages of a `mylist` endpoint.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Pagination {
+    /// Number of items requested per page, `1000` by default.
+    pub page_size: u32,
+    /// Number of pages requested at the same time, `1` fetches pages one after the other.
+    ///
+    /// With more than one, pages past the end of the list may be requested before the stream ends.
+    pub prefetch: usize,
+    /// Bypass the cached list on every page.
+    pub bypass_cache: bool,
+}
+
+impl Default for Pagination {
+    fn default() -> Self {
+        Self {
+            page_size: DEFAULT_PAGE_SIZE,
+            prefetch: 1,
+            bypass_cache: false,
+        }
+    }
+}
+
+impl Pagination {
+    pub fn with_page_size(mut self, page_size: u32) -> Self {
+        self.page_size = page_size;
+        self
+    }
+
+    pub fn with_prefetch(mut self, pages: usize) -> Self {
+        self.prefetch = pages;
+        self
+    }
+
+    pub fn with_bypass_cache(mut self, bypass_cache: bool) -> Self {
+        self.bypass_cache = bypass_cache;
+        self
+    }
+}
+
+/// Streams every item of a paginated list.
+///
+/// `fetch` is called with the `offset` and `limit` of each page. The stream ends after the first page holding
+/// fewer than `page_size` items, or right after yielding an error.
+pub fn paginate<T, F, Fut>(
+    pagination: Pagination,
+    mut fetch: F,
+) -> impl Stream<Item = Result<T, ApiError>>
+where
+    F: FnMut(u32, u32) -> Fut,
+    Fut: Future<Output = Result<Vec<T>, ApiError>>,
+{
+    let limit = pagination.page_size.max(1);
+    // Set once the last page arrived, so no page is requested past it unless prefetching.
+    let done = Arc::new(AtomicBool::new(false));
+    let last_page = done.clone();
+
+    stream::iter((0u32..).map(move |page| page.saturating_mul(limit)))
+        .take_while(move |_| future::ready(!done.load(Ordering::Relaxed)))
+        .map(move |offset| fetch(offset, limit))
+        .buffered(pagination.prefetch.max(1))
+        .take_while(move |page| {
+            let more = !last_page.load(Ordering::Relaxed);
+            let last = page
+                .as_ref()
+                .map_or(true, |items| items.len() < limit as usize);
+            last_page.fetch_or(last, Ordering::Relaxed);
+            future::ready(more)
+        })
+        .flat_map(|page| match page {
+            Ok(items) => stream::iter(items.into_iter().map(Ok).collect::<Vec<_>>()),
+            Err(err) => stream::iter(vec![Err(err)]),
+        })
+}
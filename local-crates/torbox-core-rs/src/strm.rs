@@ -0,0 +1,232 @@
+//! `.strm` files, text files holding the URL of a remote video, which media servers such as
+//! Jellyfin, Emby or Plex index and play as if the video was local.
+
+use std::{
+    collections::HashSet,
+    ffi::OsString,
+    path::{Component, Path, PathBuf},
+};
+
+use tokio::fs;
+
+use crate::{
+    client::TorboxClient,
+    data::{torrent::TorrentStatus, webdownload::WebdownloadStatus},
+    error::ApiError,
+    link::LinkBuilder,
+};
+
+/// A `.strm` file to write, pointing to `url`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StrmFile {
+    /// Path relative to the root of the tree, ending with `.strm`.
+    pub path: PathBuf,
+    pub url: String,
+}
+
+impl StrmFile {
+    /// Places the file of a download under `folder/`, named after `file_name` followed by `.strm`.
+    ///
+    /// The original extension is kept, so `Movie.mkv` and `Movie.srt` do not end up in the same
+    /// file and dots in names such as `Movie.2020` are not mistaken for one.
+    ///
+    /// `file_name` may contain sub-folders, which are kept. When it already starts with `folder`,
+    /// as TorBox file names usually do, it is not nested twice. `..`, root and prefix components
+    /// are dropped, so the file never ends up outside of the tree.
+    ///
+    /// ```
+    /// # use std::path::Path;
+    /// # use torbox_core_rs::strm::StrmFile;
+    /// let file = StrmFile::new("Show", "Show/Season 1/E01.mkv", "https://example.com/1");
+    /// assert_eq!(file.path, Path::new("Show/Season 1/E01.mkv.strm"));
+    /// ```
+    pub fn new(folder: &str, file_name: &str, url: impl Into<String>) -> Self {
+        let folder = relative(Path::new(folder));
+        let mut file = relative(Path::new(file_name));
+        if let Ok(inner) = file.strip_prefix(&folder) {
+            file = inner.to_path_buf();
+        }
+
+        let mut path = OsString::from(folder.join(file));
+        path.push(".strm");
+
+        Self {
+            path: path.into(),
+            url: url.into(),
+        }
+    }
+}
+
+/// A file left out of a `.strm` tree because TorBox listed it without an id.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SkippedFile {
+    pub download_id: u64,
+    pub name: String,
+}
+
+/// The `.strm` files of a set of downloads, see [`strm_files`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct StrmTree {
+    pub files: Vec<StrmFile>,
+    /// Files without an id, which no permalink can be built for.
+    pub skipped: Vec<SkippedFile>,
+}
+
+/// Outcome of [`write_strm_sources`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct StrmReport {
+    pub written: Vec<PathBuf>,
+    pub skipped: Vec<SkippedFile>,
+}
+
+/// A download whose files can be mirrored as `.strm` files.
+pub trait StrmSource {
+    fn id(&self) -> u64;
+
+    /// Folder the `.strm` files of the download are placed in.
+    fn folder(&self) -> &str;
+
+    /// Builder of the links of the whole download.
+    fn links<'a>(&self, client: &'a TorboxClient) -> Result<LinkBuilder<'a>, ApiError>;
+
+    /// The id, when listed, and the name of every file.
+    fn files(&self) -> Vec<(Option<u64>, &str)>;
+}
+
+impl StrmSource for TorrentStatus {
+    fn id(&self) -> u64 {
+        self.id
+    }
+
+    fn folder(&self) -> &str {
+        &self.name
+    }
+
+    fn links<'a>(&self, client: &'a TorboxClient) -> Result<LinkBuilder<'a>, ApiError> {
+        let torrent_id = u32::try_from(self.id)
+            .map_err(|_| ApiError::InvalidInput(format!("Torrent id {} is too large", self.id)))?;
+        Ok(LinkBuilder::torrent(client, torrent_id))
+    }
+
+    fn files(&self) -> Vec<(Option<u64>, &str)> {
+        self.files
+            .iter()
+            .map(|file| (file.id, file.name.as_str()))
+            .collect()
+    }
+}
+
+impl StrmSource for WebdownloadStatus {
+    fn id(&self) -> u64 {
+        self.id
+    }
+
+    fn folder(&self) -> &str {
+        &self.name
+    }
+
+    fn links<'a>(&self, client: &'a TorboxClient) -> Result<LinkBuilder<'a>, ApiError> {
+        let web_id = u32::try_from(self.id).map_err(|_| {
+            ApiError::InvalidInput(format!("Web download id {} is too large", self.id))
+        })?;
+        Ok(LinkBuilder::webdownload(client, web_id))
+    }
+
+    fn files(&self) -> Vec<(Option<u64>, &str)> {
+        self.files
+            .iter()
+            .map(|file| (Some(file.id), file.name.as_str()))
+            .collect()
+    }
+}
+
+/// A `.strm` file per file of every download, under a folder named after the download and
+/// mirroring the file paths, pointing to the file's permalink.
+///
+/// Files without an id, as in torrent metadata, are skipped and listed in [`StrmTree::skipped`].
+pub fn strm_files<S: StrmSource>(
+    client: &TorboxClient,
+    downloads: &[S],
+) -> Result<StrmTree, ApiError> {
+    let mut tree = StrmTree::default();
+
+    for download in downloads {
+        let links = download.links(client)?;
+
+        for (file_id, name) in download.files() {
+            let Some(file_id) = file_id else {
+                tree.skipped.push(SkippedFile {
+                    download_id: download.id(),
+                    name: name.to_string(),
+                });
+                continue;
+            };
+            let url = links.clone().file(file_id).permalink()?;
+            tree.files.push(StrmFile::new(download.folder(), name, url));
+        }
+    }
+
+    Ok(tree)
+}
+
+/// Writes the [`strm_files`] of `downloads` under `root`, see [`write_strm_tree`].
+pub async fn write_strm_sources<S: StrmSource>(
+    client: &TorboxClient,
+    downloads: &[S],
+    root: impl AsRef<Path>,
+) -> Result<StrmReport, ApiError> {
+    let tree = strm_files(client, downloads)?;
+    let written = write_strm_tree(root, &tree.files).await?;
+
+    Ok(StrmReport {
+        written,
+        skipped: tree.skipped,
+    })
+}
+
+/// Writes every file under `root`, creating the folders and overwriting existing files, and
+/// returns the paths written.
+///
+/// Nothing is written if two files share the same path.
+pub async fn write_strm_tree(
+    root: impl AsRef<Path>,
+    files: &[StrmFile],
+) -> Result<Vec<PathBuf>, ApiError> {
+    let mut paths = HashSet::with_capacity(files.len());
+    for file in files {
+        if file.path.file_name().is_none() {
+            return Err(ApiError::InvalidInput(format!(
+                "No file name to write the .strm of {} to",
+                file.url
+            )));
+        }
+        if !paths.insert(relative(&file.path)) {
+            return Err(ApiError::InvalidInput(format!(
+                "Several files would be written to {}",
+                file.path.display()
+            )));
+        }
+    }
+
+    let mut written = Vec::with_capacity(files.len());
+    for file in files {
+        let path = root.as_ref().join(relative(&file.path));
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent).await?;
+        }
+        fs::write(&path, format!("{}\n", file.url)).await?;
+        written.push(path);
+    }
+
+    Ok(written)
+}
+
+/// Keeps the normal components of `path`.
+fn relative(path: &Path) -> PathBuf {
+    path.components()
+        .filter_map(|component| match component {
+            Component::Normal(part) => Some(part),
+            _ => None,
+        })
+        .collect()
+}
@@ -0,0 +1,60 @@
+use crate::{
+    api::ApiResponse,
+    client::TorboxClient,
+    error::{ApiError, ApiErrorCode, ErrorValue},
+};
+
+fn parse<T: serde::de::DeserializeOwned>(json: &str) -> ApiResponse<T> {
+    serde_json::from_str(json).expect("Invalid test response")
+}
+
+#[test]
+fn test_into_data_success() {
+    let resp: ApiResponse<u64> =
+        parse(r#"{"success": true, "error": null, "detail": "ok", "data": 42}"#);
+
+    assert_eq!(resp.into_data().unwrap(), 42);
+}
+
+#[test]
+fn test_into_data_failure_code() {
+    let resp: ApiResponse<u64> = parse(
+        r#"{"success": false, "error": "BAD_TOKEN", "detail": "Invalid token.", "data": null}"#,
+    );
+
+    let err = resp.into_data().unwrap_err();
+    assert_eq!(err.code(), Some(&ApiErrorCode::BadToken));
+}
+
+#[test]
+fn test_check_failure_falls_back_to_detail() {
+    let resp: ApiResponse<()> =
+        parse(r#"{"success": false, "error": null, "detail": "Nope.", "data": null}"#);
+
+    match resp.check() {
+        Err(ApiError::Failure(ErrorValue::Message(msg))) => assert_eq!(msg, "Nope."),
+        other => panic!("Expected failure message, got {other:?}"),
+    }
+}
+
+#[test]
+fn test_into_data_missing_data() {
+    let resp: ApiResponse<u64> =
+        parse(r#"{"success": true, "error": null, "detail": "ok", "data": null}"#);
+
+    assert!(matches!(resp.into_data(), Err(ApiError::UnexpectedPayload)));
+}
+
+#[test]
+fn test_checked_respects_client_mode() {
+    let json = r#"{"success": false, "error": "ITEM_NOT_FOUND", "detail": "", "data": null}"#;
+    let client = TorboxClient::new("token".into());
+
+    assert!(client.checked(parse::<()>(json)).is_ok());
+    assert!(
+        client
+            .with_strict_errors(true)
+            .checked(parse::<()>(json))
+            .is_err()
+    );
+}
@@ -0,0 +1,56 @@
+use std::time::Duration;
+
+use crate::{client::TorboxClient, error::ApiError, network::retry::RetryPolicy};
+
+#[test]
+fn test_builder_defaults_match_new() {
+    let built = TorboxClient::builder("token").build().unwrap();
+    let new = TorboxClient::new("token".into());
+
+    assert_eq!(built.base_url, new.base_url);
+    assert_eq!(built.token().unwrap(), new.token().unwrap());
+    assert!(!built.strict_errors);
+    assert_eq!(built.retry_policy.max_attempts, 1);
+}
+
+#[test]
+fn test_builder_applies_options() {
+    let client = TorboxClient::builder("token")
+        .base_url("http://localhost:8080/v1/")
+        .user_agent("my-app/1.0")
+        .connect_timeout(Duration::from_secs(2))
+        .timeout(Duration::from_secs(10))
+        .no_proxy()
+        .strict_errors(true)
+        .retry_policy(RetryPolicy::default())
+        .build()
+        .unwrap();
+
+    assert_eq!(client.base_url, "http://localhost:8080/v1");
+    assert!(client.strict_errors);
+    assert_eq!(client.retry_policy.max_attempts, 3);
+}
+
+#[test]
+fn test_builder_rejects_invalid_token() {
+    let result = TorboxClient::builder("bad\ntoken").build();
+
+    assert!(matches!(result, Err(ApiError::Config(_))));
+}
+
+#[test]
+fn test_with_token_keeps_settings() {
+    let client = TorboxClient::new(String::new())
+        .with_base_url("http://localhost:8080/v1")
+        .with_strict_errors(true);
+
+    let authenticated = client.with_token("token").unwrap();
+    assert_eq!(authenticated.token().unwrap(), "token");
+    assert_eq!(authenticated.base_url, client.base_url);
+    assert!(authenticated.strict_errors);
+
+    assert!(matches!(
+        client.with_token("bad\ntoken"),
+        Err(ApiError::Config(_))
+    ));
+}
@@ -0,0 +1,149 @@
+use std::{
+    collections::HashMap,
+    sync::{
+        Arc, Mutex,
+        atomic::{AtomicUsize, Ordering},
+    },
+    time::Duration,
+};
+
+use crate::{
+    cache::{CacheCheck, MAX_HASHES_PER_REQUEST, check_cached_batched},
+    data::{
+        cache::{CacheAvailability, CacheInfo},
+        hash::InfoHash,
+    },
+    error::ApiError,
+};
+
+fn hash(n: usize) -> InfoHash {
+    InfoHash::new(&format!("{n:040x}")).unwrap()
+}
+
+fn info(hash: &InfoHash) -> CacheInfo {
+    CacheInfo {
+        name: format!("torrent {hash}"),
+        size: 1024,
+        hash: hash.clone(),
+        files: Vec::new(),
+    }
+}
+
+#[tokio::test]
+async fn test_check_cached_batched_splits_and_merges() {
+    let batches = Arc::new(Mutex::new(Vec::new()));
+    let seen = batches.clone();
+    let hashes: Vec<InfoHash> = (0..250).map(hash).collect();
+
+    let cached = check_cached_batched(hashes.clone(), CacheCheck::default(), move |batch| {
+        seen.lock().unwrap().push(batch.len());
+        // Only even hashes are cached, answered in both formats.
+        let cached = batch.iter().filter(|h| h.to_bytes()[19] % 2 == 0).map(info);
+        let data = if batch.len() == MAX_HASHES_PER_REQUEST {
+            CacheAvailability::List(cached.collect())
+        } else {
+            CacheAvailability::Object(cached.map(|i| (i.hash.to_string(), i)).collect())
+        };
+        async move { Ok(Some(data)) }
+    })
+    .await
+    .unwrap();
+
+    let mut sizes = batches.lock().unwrap().clone();
+    sizes.sort();
+    assert_eq!(sizes, [50, 100, 100]);
+    assert_eq!(cached.len(), 125);
+    assert!(cached.contains_key(&hashes[248]));
+    assert!(!cached.contains_key(&hashes[249]));
+}
+
+#[tokio::test]
+async fn test_check_cached_batched_deduplicates_and_caps_batch_size() {
+    let requested = Arc::new(Mutex::new(Vec::new()));
+    let seen = requested.clone();
+    let hashes = (0..150).map(|n| hash(n % 120));
+
+    let cached = check_cached_batched(
+        hashes,
+        CacheCheck::default().with_batch_size(1000),
+        move |batch| {
+            seen.lock().unwrap().push(batch);
+            async { Ok(None) }
+        },
+    )
+    .await
+    .unwrap();
+
+    let requested = requested.lock().unwrap();
+    assert!(cached.is_empty());
+    assert_eq!(requested.iter().map(Vec::len).sum::<usize>(), 120);
+    assert!(requested.iter().all(|b| b.len() <= MAX_HASHES_PER_REQUEST));
+}
+
+#[tokio::test]
+async fn test_check_cached_batched_bounds_concurrency() {
+    let running = Arc::new(AtomicUsize::new(0));
+    let peak = Arc::new(AtomicUsize::new(0));
+    let (running_in, peak_in) = (running.clone(), peak.clone());
+
+    check_cached_batched(
+        (0..50).map(hash),
+        CacheCheck::default().with_batch_size(5).with_concurrency(3),
+        move |_| {
+            let (running, peak) = (running_in.clone(), peak_in.clone());
+            async move {
+                let now = running.fetch_add(1, Ordering::SeqCst) + 1;
+                peak.fetch_max(now, Ordering::SeqCst);
+                tokio::time::sleep(Duration::from_millis(10)).await;
+                running.fetch_sub(1, Ordering::SeqCst);
+                Ok(None)
+            }
+        },
+    )
+    .await
+    .unwrap();
+
+    assert_eq!(peak.load(Ordering::SeqCst), 3);
+}
+
+#[tokio::test]
+async fn test_check_cached_batched_returns_first_error() {
+    let result = check_cached_batched(
+        (0..30).map(hash),
+        CacheCheck::default().with_batch_size(10),
+        |batch| async move {
+            if batch.contains(&hash(15)) {
+                Err(ApiError::UnexpectedPayload)
+            } else {
+                Ok(Some(CacheAvailability::List(
+                    batch.iter().map(info).collect(),
+                )))
+            }
+        },
+    )
+    .await;
+
+    assert!(matches!(result, Err(ApiError::UnexpectedPayload)));
+}
+
+#[test]
+fn test_cache_availability_formats_deserialize_to_same_map() {
+    let list = r#"[{"name": "a", "size": 1, "hash": "A492F8B92A25B0399C87715FC228C864AC5A7BFB"}]"#;
+    let object = r#"{"a492f8b92a25b0399c87715fc228c864ac5a7bfb": {"name": "a", "size": 1,
+        "hash": "a492f8b92a25b0399c87715fc228c864ac5a7bfb", "files": [{"name": "a.mkv", "size": 1}]}}"#;
+
+    let list: HashMap<_, _> = serde_json::from_str::<CacheAvailability>(list)
+        .unwrap()
+        .into_map();
+    let object: HashMap<_, _> = serde_json::from_str::<CacheAvailability>(object)
+        .unwrap()
+        .into_map();
+
+    let key: InfoHash = "a492f8b92a25b0399c87715fc228c864ac5a7bfb".parse().unwrap();
+    assert_eq!(list[&key].name, "a");
+    assert_eq!(object[&key].files.len(), 1);
+    assert_eq!(
+        list.keys().collect::<Vec<_>>(),
+        object.keys().collect::<Vec<_>>()
+    );
+}
@@ -0,0 +1,65 @@
+use std::{env, fs, sync::Arc};
+
+use crate::{
+    client::TorboxClient,
+    error::ApiError,
+    network::credentials::{
+        CredentialProvider, EnvCredentials, FileCredentials, InMemoryCredentials,
+    },
+};
+
+#[tokio::test]
+async fn test_in_memory_refresh_returns_updated_token() {
+    let credentials = InMemoryCredentials::new("old");
+
+    assert_eq!(credentials.refresh("old").await.unwrap(), None);
+
+    credentials.set("new");
+    assert_eq!(credentials.token().unwrap(), "new");
+    assert_eq!(
+        credentials.refresh("old").await.unwrap().as_deref(),
+        Some("new")
+    );
+}
+
+#[test]
+fn test_env_credentials_reports_missing_variable() {
+    let credentials = EnvCredentials::new("TORBOX_TEST_MISSING_TOKEN");
+
+    assert!(matches!(credentials.token(), Err(ApiError::Config(_))));
+}
+
+#[tokio::test]
+async fn test_file_credentials_rereads_file_on_refresh() {
+    let path = env::temp_dir().join(format!("torbox-token-{}", std::process::id()));
+    fs::write(&path, "old\n").unwrap();
+    let credentials = FileCredentials::new(&path);
+    assert_eq!(credentials.token().unwrap(), "old");
+
+    // Cached until the token is rejected
+    fs::write(&path, "rotated").unwrap();
+    assert_eq!(credentials.token().unwrap(), "old");
+    assert_eq!(
+        credentials.refresh("old").await.unwrap().as_deref(),
+        Some("rotated")
+    );
+
+    credentials.set("new").unwrap();
+    assert_eq!(fs::read_to_string(&path).unwrap(), "new");
+    assert_eq!(credentials.token().unwrap(), "new");
+
+    fs::remove_file(&path).unwrap();
+}
+
+#[test]
+fn test_client_reads_token_from_credentials() {
+    let credentials = Arc::new(InMemoryCredentials::new("first"));
+    let client = TorboxClient::builder("ignored")
+        .credentials(credentials.clone())
+        .build()
+        .unwrap();
+    assert_eq!(client.token().unwrap(), "first");
+
+    credentials.set("second");
+    assert_eq!(client.token().unwrap(), "second");
+}
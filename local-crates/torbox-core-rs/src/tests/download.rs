@@ -0,0 +1,35 @@
+use crate::{
+    data::webdownload::WebdownloadFile,
+    download::{DownloadSource, DownloadTarget, parse_content_range_total},
+};
+
+#[test]
+fn test_content_range_total_is_parsed() {
+    assert_eq!(parse_content_range_total("bytes 100-199/1234"), Some(1234));
+    assert_eq!(parse_content_range_total("bytes */500"), Some(500));
+    assert_eq!(parse_content_range_total("bytes 0-99/*"), None);
+}
+
+#[test]
+fn test_webdownload_file_target_carries_checks() {
+    let file = WebdownloadFile {
+        id: 3,
+        md5: Some("d41d8cd98f00b204e9800998ecf8427e".into()),
+        s3_path: String::new(),
+        name: "a/b.bin".into(),
+        size: 42,
+        zipped: false,
+        infected: false,
+        opensubtitles_hash: None,
+        mimetype: "application/octet-stream".into(),
+        short_name: "b.bin".into(),
+        absolute_path: String::new(),
+    };
+
+    let target = DownloadTarget::webdownload_file(7, &file);
+
+    assert_eq!(target.source, DownloadSource::Webdownload { web_id: 7 });
+    assert_eq!(target.file_id, Some(3));
+    assert_eq!(target.expected_size, Some(42));
+    assert_eq!(target.md5, file.md5);
+}
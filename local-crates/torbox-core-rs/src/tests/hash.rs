@@ -0,0 +1,50 @@
+use crate::{data::hash::InfoHash, error::ApiError};
+
+const HEX: &str = "a492f8b92a25b0399c87715fc228c864ac5a7bfb";
+const BASE32: &str = "USJPROJKEWYDTHEHOFP4EKGIMSWFU673";
+
+#[test]
+fn test_hashes_are_normalized_to_lowercase_hex() {
+    let upper = InfoHash::new(&HEX.to_uppercase()).unwrap();
+    let base32 = InfoHash::new(BASE32).unwrap();
+
+    assert_eq!(upper, base32);
+    assert_eq!(upper.to_string(), HEX);
+    assert!(upper.is_v1());
+    assert_eq!(upper.to_base32(), BASE32);
+    assert_eq!(InfoHash::from_bytes(&upper.to_bytes()).unwrap(), upper);
+}
+
+#[test]
+fn test_md5_and_sha256_are_accepted() {
+    let md5 = InfoHash::new("D41D8CD98F00B204E9800998ECF8427E").unwrap();
+    assert_eq!(md5.as_str(), "d41d8cd98f00b204e9800998ecf8427e");
+    assert!(!md5.is_v1() && !md5.is_v2());
+
+    assert!(InfoHash::new(&"ab".repeat(32)).unwrap().is_v2());
+}
+
+#[test]
+fn test_invalid_hashes_are_rejected() {
+    let bad_base32 = format!("{}!!", &BASE32[..30]);
+
+    for bad in ["", "abc", &"g".repeat(40), &"a".repeat(41), &bad_base32] {
+        assert!(matches!(InfoHash::new(bad), Err(ApiError::InvalidInput(_))));
+    }
+}
+
+#[test]
+fn test_serde_round_trip_normalizes() {
+    let hash: InfoHash = serde_json::from_str(&format!("\"{}\"", HEX.to_uppercase())).unwrap();
+    assert_eq!(serde_json::to_string(&hash).unwrap(), format!("\"{HEX}\""));
+    assert!(hash.is_valid());
+}
+
+#[test]
+fn test_invalid_response_hashes_are_kept_as_sent() {
+    for odd in ["", "not-a-hash"] {
+        let hash: InfoHash = serde_json::from_value(serde_json::json!(odd)).unwrap();
+        assert_eq!(hash.as_str(), odd);
+        assert!(!hash.is_valid());
+    }
+}
@@ -2,9 +2,32 @@ use std::env;
 
 use dotenvy::from_filename;
 
+#[cfg(test)]
+mod api;
+#[cfg(test)]
+mod builder;
+#[cfg(test)]
+mod cache;
+#[cfg(test)]
+mod credentials;
+#[cfg(test)]
+mod download;
+#[cfg(test)]
+mod hash;
+#[cfg(test)]
+mod pagination;
+#[cfg(test)]
+mod rate_limit;
+#[cfg(test)]
+mod retry;
+#[cfg(test)]
+mod strm;
+#[cfg(test)]
+mod validation;
 mod user;
 mod webdownload;
 
+#[allow(dead_code)]
 pub fn load_token_from_file() -> Option<String> {
     if let Err(err) = from_filename(".token") {
         eprintln!(
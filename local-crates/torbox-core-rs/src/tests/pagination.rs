@@ -0,0 +1,46 @@
+use std::sync::{Arc, Mutex};
+
+use futures::StreamExt;
+
+use crate::{
+    error::ApiError,
+    pagination::{Pagination, paginate},
+};
+
+#[tokio::test]
+async fn test_paginate_stops_after_short_page() {
+    let offsets = Arc::new(Mutex::new(Vec::new()));
+    let seen = offsets.clone();
+
+    let items: Vec<u32> = paginate(
+        Pagination::default().with_page_size(2),
+        move |offset, limit| {
+            seen.lock().unwrap().push(offset);
+            async move { Ok((offset..(offset + limit).min(5)).collect()) }
+        },
+    )
+    .map(Result::unwrap)
+    .collect()
+    .await;
+
+    assert_eq!(items, [0, 1, 2, 3, 4]);
+    assert_eq!(*offsets.lock().unwrap(), [0, 2, 4]);
+}
+
+#[tokio::test]
+async fn test_paginate_ends_after_error() {
+    let items: Vec<Result<u32, ApiError>> = paginate(
+        Pagination::default().with_page_size(2).with_prefetch(3),
+        |offset, _| async move {
+            match offset {
+                0 => Ok(vec![0, 1]),
+                _ => Err(ApiError::UnexpectedPayload),
+            }
+        },
+    )
+    .collect()
+    .await;
+
+    assert_eq!(items.len(), 3);
+    assert!(matches!(items[2], Err(ApiError::UnexpectedPayload)));
+}
@@ -0,0 +1,80 @@
+use std::time::Duration;
+
+use reqwest::header::{HeaderMap, HeaderValue, RETRY_AFTER};
+use tokio::time::Instant;
+
+use crate::network::rate_limit::{RateLimit, RateLimiter, parse_retry_after};
+
+#[tokio::test(start_paused = true)]
+async fn test_bucket_allows_burst_then_queues() {
+    let limiter = RateLimiter::new().with_limit("api/torrents/", RateLimit::per_second(2));
+    let start = Instant::now();
+
+    limiter.acquire("api/torrents/createtorrent").await;
+    limiter.acquire("api/torrents/mylist").await;
+    assert!(start.elapsed() < Duration::from_millis(10));
+
+    limiter.acquire("api/torrents/createtorrent").await;
+    assert!(start.elapsed() >= Duration::from_millis(500));
+}
+
+#[tokio::test(start_paused = true)]
+async fn test_longest_prefix_wins_and_other_paths_are_free() {
+    let limiter = RateLimiter::new()
+        .with_limit("api/torrents/", RateLimit::per_second(100))
+        .with_limit("api/torrents/createtorrent", RateLimit::per_minute(1));
+    let start = Instant::now();
+
+    limiter.acquire("api/torrents/createtorrent").await;
+    limiter
+        .acquire("api/torrents/mylist?bypass_cache=true")
+        .await;
+    limiter.acquire("api/user/me").await;
+    assert!(start.elapsed() < Duration::from_millis(10));
+
+    limiter.acquire("api/torrents/createtorrent").await;
+    assert!(start.elapsed() >= Duration::from_secs(60));
+}
+
+#[tokio::test(start_paused = true)]
+async fn test_unmatched_paths_share_the_default_bucket() {
+    let limiter = RateLimiter::new().with_default_limit(RateLimit::per_second(1));
+    let start = Instant::now();
+
+    limiter.acquire("torrents/search/ubuntu").await;
+    limiter.acquire("torrents/search/debian").await;
+    assert!(start.elapsed() >= Duration::from_secs(1));
+
+    limiter.acquire("api/integration/job/42").await;
+    assert!(start.elapsed() >= Duration::from_secs(2));
+}
+
+#[tokio::test(start_paused = true)]
+async fn test_pause_delays_next_request() {
+    let limiter = RateLimiter::new()
+        .with_limit("api/webdl/createwebdownload", RateLimit::per_second(100))
+        .with_limit("api/webdl/mylist", RateLimit::per_second(100));
+    let start = Instant::now();
+
+    limiter.pause("api/webdl/createwebdownload", Duration::from_secs(3));
+    limiter.acquire("api/webdl/mylist").await;
+    assert!(start.elapsed() < Duration::from_millis(10));
+
+    limiter.acquire("api/webdl/createwebdownload").await;
+    assert!(start.elapsed() >= Duration::from_secs(3));
+}
+
+#[test]
+fn test_parse_retry_after() {
+    let mut headers = HeaderMap::new();
+    assert_eq!(parse_retry_after(&headers), None);
+
+    headers.insert(RETRY_AFTER, HeaderValue::from_static("120"));
+    assert_eq!(parse_retry_after(&headers), Some(Duration::from_secs(120)));
+
+    headers.insert(
+        RETRY_AFTER,
+        HeaderValue::from_static("Wed, 21 Oct 2015 07:28:00 GMT"),
+    );
+    assert_eq!(parse_retry_after(&headers), Some(Duration::ZERO));
+}
@@ -0,0 +1,54 @@
+use std::time::Duration;
+
+use reqwest::{Method, StatusCode};
+
+use crate::{error::ApiErrorCode, network::retry::RetryPolicy};
+
+fn no_jitter() -> RetryPolicy {
+    RetryPolicy {
+        jitter: false,
+        ..Default::default()
+    }
+}
+
+#[test]
+fn test_backoff_is_exponential_and_capped() {
+    let policy = no_jitter().with_backoff(Duration::from_millis(100), Duration::from_millis(350));
+
+    assert_eq!(policy.backoff(1), Duration::from_millis(100));
+    assert_eq!(policy.backoff(2), Duration::from_millis(200));
+    assert_eq!(policy.backoff(3), Duration::from_millis(350));
+    assert_eq!(policy.backoff(200), Duration::from_millis(350));
+}
+
+#[test]
+fn test_backoff_jitter_stays_in_bounds() {
+    let policy =
+        RetryPolicy::default().with_backoff(Duration::from_secs(1), Duration::from_secs(1));
+
+    for _ in 0..100 {
+        let delay = policy.backoff(1);
+        assert!(delay >= Duration::from_millis(500) && delay <= Duration::from_secs(1));
+    }
+}
+
+#[test]
+fn test_post_is_not_retried_by_default() {
+    let policy = RetryPolicy::default();
+
+    assert!(policy.should_retry_status(&Method::GET, StatusCode::BAD_GATEWAY));
+    assert!(!policy.should_retry_status(&Method::POST, StatusCode::BAD_GATEWAY));
+    assert!(!policy.should_retry_code(&Method::POST, &ApiErrorCode::DownloadServerError));
+
+    let policy = policy.with_retry_non_idempotent(true);
+    assert!(policy.should_retry_code(&Method::POST, &ApiErrorCode::DownloadServerError));
+}
+
+#[test]
+fn test_only_configured_failures_are_retried() {
+    let policy = RetryPolicy::default();
+
+    assert!(!policy.should_retry_status(&Method::GET, StatusCode::NOT_FOUND));
+    assert!(!policy.should_retry_code(&Method::GET, &ApiErrorCode::BadToken));
+    assert!(policy.should_retry_code(&Method::GET, &ApiErrorCode::NoServersAvailableError));
+}
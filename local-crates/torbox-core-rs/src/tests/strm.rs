@@ -0,0 +1,72 @@
+use std::{env, fs, path::Path};
+
+use crate::{
+    error::ApiError,
+    strm::{StrmFile, write_strm_tree},
+};
+
+#[test]
+fn test_strm_file_mirrors_file_path() {
+    let nested = StrmFile::new("Show", "Show/Season 1/E01.mkv", "u");
+    assert_eq!(nested.path, Path::new("Show/Season 1/E01.mkv.strm"));
+
+    let flat = StrmFile::new("Movie", "movie.2024.mkv", "u");
+    assert_eq!(flat.path, Path::new("Movie/movie.2024.mkv.strm"));
+
+    let dotted = StrmFile::new("Movie", "Movie.2020", "u");
+    assert_eq!(dotted.path, Path::new("Movie/Movie.2020.strm"));
+}
+
+#[test]
+fn test_video_and_subtitle_sharing_a_stem_get_their_own_file() {
+    let video = StrmFile::new("Movie", "Movie.mkv", "u");
+    let subtitle = StrmFile::new("Movie", "Movie.srt", "u");
+
+    assert_eq!(video.path, Path::new("Movie/Movie.mkv.strm"));
+    assert_eq!(subtitle.path, Path::new("Movie/Movie.srt.strm"));
+}
+
+#[test]
+fn test_strm_file_stays_inside_tree() {
+    let file = StrmFile::new("../etc", "/../passwd.mkv", "u");
+
+    assert_eq!(file.path, Path::new("etc/passwd.mkv.strm"));
+}
+
+#[tokio::test]
+async fn test_write_strm_tree_creates_folders() {
+    let root = env::temp_dir().join(format!("torbox-strm-{}", std::process::id()));
+    let files = [
+        StrmFile::new("Show", "Show/Season 1/E01.mkv", "https://example.com/1"),
+        StrmFile::new("Show", "Show/Season 1/E02.mkv", "https://example.com/2"),
+    ];
+
+    let written = write_strm_tree(&root, &files).await.unwrap();
+
+    assert_eq!(
+        written,
+        [
+            root.join("Show/Season 1/E01.mkv.strm"),
+            root.join("Show/Season 1/E02.mkv.strm")
+        ]
+    );
+    assert_eq!(
+        fs::read_to_string(&written[1]).unwrap(),
+        "https://example.com/2\n"
+    );
+    fs::remove_dir_all(root).unwrap();
+}
+
+#[tokio::test]
+async fn test_write_strm_tree_rejects_duplicate_paths() {
+    let root = env::temp_dir().join(format!("torbox-strm-dup-{}", std::process::id()));
+    let files = [
+        StrmFile::new("Show", "Show/E01.mkv", "https://example.com/1"),
+        StrmFile::new("Show", "E01.mkv", "https://example.com/2"),
+    ];
+
+    let err = write_strm_tree(&root, &files).await.unwrap_err();
+
+    assert!(matches!(err, ApiError::InvalidInput(_)), "{err:?}");
+    assert!(!root.exists());
+}
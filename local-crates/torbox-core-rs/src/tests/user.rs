@@ -33,3 +33,17 @@
 
 //     println!("Successfully gotten user profile without settings");
 // }
+
+#[cfg(feature = "specta")]
+#[test]
+fn test_seed_preference_is_exported_as_a_number() {
+    use specta::{Generics, Type, TypeCollection};
+
+    use crate::data::user::SeedPreference;
+
+    let mut types = TypeCollection::default();
+    assert_eq!(
+        SeedPreference::inline(&mut types, Generics::Definition),
+        u8::inline(&mut types, Generics::Definition)
+    );
+}
@@ -0,0 +1,65 @@
+use crate::{
+    error::ApiError,
+    validation::{validate_alternative_hash, validate_edit, validate_name, validate_url},
+};
+
+#[test]
+fn test_name_rules_are_enforced() {
+    assert!(validate_name("name", "Ubuntu 24.04 LTS").is_ok());
+    assert!(validate_name("name", &"a".repeat(200)).is_ok());
+
+    for bad in [
+        "",
+        " padded",
+        "padded ",
+        "a/b",
+        "what?",
+        "tab\tname",
+        "part #2",
+        "100%",
+        "Tom & Jerry",
+    ] {
+        assert!(
+            matches!(validate_name("name", bad), Err(ApiError::InvalidInput(_))),
+            "{bad:?} should be rejected"
+        );
+    }
+    assert!(validate_name("name", &"a".repeat(201)).is_err());
+}
+
+#[test]
+fn test_alternative_hashes_must_be_md5_sha1_or_sha256() {
+    assert!(validate_alternative_hash(&"a".repeat(32)).is_ok());
+    assert!(validate_alternative_hash("6cad4a4671eb622b279619868342171cf5ec1045").is_ok());
+    assert!(validate_alternative_hash(&"F".repeat(64)).is_ok());
+
+    assert!(validate_alternative_hash(&"a".repeat(33)).is_err());
+    assert!(validate_alternative_hash(&"g".repeat(40)).is_err());
+}
+
+#[test]
+fn test_tags_follow_the_name_rules() {
+    let tags = vec!["linux".to_string(), " iso".to_string()];
+
+    assert!(validate_edit(Some("ubuntu"), Some(&tags[..1]), None).is_ok());
+    assert!(validate_edit(None, Some(&tags), None).is_err());
+}
+
+#[test]
+fn test_url_rules_are_enforced() {
+    assert!(validate_url("url", "https://example.com/feed.xml").is_ok());
+    assert!(validate_url("url", "http://127.0.0.1:8080").is_ok());
+
+    for bad in [
+        "",
+        "example.com",
+        "ftp://example.com",
+        "https://",
+        "mailto:me@example.com",
+    ] {
+        assert!(
+            matches!(validate_url("url", bad), Err(ApiError::InvalidInput(_))),
+            "{bad:?} should be rejected"
+        );
+    }
+}
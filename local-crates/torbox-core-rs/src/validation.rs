@@ -0,0 +1,89 @@
+use reqwest::Url;
+
+use crate::error::ApiError;
+
+/// Maximum length of an item name or tag, in characters.
+pub const MAX_NAME_LENGTH: usize = 200;
+
+/// Characters that are not filesystem safe on at least one platform, or that change the meaning
+/// of a URL the name ends up in.
+const UNSAFE_CHARS: &[char] = &['/', '\\', ':', '*', '?', '"', '<', '>', '|', '#', '%', '&'];
+
+/// Checks an item name (or tag) against the rules of the `edit` endpoints.
+///
+/// A name must be 1 to 200 characters long, have no leading or trailing whitespace, and only hold
+/// filesystem and URL safe characters (no `#`, `%`, `&` or `?` either). TorBox silently strips
+/// offending characters instead of rejecting them, which is why they are refused here.
+pub fn validate_name(field: &str, name: &str) -> Result<(), ApiError> {
+    let length = name.chars().count();
+    if length == 0 || length > MAX_NAME_LENGTH {
+        return Err(ApiError::InvalidInput(format!(
+            "{field} must be between 1 and {MAX_NAME_LENGTH} characters long, got {length}"
+        )));
+    }
+
+    if name.trim() != name {
+        return Err(ApiError::InvalidInput(format!(
+            "{field} cannot have leading or trailing whitespace"
+        )));
+    }
+
+    if let Some(c) = name
+        .chars()
+        .find(|c| c.is_control() || UNSAFE_CHARS.contains(c))
+    {
+        return Err(ApiError::InvalidInput(format!(
+            "{field} cannot contain {c:?}"
+        )));
+    }
+
+    Ok(())
+}
+
+/// Checks that `url` is an absolute `http` or `https` URL with a host.
+pub fn validate_url(field: &str, url: &str) -> Result<(), ApiError> {
+    let valid = Url::parse(url)
+        .is_ok_and(|parsed| matches!(parsed.scheme(), "http" | "https") && parsed.has_host());
+
+    if valid {
+        Ok(())
+    } else {
+        Err(ApiError::InvalidInput(format!(
+            "{field} {url:?} is not an http(s) URL"
+        )))
+    }
+}
+
+/// Checks that `hash` is a hex encoded MD5, SHA1 or SHA256.
+pub fn validate_alternative_hash(hash: &str) -> Result<(), ApiError> {
+    let valid = matches!(hash.len(), 32 | 40 | 64) && hash.chars().all(|c| c.is_ascii_hexdigit());
+
+    if valid {
+        Ok(())
+    } else {
+        Err(ApiError::InvalidInput(format!(
+            "{hash:?} is not an MD5, SHA1 or SHA256 hash"
+        )))
+    }
+}
+
+/// Validates the fields shared by the torrent, web download and usenet `edit` bodies.
+pub fn validate_edit(
+    name: Option<&str>,
+    tags: Option<&[String]>,
+    alternative_hashes: Option<&[String]>,
+) -> Result<(), ApiError> {
+    if let Some(name) = name {
+        validate_name("name", name)?;
+    }
+
+    for tag in tags.unwrap_or_default() {
+        validate_name("tag", tag)?;
+    }
+
+    for hash in alternative_hashes.unwrap_or_default() {
+        validate_alternative_hash(hash)?;
+    }
+
+    Ok(())
+}
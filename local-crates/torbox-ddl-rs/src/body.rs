@@ -5,9 +5,9 @@ use crate::{
 use async_trait::async_trait;
 use reqwest::multipart::Form;
 use serde::Serialize;
-use torbox_core_rs::body::ToMultipart;
+use torbox_core_rs::{body::ToMultipart, error::ApiError, validation::validate_edit};
 
-#[derive(Debug, Serialize)]
+#[derive(Debug, Clone, Serialize)]
 #[serde(rename_all = "snake_case")]
 #[cfg_attr(feature = "specta", derive(specta::Type))]
 #[derive(Default)]
@@ -84,3 +84,31 @@ pub struct WebdownloadControlBody {
     pub source: WebdownloadControlSource,
     pub operation: WebdownloadOperation,
 }
+
+/// Request body of [`edit_item_put`](crate::WebdownloadApi::edit_item_put).
+///
+/// Fields left to `None` are not sent, the ones given overwrite the previous values entirely.
+#[derive(Debug, Serialize, Default, Clone)]
+#[cfg_attr(feature = "specta", derive(specta::Type))]
+pub struct WebdownloadEditBody {
+    /// The web download's ID that you want to edit.
+    pub webdl_id: u32,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tags: Option<Vec<String>>,
+    /// MD5, SHA1 or SHA256 hashes the item can also be found with.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub alternative_hashes: Option<Vec<String>>,
+}
+
+impl WebdownloadEditBody {
+    /// Checks the name, tags and alternative hashes against the restrictions of the endpoint.
+    pub fn validate(&self) -> Result<(), ApiError> {
+        validate_edit(
+            self.name.as_deref(),
+            self.tags.as_deref(),
+            self.alternative_hashes.as_deref(),
+        )
+    }
+}
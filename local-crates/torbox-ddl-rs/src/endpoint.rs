@@ -12,7 +12,7 @@ use torbox_core_rs::{
 };
 
 use crate::{
-    body::{WebdownloadControlReq, WebdownloadCreateBody},
+    body::{WebdownloadControlReq, WebdownloadCreateBody, WebdownloadEditBody},
     query::{
         ListWebdownloadsQuery, WebdownloadCachedAvailabilityQuery, WebdownloadRequestLinkQuery,
     },
@@ -74,3 +74,12 @@ impl EndpointSpec for WebdownloadHosterListGetEp {
     const PATH: &'static str = "api/webdl/hosters";
     const METHOD: Method = Method::GET;
 }
+
+pub struct WebdownloadEditPutEp;
+
+impl EndpointSpec for WebdownloadEditPutEp {
+    type Req = WebdownloadEditBody;
+    type Resp = ();
+    const PATH: &'static str = "api/webdl/editwebdownload";
+    const METHOD: Method = Method::PUT;
+}
@@ -1,6 +1,7 @@
+use futures::Stream;
 use torbox_core_rs::{
     api::ApiResponse,
-    client::{Endpoint, EndpointSpec, TorboxClient},
+    client::{Endpoint, TorboxClient},
     data::{
         creation::DownloadLinkResponse,
         webdownload::{
@@ -10,13 +11,15 @@ use torbox_core_rs::{
     },
     enums::OneOrMany,
     error::ApiError,
+    link::LinkBuilder,
+    pagination::{Pagination, paginate},
 };
 
 use crate::{
-    body::{WebdownloadControlReq, WebdownloadCreateBody},
+    body::{WebdownloadControlReq, WebdownloadCreateBody, WebdownloadEditBody},
     endpoint::{
         ListWebdownloadsGetEp, WebdownloadCachedAvailabilityGetEp, WebdownloadControlPostEp,
-        WebdownloadCreatePostEp, WebdownloadHosterListGetEp, WebdownloadRequestLinkGetEp,
+        WebdownloadCreatePostEp, WebdownloadEditPutEp, WebdownloadHosterListGetEp,
     },
     query::{
         ListWebdownloadsQuery, WebdownloadCachedAvailabilityQuery, WebdownloadRequestLinkQuery,
@@ -27,6 +30,7 @@ use crate::{
 pub mod body;
 pub mod endpoint;
 pub mod payload;
+pub mod permalink;
 pub mod query;
 pub mod tests;
 pub mod types;
@@ -58,6 +62,11 @@ impl<'a> WebdownloadApi<'a> {
             .await
     }
 
+    /// Starts building download links or shareable permalinks for a web download, see [`LinkBuilder`].
+    pub fn link_builder(&self, web_id: u32) -> LinkBuilder<'a> {
+        LinkBuilder::webdownload(self.client, web_id)
+    }
+
     /// Requests a download link for a torrent
     ///
     /// Links are valid for 3 hours. Once downloading starts, the transfer
@@ -66,6 +75,9 @@ impl<'a> WebdownloadApi<'a> {
     ///
     /// Setting `redirect = true` truly helps torbox servers not to be overwhelmed.
     ///
+    /// Several `files_id` give several links, which this call rejects, get them with
+    /// [`link_builder`](Self::link_builder) instead.
+    ///
     /// # Arguments
     ///
     /// * `query` - Contains torrent ID and download options
@@ -77,35 +89,15 @@ impl<'a> WebdownloadApi<'a> {
         &self,
         query: WebdownloadRequestLinkQuery,
     ) -> Result<DownloadLinkResponse, ApiError> {
-        let endpoint = format!(
-            "{}/{}",
-            self.client.base_url,
-            WebdownloadRequestLinkGetEp::PATH
-        );
-        let request = self.client.client.get(&endpoint).query(&query);
-
-        let response = request.send().await?;
-
-        if query.redirect {
-            if response.status().is_redirection() {
-                let location = response
-                    .headers()
-                    .get("Location")
-                    .ok_or(ApiError::RedirectError("Missing Location header".into()))?
-                    .to_str()
-                    .map_err(|_| ApiError::RedirectError("Invalid Location header".into()))?;
-
-                Ok(DownloadLinkResponse::Redirect(location.to_string()))
-            } else {
-                match response.json::<ApiResponse<String>>().await {
-                    Ok(json) => Ok(DownloadLinkResponse::Json(json)),
-                    Err(_) => Err(ApiError::UnexpectedPayload),
-                }
-            }
-        } else {
-            let json = response.json::<ApiResponse<String>>().await?;
-            Ok(DownloadLinkResponse::Json(json))
+        let mut builder = self.link_builder(query.web_id);
+        if !query.zip_link {
+            builder = builder.files(query.files_id.into_iter().flatten().map(u64::from));
+        }
+        if let Some(user_ip) = query.user_ip {
+            builder = builder.user_ip(user_ip);
         }
+
+        builder.request(query.redirect).await
     }
 
     /// Gets the user's torrent list. This gives you the needed information to perform other torrent actions.
@@ -134,6 +126,26 @@ impl<'a> WebdownloadApi<'a> {
         Ok(normalized)
     }
 
+    /// Streams every web download of the user, requesting the list page by page.
+    pub fn list_all(
+        &self,
+        pagination: Pagination,
+    ) -> impl Stream<Item = Result<WebdownloadStatus, ApiError>> + use<'a> {
+        let client = self.client;
+
+        paginate(pagination, move |offset, limit| async move {
+            let mut query = ListWebdownloadsQuery::default();
+            query.bypass_cache = Some(pagination.bypass_cache);
+            query.offset = Some(offset);
+            query.limit = Some(limit);
+            let page = WebdownloadApi::new(client)
+                .list_query(query)
+                .await?
+                .into_data()?;
+            Ok(page.unwrap_or_default())
+        })
+    }
+
     pub async fn is_cached(
         &self,
         query: WebdownloadCachedAvailabilityQuery,
@@ -174,9 +186,6 @@ impl<'a> WebdownloadApi<'a> {
             .await
     }
 
-    /// ## TODO:
-    /// This function hasn't yet been made due to laziness.
-    ///
     /// ## DANGEROUS
     ///
     /// ## Overview
@@ -194,5 +203,17 @@ impl<'a> WebdownloadApi<'a> {
     ///
     /// - Tags follow the same rules as the name.
     /// - Alternative hashes must be of MD5, SHA1, or SHA256.
-    pub async fn edit_item_put(&self) {}
+    ///
+    /// The body is validated before being sent, an invalid one returns `ApiError::InvalidInput`
+    /// instead of letting TorBox silently strip the offending characters.
+    pub async fn edit_item_put(
+        &self,
+        body: WebdownloadEditBody,
+    ) -> Result<ApiResponse<()>, ApiError> {
+        body.validate()?;
+
+        Endpoint::<WebdownloadEditPutEp>::new(self.client)
+            .call_json(body)
+            .await
+    }
 }
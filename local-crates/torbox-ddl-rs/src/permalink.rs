@@ -0,0 +1,45 @@
+//! Permalinks and `.strm` trees for media servers, built without calling the API.
+
+use std::path::Path;
+
+use torbox_core_rs::{
+    client::TorboxClient,
+    data::webdownload::WebdownloadStatus,
+    error::ApiError,
+    link::LinkBuilder,
+    strm::{self, StrmReport, StrmTree},
+};
+
+/// Builds the `requestdl` permalink of a web download file, or of its zip without `file_id`.
+///
+/// It redirects to a fresh CDN link whenever it is opened and carries the client's token, see
+/// [`LinkBuilder::permalink`].
+pub fn permalink(
+    client: &TorboxClient,
+    web_id: u32,
+    file_id: Option<u64>,
+) -> Result<String, ApiError> {
+    let builder = LinkBuilder::webdownload(client, web_id);
+    match file_id {
+        Some(file_id) => builder.file(file_id).permalink(),
+        None => builder.permalink(),
+    }
+}
+
+/// A `.strm` file per file of every web download, under a folder named after the download and
+/// mirroring the file paths.
+pub fn strm_files(
+    client: &TorboxClient,
+    downloads: &[WebdownloadStatus],
+) -> Result<StrmTree, ApiError> {
+    strm::strm_files(client, downloads)
+}
+
+/// Writes the [`strm_files`] of `downloads` under `root`, reporting the paths written.
+pub async fn write_webdownloads_strm(
+    client: &TorboxClient,
+    downloads: &[WebdownloadStatus],
+    root: impl AsRef<Path>,
+) -> Result<StrmReport, ApiError> {
+    strm::write_strm_sources(client, downloads, root).await
+}
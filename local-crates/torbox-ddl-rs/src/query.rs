@@ -1,12 +1,10 @@
 use crate::types::FormatType;
 use serde::Serialize;
+use torbox_core_rs::data::hash::InfoHash;
 
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, Default)]
 #[cfg_attr(feature = "specta", derive(specta::Type))]
 pub struct WebdownloadRequestLinkQuery {
-    /// Hidden and not shown by specta because token is needed for queries here :(
-    #[cfg_attr(feature = "specta", specta(skip))]
-    pub(crate) token: String,
     /// The torrent's ID that you want to download
     pub web_id: u32,
     /// The files's ID that you want to download.
@@ -23,37 +21,16 @@ pub struct WebdownloadRequestLinkQuery {
     pub redirect: bool,
 }
 
-impl Default for WebdownloadRequestLinkQuery {
-    fn default() -> Self {
-        Self {
-            token: String::new(),
-            web_id: 0,
-            files_id: None,
-            zip_link: false,
-            user_ip: None,
-            redirect: false,
-        }
-    }
-}
-
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, Default)]
 #[cfg_attr(feature = "specta", derive(specta::Type))]
 pub struct WebdownloadControlQuery {
     pub bypass_cache: bool,
 }
 
-impl Default for WebdownloadControlQuery {
-    fn default() -> Self {
-        Self {
-            bypass_cache: false,
-        }
-    }
-}
-
 /// `id` param isn't given because if it is, it will return an Object and not a Vec
 ///
 /// Please use TorrentStatusQuery instead
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, Default)]
 #[cfg_attr(feature = "specta", derive(specta::Type))]
 pub struct ListWebdownloadsQuery {
     id: Option<u32>,
@@ -72,30 +49,20 @@ pub struct ListWebdownloadsQuery {
     pub limit: Option<u32>,
 }
 
-impl Default for ListWebdownloadsQuery {
-    fn default() -> Self {
-        Self {
-            id: None,
-            bypass_cache: None,
-            offset: None,
-            limit: None,
-        }
-    }
-}
-
 #[derive(Debug, Serialize)]
 #[cfg_attr(feature = "specta", derive(specta::Type))]
 pub struct WebdownloadCachedAvailabilityQuery {
     #[serde(serialize_with = "serialize_comma_separated")]
-    pub hash: Vec<String>,
+    pub hash: Vec<InfoHash>,
     pub format: FormatType,
 }
 
-fn serialize_comma_separated<S>(vec: &[String], serializer: S) -> Result<S::Ok, S::Error>
+fn serialize_comma_separated<S>(vec: &[InfoHash], serializer: S) -> Result<S::Ok, S::Error>
 where
     S: serde::Serializer,
 {
-    serializer.serialize_str(&vec.join(","))
+    let joined: Vec<&str> = vec.iter().map(InfoHash::as_str).collect();
+    serializer.serialize_str(&joined.join(","))
 }
 
 impl Default for WebdownloadCachedAvailabilityQuery {
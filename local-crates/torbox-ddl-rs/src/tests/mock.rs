@@ -0,0 +1,407 @@
+use std::path::{Path, PathBuf};
+
+use futures::StreamExt;
+use torbox_core_rs::{
+    data::{creation::DownloadLinkResponse, hash::InfoHash, webdownload::WebdownloadHosterKind},
+    download::{DownloadTarget, Downloader},
+    error::ApiError,
+    pagination::Pagination,
+};
+use torbox_mock::{Injection, MockServer, file_content};
+
+use crate::{
+    WebdownloadApi,
+    body::{WebdownloadControlReq, WebdownloadCreateBody, WebdownloadEditBody},
+    permalink::{permalink, strm_files},
+    query::{
+        ListWebdownloadsQuery, WebdownloadCachedAvailabilityQuery, WebdownloadRequestLinkQuery,
+    },
+    types::WebdownloadControlSource,
+};
+
+#[tokio::test]
+async fn test_create_then_list_webdownload() {
+    let server = MockServer::start().await;
+    let client = server.client();
+    let api = WebdownloadApi::new(&client);
+
+    let created = api
+        .create(WebdownloadCreateBody {
+            link: "https://example.com/files/archive.zip".into(),
+            ..Default::default()
+        })
+        .await
+        .unwrap()
+        .into_data()
+        .unwrap();
+
+    let list = api
+        .list_query(ListWebdownloadsQuery::default())
+        .await
+        .unwrap()
+        .into_data()
+        .unwrap()
+        .unwrap();
+
+    assert_eq!(list.len(), 1);
+    assert_eq!(list[0].id, created.webdownload_id as u64);
+    assert_eq!(list[0].name, "archive.zip");
+    assert_eq!(list[0].hash, created.hash);
+    assert_eq!(created.hash, "fdd49de837474037b86acabda3eb91a8");
+}
+
+#[tokio::test]
+async fn test_control_deletes_every_webdownload() {
+    let server = MockServer::start().await;
+    let first = server
+        .state()
+        .add_webdownload("a", "https://example.com/a", &[("a.bin", 1)]);
+    let second = server
+        .state()
+        .add_webdownload("b", "https://example.com/b", &[("b.bin", 1)]);
+    let client = server.client();
+    let api = WebdownloadApi::new(&client);
+
+    let resp = api
+        .control(WebdownloadControlReq {
+            source: WebdownloadControlSource::All(true),
+            ..Default::default()
+        })
+        .await
+        .unwrap();
+
+    assert!(resp.success);
+    assert!(server.state().webdownload(first).is_none());
+    assert!(server.state().webdownload(second).is_none());
+}
+
+#[tokio::test]
+async fn test_request_download_link_redirects() {
+    let server = MockServer::start().await;
+    let id = server
+        .state()
+        .add_webdownload("a", "https://example.com/a", &[("a.bin", 1)]);
+    let client = server.client();
+    let api = WebdownloadApi::new(&client);
+
+    let link = api
+        .request_download_link(WebdownloadRequestLinkQuery {
+            web_id: id as u32,
+            zip_link: true,
+            redirect: true,
+            ..Default::default()
+        })
+        .await
+        .unwrap();
+
+    assert!(matches!(link, DownloadLinkResponse::Redirect(url) if url.starts_with("http")));
+}
+
+fn temp_path(name: &str) -> PathBuf {
+    std::env::temp_dir().join(format!("torbox-ddl-{}-{name}", std::process::id()))
+}
+
+/// Adds a web download with a single 10 000 bytes file and returns its id and download target.
+async fn webdownload_target(server: &MockServer) -> (u64, DownloadTarget) {
+    let id = server
+        .state()
+        .add_webdownload("a", "https://example.com/a", &[("a.bin", 10_000)]);
+    let client = server.client();
+    let list = WebdownloadApi::new(&client)
+        .list_query(ListWebdownloadsQuery::default())
+        .await
+        .unwrap()
+        .into_data()
+        .unwrap()
+        .unwrap();
+
+    (
+        id,
+        DownloadTarget::webdownload_file(id as u32, &list[0].files[0]),
+    )
+}
+
+#[tokio::test]
+async fn test_downloader_verifies_size_and_md5() {
+    let server = MockServer::start().await;
+    let (_, target) = webdownload_target(&server).await;
+    let client = server.client();
+    let dest = temp_path("verified.bin");
+
+    let mut progress = Vec::new();
+    let report = Downloader::new(&client)
+        .download(&target, &dest, |p| progress.push(p))
+        .await
+        .unwrap();
+
+    assert_eq!(report.size, 10_000);
+    assert!(report.md5_verified);
+    assert_eq!(progress.last().unwrap().downloaded, 10_000);
+    assert_eq!(std::fs::read(&dest).unwrap(), file_content(10_000));
+    std::fs::remove_file(dest).ok();
+}
+
+#[tokio::test]
+async fn test_downloader_follows_relative_redirect() {
+    let server = MockServer::start().await;
+    let (id, target) = webdownload_target(&server).await;
+    let client = server.client();
+    let dest = temp_path("redirected.bin");
+    server.state().inject(
+        format!("cdn/webdl/{id}/0"),
+        Injection::Redirect {
+            location: "0?redirected=true".into(),
+        },
+    );
+
+    let report = Downloader::new(&client)
+        .download(&target, &dest, |_| {})
+        .await
+        .unwrap();
+
+    assert!(report.md5_verified);
+    let redirected = server.state().requests().pop().unwrap();
+    assert_eq!(redirected.path, format!("cdn/webdl/{id}/0"));
+    assert_eq!(redirected.query.as_deref(), Some("redirected=true"));
+    std::fs::remove_file(dest).ok();
+}
+
+#[tokio::test]
+async fn test_downloader_resumes_part_file() {
+    let server = MockServer::start().await;
+    let (_, target) = webdownload_target(&server).await;
+    let client = server.client();
+    let dest = temp_path("resumed.bin");
+    let mut part = dest.clone().into_os_string();
+    part.push(".part");
+    std::fs::write(&part, &file_content(10_000)[..4_000]).unwrap();
+
+    let report = Downloader::new(&client)
+        .download(&target, &dest, |_| {})
+        .await
+        .unwrap();
+
+    assert_eq!(report.resumed_from, 4_000);
+    assert_eq!(std::fs::read(&dest).unwrap(), file_content(10_000));
+    std::fs::remove_file(dest).ok();
+}
+
+#[tokio::test]
+async fn test_downloader_restarts_when_part_file_is_too_large() {
+    let server = MockServer::start().await;
+    let id = server
+        .state()
+        .add_webdownload("a", "https://example.com/a", &[("a.bin", 10_000)]);
+    let client = server.client();
+    let dest = temp_path("stale.bin");
+    let mut part = dest.clone().into_os_string();
+    part.push(".part");
+    std::fs::write(&part, vec![0; 12_000]).unwrap();
+
+    // Neither the size nor the MD5 is known, the 416 answer has to be checked
+    let target = DownloadTarget::webdownload(id as u32, Some(0));
+    let report = Downloader::new(&client)
+        .download(&target, &dest, |_| {})
+        .await
+        .unwrap();
+
+    assert_eq!(report.size, 10_000);
+    assert_eq!(std::fs::read(&dest).unwrap(), file_content(10_000));
+    assert_eq!(
+        server.state().request_count(&format!("cdn/webdl/{id}/0")),
+        2
+    );
+    std::fs::remove_file(dest).ok();
+}
+
+#[tokio::test]
+async fn test_downloader_resumes_after_interruption() {
+    let server = MockServer::start().await;
+    let (id, target) = webdownload_target(&server).await;
+    let cdn_path = format!("cdn/webdl/{id}/0");
+    server
+        .state()
+        .inject(cdn_path.clone(), Injection::Interrupt { after: 3_000 });
+    let client = server.client();
+    let dest = temp_path("interrupted.bin");
+
+    let report = Downloader::new(&client)
+        .download(&target, &dest, |_| {})
+        .await
+        .unwrap();
+
+    assert!(report.md5_verified);
+    assert_eq!(server.state().request_count(&cdn_path), 2);
+    assert_eq!(std::fs::read(&dest).unwrap(), file_content(10_000));
+    std::fs::remove_file(dest).ok();
+}
+
+#[tokio::test]
+async fn test_downloader_rejects_wrong_md5() {
+    let server = MockServer::start().await;
+    let (_, target) = webdownload_target(&server).await;
+    let client = server.client();
+    let dest = temp_path("corrupted.bin");
+
+    let err = Downloader::new(&client)
+        .download(&target.with_md5("0".repeat(32)), &dest, |_| {})
+        .await
+        .unwrap_err();
+
+    assert!(matches!(err, ApiError::ChecksumMismatch { .. }));
+    assert!(!dest.exists());
+}
+
+#[tokio::test]
+async fn test_list_all_prefetches_pages() {
+    let server = MockServer::start().await;
+    for i in 0..5 {
+        server.state().add_webdownload(
+            &format!("archive-{i}"),
+            "https://example.com/archive.zip",
+            &[("archive.zip", 1024)],
+        );
+    }
+    let client = server.client();
+    let api = WebdownloadApi::new(&client);
+
+    let items: Vec<_> = api
+        .list_all(Pagination::default().with_page_size(2).with_prefetch(3))
+        .map(Result::unwrap)
+        .collect()
+        .await;
+
+    assert_eq!(items.len(), 5);
+    assert!(items.windows(2).all(|w| w[0].id < w[1].id));
+}
+
+#[tokio::test]
+async fn test_edit_item_put_renames_and_tags() {
+    let server = MockServer::start().await;
+    let id = server.state().add_webdownload(
+        "archive",
+        "https://example.com/archive.zip",
+        &[("archive.zip", 1024)],
+    );
+    let client = server.client();
+    let api = WebdownloadApi::new(&client);
+
+    let resp = api
+        .edit_item_put(WebdownloadEditBody {
+            webdl_id: id as u32,
+            name: Some("Renamed archive".into()),
+            tags: Some(vec!["backup".into()]),
+            alternative_hashes: Some(vec!["d41d8cd98f00b204e9800998ecf8427e".into()]),
+        })
+        .await
+        .unwrap();
+
+    assert!(resp.success);
+    let item = server.state().webdownload(id).unwrap();
+    assert_eq!(item["name"], "Renamed archive");
+    assert_eq!(item["tags"][0], "backup");
+}
+
+#[tokio::test]
+async fn test_edit_item_put_rejects_unsafe_name_locally() {
+    let server = MockServer::start().await;
+    let client = server.client();
+    let api = WebdownloadApi::new(&client);
+
+    let err = api
+        .edit_item_put(WebdownloadEditBody {
+            webdl_id: 1,
+            name: Some("../etc/passwd".into()),
+            ..Default::default()
+        })
+        .await
+        .unwrap_err();
+
+    assert!(matches!(err, ApiError::InvalidInput(_)));
+    assert_eq!(server.state().request_count("api/webdl/editwebdownload"), 0);
+}
+
+#[tokio::test]
+async fn test_strm_files_point_to_permalinks() {
+    let server = MockServer::start().await;
+    let id = server.state().add_webdownload(
+        "Movie",
+        "https://example.com/movie",
+        &[("movie.mkv", 1), ("subs/movie.en.srt", 2)],
+    );
+    let client = server.client();
+    let downloads = WebdownloadApi::new(&client)
+        .list_query(ListWebdownloadsQuery::default())
+        .await
+        .unwrap()
+        .into_data()
+        .unwrap()
+        .unwrap();
+
+    let tree = strm_files(&client, &downloads).unwrap();
+    let files = tree.files;
+
+    assert_eq!(files.len(), 2);
+    assert!(tree.skipped.is_empty());
+    assert_eq!(files[0].path, Path::new("Movie/movie.mkv.strm"));
+    assert_eq!(files[1].path, Path::new("Movie/subs/movie.en.srt.strm"));
+    assert_eq!(
+        files[1].url,
+        format!(
+            "{}/api/webdl/requestdl?token={}&web_id={id}&file_id=1&redirect=true",
+            server.base_url(),
+            server.token()
+        )
+    );
+    assert_eq!(
+        files[1].url,
+        permalink(&client, id as u32, Some(1)).unwrap()
+    );
+}
+
+#[tokio::test]
+async fn test_check_cached_finds_stored_webdownloads() {
+    let server = MockServer::start().await;
+    let client = server.client().with_strict_errors(true);
+    let api = WebdownloadApi::new(&client);
+    let id =
+        server
+            .state()
+            .add_webdownload("file", "https://example.com/file", &[("file.bin", 64)]);
+    let stored = server.state().webdownload(id).unwrap();
+
+    let query = WebdownloadCachedAvailabilityQuery {
+        hash: vec![
+            InfoHash::new(stored["hash"].as_str().unwrap()).unwrap(),
+            InfoHash::new(&"f".repeat(32)).unwrap(),
+        ],
+        ..Default::default()
+    };
+    let cached = api
+        .is_cached(query)
+        .await
+        .unwrap()
+        .into_data()
+        .unwrap()
+        .unwrap();
+    assert_eq!(cached.len(), 1);
+    assert_eq!(cached[0].name, "file");
+    assert_eq!(cached[0].size, 64);
+}
+
+#[tokio::test]
+async fn test_list_hosters() {
+    let server = MockServer::start().await;
+    let client = server.client().with_strict_errors(true);
+
+    let hosters = WebdownloadApi::new(&client)
+        .list_hosters()
+        .await
+        .unwrap()
+        .into_data()
+        .unwrap();
+    assert_eq!(hosters.len(), 1);
+    assert!(hosters[0].status);
+    assert!(matches!(hosters[0].kind, WebdownloadHosterKind::Hoster));
+    assert!(hosters[0].domains.contains(&"example.com".to_string()));
+}
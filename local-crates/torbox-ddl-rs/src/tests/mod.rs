@@ -1,3 +1,6 @@
+#[cfg(test)]
+mod mock;
+
 #[cfg(test)]
 pub mod webdownload_test {
     use crate::{
@@ -113,7 +116,6 @@ pub mod webdownload_test {
         let client = test_client();
         let api = WebdownloadApi::new(&client);
         let query = WebdownloadRequestLinkQuery {
-            token: load_token_from_file().expect("Missing TORBOX_TOKEN in .token file"),
             web_id: 1,
             redirect: false,
             ..Default::default()
@@ -132,7 +134,6 @@ pub mod webdownload_test {
         let client = test_client();
         let api = WebdownloadApi::new(&client);
         let query = WebdownloadRequestLinkQuery {
-            token: load_token_from_file().expect("Missing TORBOX_TOKEN in .token file"),
             web_id: 1,
             redirect: true,
             ..Default::default()
@@ -1,5 +1,4 @@
-use serde::{Deserialize, Serialize};
-use torbox_core_rs::api::ApiResponse;
+use serde::Serialize;
 
 #[derive(Debug, Serialize)]
 #[serde(rename_all = "snake_case")]
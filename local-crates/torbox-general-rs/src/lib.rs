@@ -10,7 +10,6 @@ use torbox_core_rs::{
 use crate::{
     endpoint::{
         GetChangelogJsonVersionsEp, GetChangelogRssFeedEp, GetSpeedtestFilesEp, GetStatsEp,
-        GetUpStatusEp,
     },
     query::SpeedTestQuery,
     types::FileLength,
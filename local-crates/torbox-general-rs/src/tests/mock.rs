@@ -0,0 +1,66 @@
+use torbox_mock::MockServer;
+
+use crate::{GeneralApi, types::FileLength};
+
+#[tokio::test]
+async fn test_stats_count_stored_downloads() {
+    let server = MockServer::start().await;
+    let client = server.client().with_strict_errors(true);
+    server
+        .state()
+        .add_torrent("ubuntu", &[("ubuntu.iso", 4096)]);
+
+    let stats = GeneralApi::new(&client)
+        .get_stats()
+        .await
+        .unwrap()
+        .into_data()
+        .unwrap();
+    assert_eq!(stats.active_torrents, Some(1));
+    assert_eq!(stats.active_web_downloads, Some(0));
+}
+
+#[tokio::test]
+async fn test_changelogs_in_json_and_rss() {
+    let server = MockServer::start().await;
+    let client = server.client().with_strict_errors(true);
+    let api = GeneralApi::new(&client);
+
+    let versions = api
+        .get_changelog_json_versions()
+        .await
+        .unwrap()
+        .into_data()
+        .unwrap();
+    assert_eq!(versions.len(), 1);
+
+    let feed = api.get_changelog_rss_feed().await.unwrap();
+    assert!(feed.starts_with("<?xml"));
+    assert!(feed.contains(&format!("<title>{}</title>", versions[0].name)));
+}
+
+#[tokio::test]
+async fn test_speedtest_files_by_region_and_length() {
+    let server = MockServer::start().await;
+    let client = server.client().with_strict_errors(true);
+    let api = GeneralApi::new(&client);
+
+    let all = api
+        .get_speedtest_files(None, None)
+        .await
+        .unwrap()
+        .into_data()
+        .unwrap();
+    assert_eq!(all.len(), 2);
+    assert_eq!(all.iter().filter(|file| file.closest).count(), 1);
+
+    let eu = api
+        .get_speedtest_files(Some(FileLength::Short), Some("eu".into()))
+        .await
+        .unwrap()
+        .into_data()
+        .unwrap();
+    assert_eq!(eu.len(), 1);
+    assert_eq!(eu[0].region, "eu");
+    assert!(eu[0].url.ends_with("/short.bin"));
+}
@@ -1,8 +1,11 @@
+#[cfg(test)]
+mod mock;
+
 #[cfg(test)]
 pub mod general_test {
     use std::env;
 
-    use crate::{GeneralApi, query::SpeedTestQuery, types::FileLength};
+    use crate::{GeneralApi, types::FileLength};
 
     use dotenvy::from_filename;
     use torbox_core_rs::client::TorboxClient;
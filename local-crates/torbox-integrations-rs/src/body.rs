@@ -0,0 +1,44 @@
+use serde::Serialize;
+use torbox_core_rs::data::user::JobItemType;
+
+use crate::types::IntegrationDestination;
+
+/// Request body of [`queue_transfer`](crate::IntegrationsApi::queue_transfer).
+#[derive(Debug, Clone, Serialize)]
+#[cfg_attr(feature = "specta", derive(specta::Type))]
+pub struct IntegrationTransferBody {
+    /// The id of the torrent, web download or usenet download to transfer.
+    pub id: u32,
+    /// The file to transfer, every file is sent if none is given.
+    pub file_id: Option<u32>,
+    /// Whether to upload the files as a single zip. Ignored when `file_id` is given.
+    pub zip: bool,
+    #[serde(rename = "type")]
+    pub item_type: JobItemType,
+    /// Provider and its token, sent as `<provider>_token`.
+    #[serde(flatten)]
+    pub destination: IntegrationDestination,
+}
+
+impl IntegrationTransferBody {
+    /// A body transferring every file of the item, unzipped.
+    pub fn new(item_type: JobItemType, id: u32, destination: IntegrationDestination) -> Self {
+        Self {
+            id,
+            file_id: None,
+            zip: false,
+            item_type,
+            destination,
+        }
+    }
+
+    pub fn with_file(mut self, file_id: u32) -> Self {
+        self.file_id = Some(file_id);
+        self
+    }
+
+    pub fn with_zip(mut self, zip: bool) -> Self {
+        self.zip = zip;
+        self
+    }
+}
@@ -0,0 +1,88 @@
+use reqwest::Method;
+use torbox_core_rs::{client::EndpointSpec, data::user::Job};
+
+use crate::{body::IntegrationTransferBody, payload::IntegrationQueuePayload};
+
+pub struct GoogleDrivePostEp;
+
+impl EndpointSpec for GoogleDrivePostEp {
+    type Req = IntegrationTransferBody;
+    type Resp = IntegrationQueuePayload;
+    const PATH: &'static str = "api/integration/googledrive";
+    const METHOD: Method = Method::POST;
+}
+
+pub struct DropboxPostEp;
+
+impl EndpointSpec for DropboxPostEp {
+    type Req = IntegrationTransferBody;
+    type Resp = IntegrationQueuePayload;
+    const PATH: &'static str = "api/integration/dropbox";
+    const METHOD: Method = Method::POST;
+}
+
+pub struct OneDrivePostEp;
+
+impl EndpointSpec for OneDrivePostEp {
+    type Req = IntegrationTransferBody;
+    type Resp = IntegrationQueuePayload;
+    const PATH: &'static str = "api/integration/onedrive";
+    const METHOD: Method = Method::POST;
+}
+
+pub struct GoFilePostEp;
+
+impl EndpointSpec for GoFilePostEp {
+    type Req = IntegrationTransferBody;
+    type Resp = IntegrationQueuePayload;
+    const PATH: &'static str = "api/integration/gofile";
+    const METHOD: Method = Method::POST;
+}
+
+pub struct OneFichierPostEp;
+
+impl EndpointSpec for OneFichierPostEp {
+    type Req = IntegrationTransferBody;
+    type Resp = IntegrationQueuePayload;
+    const PATH: &'static str = "api/integration/1fichier";
+    const METHOD: Method = Method::POST;
+}
+
+pub struct ListJobsGetEp;
+
+impl EndpointSpec for ListJobsGetEp {
+    type Req = ();
+    type Resp = Vec<Job>;
+    const PATH: &'static str = "api/integration/jobs";
+    const METHOD: Method = Method::GET;
+}
+
+/// `{job_id}` is appended to the path, see [`IntegrationsApi::get_job`](crate::IntegrationsApi::get_job).
+pub struct JobGetEp;
+
+impl EndpointSpec for JobGetEp {
+    type Req = ();
+    type Resp = Job;
+    const PATH: &'static str = "api/integration/job";
+    const METHOD: Method = Method::GET;
+}
+
+/// `{job_id}` is appended to the path, see [`IntegrationsApi::cancel_job`](crate::IntegrationsApi::cancel_job).
+pub struct JobDeleteEp;
+
+impl EndpointSpec for JobDeleteEp {
+    type Req = ();
+    type Resp = ();
+    const PATH: &'static str = "api/integration/job";
+    const METHOD: Method = Method::DELETE;
+}
+
+/// `{hash}` is appended to the path, see [`IntegrationsApi::jobs_for_hash`](crate::IntegrationsApi::jobs_for_hash).
+pub struct JobsByHashGetEp;
+
+impl EndpointSpec for JobsByHashGetEp {
+    type Req = ();
+    type Resp = Vec<Job>;
+    const PATH: &'static str = "api/integration/jobs";
+    const METHOD: Method = Method::GET;
+}
@@ -0,0 +1,140 @@
+pub mod body;
+pub mod endpoint;
+pub mod payload;
+pub mod tests;
+pub mod types;
+
+use std::time::Duration;
+
+use futures::{Stream, stream};
+use torbox_core_rs::{
+    api::ApiResponse,
+    client::{Endpoint, EndpointSpec, TorboxClient},
+    data::{hash::InfoHash, user::Job},
+    error::ApiError,
+};
+
+use crate::{
+    body::IntegrationTransferBody,
+    endpoint::{
+        DropboxPostEp, GoFilePostEp, GoogleDrivePostEp, JobDeleteEp, JobGetEp, JobsByHashGetEp,
+        ListJobsGetEp, OneDrivePostEp, OneFichierPostEp,
+    },
+    payload::IntegrationQueuePayload,
+    types::IntegrationDestination,
+};
+
+/// Main interface for TorBox cloud integrations
+///
+/// Provides methods for all integration-related API calls including:
+/// - Queueing the upload of a torrent, web download or usenet download to Google Drive, Dropbox,
+///   OneDrive, GoFile or 1fichier
+/// - Listing, polling and cancelling the resulting jobs
+#[cfg_attr(feature = "specta", derive(specta::Type))]
+pub struct IntegrationsApi<'a> {
+    client: &'a TorboxClient,
+}
+
+impl<'a> IntegrationsApi<'a> {
+    pub fn new(client: &'a TorboxClient) -> Self {
+        Self { client }
+    }
+
+    /// Queues the transfer of a finished download to the provider of `body.destination`.
+    pub async fn queue_transfer(
+        &self,
+        body: IntegrationTransferBody,
+    ) -> Result<ApiResponse<IntegrationQueuePayload>, ApiError> {
+        match body.destination {
+            IntegrationDestination::GoogleDrive(_) => {
+                Endpoint::<GoogleDrivePostEp>::new(self.client)
+                    .call_json(body)
+                    .await
+            }
+            IntegrationDestination::Dropbox(_) => {
+                Endpoint::<DropboxPostEp>::new(self.client)
+                    .call_json(body)
+                    .await
+            }
+            IntegrationDestination::OneDrive(_) => {
+                Endpoint::<OneDrivePostEp>::new(self.client)
+                    .call_json(body)
+                    .await
+            }
+            IntegrationDestination::GoFile(_) => {
+                Endpoint::<GoFilePostEp>::new(self.client)
+                    .call_json(body)
+                    .await
+            }
+            IntegrationDestination::OneFichier(_) => {
+                Endpoint::<OneFichierPostEp>::new(self.client)
+                    .call_json(body)
+                    .await
+            }
+        }
+    }
+
+    /// Gets every integration job of the user.
+    pub async fn list_jobs(&self) -> Result<ApiResponse<Vec<Job>>, ApiError> {
+        Endpoint::<ListJobsGetEp>::new(self.client)
+            .call_query(())
+            .await
+    }
+
+    /// Gets a single job, to follow its progress.
+    pub async fn get_job(&self, id: u64) -> Result<ApiResponse<Job>, ApiError> {
+        let path = format!("{}/{id}", JobGetEp::PATH);
+        let resp = self
+            .client
+            .request_with_query(JobGetEp::METHOD, &path, &())
+            .await?;
+        self.client.checked(resp)
+    }
+
+    /// Cancels a job that has not finished yet.
+    pub async fn cancel_job(&self, id: u64) -> Result<ApiResponse<()>, ApiError> {
+        let path = format!("{}/{id}", JobDeleteEp::PATH);
+        let resp = self
+            .client
+            .request_with_query(JobDeleteEp::METHOD, &path, &())
+            .await?;
+        self.client.checked(resp)
+    }
+
+    /// Gets the jobs transferring the download identified by `hash`.
+    pub async fn jobs_for_hash(&self, hash: &InfoHash) -> Result<ApiResponse<Vec<Job>>, ApiError> {
+        let path = format!("{}/{hash}", JobsByHashGetEp::PATH);
+        let resp = self
+            .client
+            .request_with_query(JobsByHashGetEp::METHOD, &path, &())
+            .await?;
+        self.client.checked(resp)
+    }
+
+    /// Polls a job every `interval`, yielding a snapshot per poll until its status is finished.
+    ///
+    /// The stream ends after the first error.
+    pub fn watch_job(
+        &self,
+        id: u64,
+        interval: Duration,
+    ) -> impl Stream<Item = Result<Job, ApiError>> + use<'a> {
+        let client = self.client;
+
+        // `None` once the watch is over, `Some(false)` before the first poll.
+        stream::unfold(Some(false), move |polled| async move {
+            if polled? {
+                tokio::time::sleep(interval).await;
+            }
+
+            let api = IntegrationsApi::new(client);
+            match api.get_job(id).await.and_then(|r| r.into_data()) {
+                Ok(job) => {
+                    let next = (!job.status.is_finished()).then_some(true);
+                    Some((Ok(job), next))
+                }
+                Err(err) => Some((Err(err), None)),
+            }
+        })
+    }
+}
@@ -0,0 +1,22 @@
+use serde::Deserialize;
+use torbox_core_rs::data::user::Job;
+
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+pub enum IntegrationQueuePayload {
+    Job(Box<Job>),
+    Queued { job_id: u64 },
+    Message(String),
+    Empty,
+}
+
+impl IntegrationQueuePayload {
+    /// Id of the queued job, to poll with [`watch_job`](crate::IntegrationsApi::watch_job).
+    pub fn job_id(&self) -> Option<u64> {
+        match self {
+            IntegrationQueuePayload::Job(job) => Some(job.id),
+            IntegrationQueuePayload::Queued { job_id } => Some(*job_id),
+            _ => None,
+        }
+    }
+}
@@ -0,0 +1,176 @@
+use std::time::Duration;
+
+use futures::StreamExt;
+use serde_json::json;
+use torbox_core_rs::{
+    data::{
+        hash::InfoHash,
+        user::{IntegrationKind, JobItemType, JobStatus},
+    },
+    error::{ApiError, ApiErrorCode},
+};
+use torbox_mock::MockServer;
+
+use crate::{IntegrationsApi, body::IntegrationTransferBody, types::IntegrationDestination};
+
+#[tokio::test]
+async fn test_queue_transfer_to_each_provider() {
+    let server = MockServer::start().await;
+    let client = server.client().with_strict_errors(true);
+    let api = IntegrationsApi::new(&client);
+    let id = server
+        .state()
+        .add_torrent("ubuntu", &[("ubuntu.iso", 4096)]) as u32;
+
+    let destinations = [
+        IntegrationDestination::GoogleDrive("google".into()),
+        IntegrationDestination::Dropbox("dropbox".into()),
+        IntegrationDestination::OneDrive("onedrive".into()),
+        IntegrationDestination::GoFile(None),
+        IntegrationDestination::OneFichier("1fichier".into()),
+    ];
+    for destination in destinations {
+        let kind = destination.kind();
+        let body =
+            IntegrationTransferBody::new(JobItemType::Torrent, id, destination).with_zip(true);
+        let job_id = api
+            .queue_transfer(body)
+            .await
+            .unwrap()
+            .into_data()
+            .unwrap()
+            .job_id()
+            .unwrap();
+
+        let job = api.get_job(job_id).await.unwrap().into_data().unwrap();
+        assert_eq!(job.integration, kind);
+        assert_eq!(job.job_type, JobItemType::Torrent);
+        assert_eq!(job.status, JobStatus::Pending);
+        assert!(job.zip);
+    }
+
+    let jobs = api.list_jobs().await.unwrap().into_data().unwrap();
+    assert_eq!(jobs.len(), 5);
+    assert!(
+        jobs.iter()
+            .any(|job| job.integration == IntegrationKind::OneFichier)
+    );
+
+    let hash = InfoHash::new(&format!("{id:040x}")).unwrap();
+    let jobs = api.jobs_for_hash(&hash).await.unwrap().into_data().unwrap();
+    assert_eq!(jobs.len(), 5);
+}
+
+#[tokio::test]
+async fn test_queue_transfer_of_missing_download() {
+    let server = MockServer::start().await;
+    let client = server.client().with_strict_errors(true);
+    let api = IntegrationsApi::new(&client);
+
+    let body = IntegrationTransferBody::new(
+        JobItemType::Usenet,
+        42,
+        IntegrationDestination::Dropbox("dropbox".into()),
+    );
+    let err = api.queue_transfer(body).await.unwrap_err();
+    assert_eq!(err.code(), Some(&ApiErrorCode::ItemNotFound));
+}
+
+#[tokio::test]
+async fn test_cancel_job() {
+    let server = MockServer::start().await;
+    let client = server.client().with_strict_errors(true);
+    let api = IntegrationsApi::new(&client);
+    let id = server
+        .state()
+        .add_webdownload("file", "https://example.com/file", &[("file.bin", 64)])
+        as u32;
+
+    let body = IntegrationTransferBody::new(
+        JobItemType::Webdl,
+        id,
+        IntegrationDestination::GoFile(Some("gofile".into())),
+    )
+    .with_file(0);
+    let job_id = api
+        .queue_transfer(body)
+        .await
+        .unwrap()
+        .into_data()
+        .unwrap()
+        .job_id()
+        .unwrap();
+
+    api.cancel_job(job_id).await.unwrap();
+    let job = api.get_job(job_id).await.unwrap().into_data().unwrap();
+    assert_eq!(job.status, JobStatus::Cancelled);
+    assert_eq!(job.file_id, Some(0));
+
+    let err = api.cancel_job(job_id).await.unwrap_err();
+    assert_eq!(err.code(), Some(&ApiErrorCode::InvalidOption));
+
+    // Kinds and statuses added to TorBox later do not fail the job
+    server
+        .state()
+        .script(job_id, [json!({"type": "seedbox", "status": "archived"})]);
+    let job = api.get_job(job_id).await.unwrap().into_data().unwrap();
+    assert_eq!(job.job_type, JobItemType::Unknown);
+    assert_eq!(job.status, JobStatus::Unknown);
+    assert_eq!(api.list_jobs().await.unwrap().into_data().unwrap().len(), 1);
+}
+
+#[tokio::test]
+async fn test_watch_job_until_completed() {
+    let server = MockServer::start().await;
+    let client = server.client().with_strict_errors(true);
+    let api = IntegrationsApi::new(&client);
+    let id = server.state().add_usenet("show", &[("show.mkv", 128)]) as u32;
+
+    let body = IntegrationTransferBody::new(
+        JobItemType::Usenet,
+        id,
+        IntegrationDestination::OneDrive("onedrive".into()),
+    );
+    let job_id = api
+        .queue_transfer(body)
+        .await
+        .unwrap()
+        .into_data()
+        .unwrap()
+        .job_id()
+        .unwrap();
+    server.state().script(
+        job_id,
+        [
+            json!({"status": "uploading", "progress": 0.5}),
+            json!({"status": "completed", "progress": 1.0, "download_url": "https://onedrive.live.com/show"}),
+        ],
+    );
+
+    let jobs: Vec<_> = api
+        .watch_job(job_id, Duration::from_millis(10))
+        .collect()
+        .await;
+    let statuses: Vec<JobStatus> = jobs
+        .iter()
+        .map(|job| job.as_ref().unwrap().status)
+        .collect();
+    assert_eq!(statuses, [JobStatus::Uploading, JobStatus::Completed]);
+
+    let last = jobs.last().unwrap().as_ref().unwrap();
+    assert_eq!(
+        last.download_url.as_deref(),
+        Some("https://onedrive.live.com/show")
+    );
+}
+
+#[tokio::test]
+async fn test_watch_missing_job() {
+    let server = MockServer::start().await;
+    let client = server.client().with_strict_errors(true);
+    let api = IntegrationsApi::new(&client);
+
+    let events: Vec<_> = api.watch_job(7, Duration::from_millis(10)).collect().await;
+    assert_eq!(events.len(), 1);
+    assert!(matches!(events[0], Err(ApiError::Failure(_))));
+}
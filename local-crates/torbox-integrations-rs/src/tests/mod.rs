@@ -0,0 +1,42 @@
+#[cfg(test)]
+mod mock;
+
+#[cfg(test)]
+pub mod integrations_test {
+    use std::env;
+
+    use crate::IntegrationsApi;
+
+    use dotenvy::from_filename;
+    use torbox_core_rs::client::TorboxClient;
+
+    pub fn load_token_from_file() -> Option<String> {
+        if let Err(err) = from_filename(".token") {
+            eprintln!(
+                "Could not load .token file, please create one before starting unit testing: {err}"
+            );
+            return None;
+        }
+        env::var("TORBOX_TOKEN").ok()
+    }
+
+    fn test_client() -> TorboxClient {
+        let token = load_token_from_file().expect("Missing TORBOX_TOKEN in .token file");
+        TorboxClient::new(token)
+    }
+
+    #[tokio::test]
+    async fn test_list_jobs_success() {
+        let client = test_client();
+        let api = IntegrationsApi::new(&client);
+
+        let result = api.list_jobs().await;
+        match result {
+            Ok(response) => {
+                println!("Integration Jobs: {:?}", response);
+                assert!(response.success, "API responded with success=false");
+            }
+            Err(e) => panic!("API call failed: {e:?}"),
+        }
+    }
+}
@@ -0,0 +1,33 @@
+use serde::{Deserialize, Serialize};
+use torbox_core_rs::data::user::IntegrationKind;
+
+/// Provider to upload to, with the token authorizing TorBox on it.
+///
+/// Tokens are the OAuth access tokens of each provider, obtained by your own application.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "specta", derive(specta::Type))]
+pub enum IntegrationDestination {
+    #[serde(rename = "google_token")]
+    GoogleDrive(String),
+    #[serde(rename = "dropbox_token")]
+    Dropbox(String),
+    #[serde(rename = "onedrive_token")]
+    OneDrive(String),
+    /// Uploads anonymously without a token.
+    #[serde(rename = "gofile_token")]
+    GoFile(Option<String>),
+    #[serde(rename = "onefichier_token")]
+    OneFichier(String),
+}
+
+impl IntegrationDestination {
+    pub fn kind(&self) -> IntegrationKind {
+        match self {
+            IntegrationDestination::GoogleDrive(_) => IntegrationKind::GoogleDrive,
+            IntegrationDestination::Dropbox(_) => IntegrationKind::Dropbox,
+            IntegrationDestination::OneDrive(_) => IntegrationKind::OneDrive,
+            IntegrationDestination::GoFile(_) => IntegrationKind::GoFile,
+            IntegrationDestination::OneFichier(_) => IntegrationKind::OneFichier,
+        }
+    }
+}
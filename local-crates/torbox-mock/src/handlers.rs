@@ -0,0 +1,1959 @@
+use std::{
+    collections::{HashMap, VecDeque},
+    io,
+};
+
+use axum::{
+    Extension, Json,
+    body::{Body, Bytes},
+    extract::{Multipart, Path, Query, Request, State},
+    http::{HeaderMap, HeaderValue, StatusCode, header},
+    middleware::Next,
+    response::{IntoResponse, Response},
+};
+use chrono::Utc;
+use futures::stream;
+use serde::Serialize;
+use serde_json::{Value, json};
+use sha1::{Digest, Sha1};
+use torbox_core_rs::{data::queued::QueuedType, error::ApiErrorCode};
+
+use crate::{
+    MOCK_TOKEN,
+    state::{
+        Injection, MockState, RecordedRequest, file_content, find, find_mut, link_hash,
+        queued_value, torrent_value, usenet_value, webdl_value,
+    },
+};
+
+type Params = Query<HashMap<String, String>>;
+
+/// Marks a request whose response body must stop after this many bytes.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct Interrupt(u64);
+
+/// Number of accounts a mock vendor may register.
+const VENDOR_USERS_ALLOWED: u64 = 2;
+
+/// Endpoints TorBox serves without an API token.
+const PUBLIC_PATHS: &[&str] = &[
+    "api/stats",
+    "api/changelogs/rss",
+    "api/changelogs/json",
+    "api/speedtest",
+    "api/webdl/hosters",
+    "api/user/refreshtoken",
+    "api/user/auth/device/start",
+    "api/user/auth/device/token",
+];
+
+/// Logs every request, applies injected failures and checks authentication.
+pub(crate) async fn guard(
+    State(state): State<MockState>,
+    mut req: Request,
+    next: Next,
+) -> Response {
+    let path = req
+        .uri()
+        .path()
+        .trim_start_matches("/v1")
+        .trim_start_matches('/')
+        .to_string();
+    let query = req.uri().query().map(str::to_string);
+
+    let (injection, token) = {
+        let mut store = state.lock();
+        store.requests.push(RecordedRequest {
+            method: req.method().to_string(),
+            path: path.clone(),
+            query: query.clone(),
+        });
+        let injection = store
+            .injections
+            .get_mut(&path)
+            .and_then(|queue| queue.pop_front());
+        (injection, store.token.clone())
+    };
+
+    match injection {
+        Some(Injection::Error(code)) => return fail(code, "Injected error"),
+        Some(Injection::Status {
+            status,
+            retry_after,
+        }) => {
+            let status = StatusCode::from_u16(status).unwrap_or(StatusCode::INTERNAL_SERVER_ERROR);
+            let mut res = (
+                status,
+                Json(json!({
+                    "success": false,
+                    "error": null,
+                    "detail": "Injected status",
+                    "data": null,
+                })),
+            )
+                .into_response();
+            if let Some(secs) = retry_after {
+                res.headers_mut()
+                    .insert(header::RETRY_AFTER, HeaderValue::from(secs));
+            }
+            return res;
+        }
+        Some(Injection::Redirect { location }) => {
+            return (
+                StatusCode::TEMPORARY_REDIRECT,
+                [(header::LOCATION, location)],
+            )
+                .into_response();
+        }
+        Some(Injection::Interrupt { after }) => {
+            req.extensions_mut().insert(Interrupt(after));
+        }
+        Some(Injection::Pass) | None => {}
+    }
+
+    if PUBLIC_PATHS.contains(&path.as_str()) || path.starts_with("cdn/") {
+        return next.run(req).await;
+    }
+
+    let bearer = req
+        .headers()
+        .get(header::AUTHORIZATION)
+        .and_then(|v| v.to_str().ok())
+        .and_then(|v| v.strip_prefix("Bearer "))
+        .map(str::to_string);
+    let query_token = query.as_deref().and_then(|q| {
+        q.split('&')
+            .find_map(|pair| pair.strip_prefix("token="))
+            .map(str::to_string)
+    });
+
+    match bearer.or(query_token) {
+        None => fail(ApiErrorCode::NoAuth, "No authentication provided."),
+        Some(given) if given != token => fail(ApiErrorCode::BadToken, "Invalid API token."),
+        Some(_) => next.run(req).await,
+    }
+}
+
+fn ok(detail: &str, data: impl Serialize) -> Response {
+    Json(json!({
+        "success": true,
+        "error": null,
+        "detail": detail,
+        "data": data,
+    }))
+    .into_response()
+}
+
+fn fail(code: ApiErrorCode, detail: &str) -> Response {
+    let status = match code {
+        ApiErrorCode::NoAuth | ApiErrorCode::BadToken | ApiErrorCode::AuthError => {
+            StatusCode::UNAUTHORIZED
+        }
+        ApiErrorCode::ItemNotFound | ApiErrorCode::EndpointNotFound => StatusCode::NOT_FOUND,
+        ApiErrorCode::PlanRestrictedFeature => StatusCode::FORBIDDEN,
+        ApiErrorCode::DatabaseError
+        | ApiErrorCode::UnknownError
+        | ApiErrorCode::DownloadServerError
+        | ApiErrorCode::NoServersAvailableError => StatusCode::INTERNAL_SERVER_ERROR,
+        _ => StatusCode::BAD_REQUEST,
+    };
+
+    (
+        status,
+        Json(json!({
+            "success": false,
+            "error": code,
+            "detail": detail,
+            "data": null,
+        })),
+    )
+        .into_response()
+}
+
+fn flag(params: &HashMap<String, String>, key: &str) -> bool {
+    params.get(key).is_some_and(|v| v == "true" || v == "1")
+}
+
+fn number(params: &HashMap<String, String>, key: &str) -> Option<u64> {
+    params.get(key).and_then(|v| v.parse().ok())
+}
+
+/// Shared `mylist` behaviour: a single object when `id` is given, otherwise a paginated list.
+fn list(
+    items: &mut [Value],
+    scripts: &mut HashMap<u64, VecDeque<Value>>,
+    params: &HashMap<String, String>,
+) -> Response {
+    if let Some(id) = number(params, "id") {
+        let Some(item) = find_mut(items, id) else {
+            return fail(ApiErrorCode::ItemNotFound, "Download not found.");
+        };
+
+        let patch = scripts.get_mut(&id).and_then(|queue| queue.pop_front());
+        if let Some(Value::Object(fields)) = patch {
+            for (key, value) in fields {
+                item[key] = value;
+            }
+        }
+
+        return ok("Download found.", item);
+    }
+
+    let offset = number(params, "offset").unwrap_or(0) as usize;
+    let limit = number(params, "limit").unwrap_or(1000) as usize;
+    let page: Vec<&Value> = items.iter().skip(offset).take(limit).collect();
+
+    ok("List retrieved.", page)
+}
+
+/// Collects the text fields and uploaded file of a multipart form.
+async fn read_form(mut multipart: Multipart) -> (HashMap<String, String>, Option<Vec<u8>>) {
+    let mut fields = HashMap::new();
+    let mut file = None;
+
+    while let Ok(Some(field)) = multipart.next_field().await {
+        let name = field.name().unwrap_or_default().to_string();
+        if name == "file" {
+            file = field.bytes().await.ok().map(|b| b.to_vec());
+        } else if let Ok(text) = field.text().await {
+            fields.insert(name, text);
+        }
+    }
+
+    (fields, file)
+}
+
+/// Shared `controlX` behaviour for a single id or `all`.
+fn control(items: &mut Vec<Value>, id: Option<u64>, all: bool, operation: &str) -> Response {
+    let ids: Vec<u64> = match id {
+        Some(id) if find(items, id).is_some() => vec![id],
+        Some(_) => return fail(ApiErrorCode::ItemNotFound, "Download not found."),
+        None if all => items.iter().filter_map(|i| i["id"].as_u64()).collect(),
+        None => {
+            return fail(
+                ApiErrorCode::MissingRequiredOption,
+                "An id or all is required.",
+            );
+        }
+    };
+
+    match operation {
+        "delete" => items.retain(|item| !item["id"].as_u64().is_some_and(|id| ids.contains(&id))),
+        "pause" | "resume" | "reannounce" => {
+            for id in ids {
+                if let Some(item) = find_mut(items, id) {
+                    item["active"] = json!(operation != "pause");
+                    if operation == "pause" {
+                        item["download_state"] = json!("paused");
+                    }
+                }
+            }
+        }
+        _ => return fail(ApiErrorCode::InvalidOption, "Invalid operation."),
+    }
+
+    ok("Operation successful.", Value::Null)
+}
+
+/// Shared `edit` behaviour, overwriting the fields present in the body.
+fn edit(items: &mut [Value], id: Option<u64>, body: &Value) -> Response {
+    let Some(item) = id.and_then(|id| find_mut(items, id)) else {
+        return fail(ApiErrorCode::ItemNotFound, "Download not found.");
+    };
+
+    for key in ["name", "tags", "alternative_hashes"] {
+        if let Some(value) = body.get(key) {
+            item[key] = value.clone();
+        }
+    }
+
+    ok("Item edited successfully.", Value::Null)
+}
+
+/// Torrent sizes are stored as floats, `checkcached` answers with integers.
+fn size(value: &Value) -> u64 {
+    value.as_f64().unwrap_or_default() as u64
+}
+
+/// Lowercase hashes of a `checkcached` request, `None` past the 100 the real endpoints accept.
+fn hashes(params: &HashMap<String, String>) -> Option<Vec<String>> {
+    let hashes: Vec<String> = params
+        .get("hash")
+        .map(|h| h.split(',').map(str::to_ascii_lowercase).collect())
+        .unwrap_or_default();
+    (hashes.len() <= 100).then_some(hashes)
+}
+
+/// Shared `checkcached` behaviour, in the `list` or `object` format.
+fn check_cached(items: &[Value], params: &HashMap<String, String>) -> Response {
+    let Some(hashes) = hashes(params) else {
+        return fail(ApiErrorCode::InvalidOption, "Too many hashes.");
+    };
+
+    let cached = items
+        .iter()
+        .filter(|item| hashes.iter().any(|hash| item["hash"] == *hash))
+        .map(|item| {
+            let files = if flag(params, "list_files") {
+                item["files"]
+                    .as_array()
+                    .into_iter()
+                    .flatten()
+                    .map(|f| json!({ "name": f["name"], "size": size(&f["size"]) }))
+                    .collect()
+            } else {
+                Vec::new()
+            };
+            json!({
+                "name": item["name"],
+                "size": size(&item["size"]),
+                "hash": item["hash"],
+                "files": files,
+            })
+        });
+
+    if params.get("format").is_some_and(|f| f == "object") {
+        let object: serde_json::Map<String, Value> = cached
+            .map(|info| (info["hash"].as_str().unwrap_or_default().to_string(), info))
+            .collect();
+        ok("Cached items found.", object)
+    } else {
+        ok("Cached items found.", cached.collect::<Vec<_>>())
+    }
+}
+
+/// Shared Search API behaviour, every stored download whose name contains the query is a result.
+///
+/// Results are always cached and owned since they come from the account itself.
+fn search(items: &[Value], query: &str, params: &HashMap<String, String>, kind: &str) -> Value {
+    let query = query.to_lowercase();
+    let results: Vec<Value> = items
+        .iter()
+        .filter(|item| {
+            item["name"]
+                .as_str()
+                .is_some_and(|name| name.to_lowercase().contains(&query))
+        })
+        .map(|item| {
+            json!({
+                "hash": item["hash"],
+                "raw_title": item["name"],
+                "title": item["name"],
+                "magnet": item.get("magnet"),
+                "nzb": null,
+                "last_known_seeders": 10,
+                "last_known_peers": 0,
+                "size": size(&item["size"]),
+                "tracker": "mock",
+                "categories": [],
+                "files": item["files"].as_array().map_or(0, Vec::len),
+                "type": kind,
+                "age": "1d",
+                "user_search": false,
+                "cached": flag(params, "check_cache"),
+                "owned": flag(params, "check_owned"),
+            })
+        })
+        .collect();
+
+    let metadata = match params.get("metadata").map(String::as_str) {
+        Some("false") => Value::Null,
+        _ => json!({ "title": query, "genres": [], "mediaType": "movie" }),
+    };
+    let list = if kind == "torrent" {
+        "torrents"
+    } else {
+        "nzbs"
+    };
+
+    json!({ "metadata": metadata, list: results })
+}
+
+/// Shared `requestdl` behaviour, answering with a 307 when `redirect=true`.
+///
+/// Links point back to the mock's `cdn` route, which serves [`file_content`](crate::file_content).
+fn request_link(
+    host: &str,
+    kind: &str,
+    items: &[Value],
+    params: &HashMap<String, String>,
+    id_key: &str,
+) -> Response {
+    let Some(id) = number(params, id_key) else {
+        return fail(
+            ApiErrorCode::MissingRequiredOption,
+            "A download id is required.",
+        );
+    };
+    if find(items, id).is_none() {
+        return fail(ApiErrorCode::ItemNotFound, "Download not found.");
+    }
+
+    let file = match number(params, "file_id") {
+        Some(file) if !flag(params, "zip_link") => file.to_string(),
+        _ => "zip".to_string(),
+    };
+    let url = format!("http://{host}/cdn/{kind}/{id}/{file}");
+
+    if flag(params, "redirect") {
+        return (StatusCode::TEMPORARY_REDIRECT, [(header::LOCATION, url)]).into_response();
+    }
+
+    ok("Download link generated.", url)
+}
+
+fn host(headers: &HeaderMap) -> &str {
+    headers
+        .get(header::HOST)
+        .and_then(|v| v.to_str().ok())
+        .unwrap_or("localhost")
+}
+
+/// Reads the info hash and display name of a magnet link.
+fn parse_magnet(magnet: &str) -> (Option<String>, Option<String>) {
+    let query = magnet.split_once('?').map(|(_, q)| q).unwrap_or_default();
+    let mut hash = None;
+    let mut name = None;
+
+    for pair in query.split('&') {
+        match pair.split_once('=') {
+            Some(("xt", value)) => {
+                hash = value
+                    .strip_prefix("urn:btih:")
+                    .map(|h| h.to_ascii_lowercase());
+            }
+            Some(("dn", value)) => name = Some(value.replace('+', " ")),
+            _ => {}
+        }
+    }
+
+    (hash, name)
+}
+
+/// Escapes text placed in an XML element.
+fn escape_xml(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+}
+
+/// An RSS 2.0 document with one item per (title, description).
+fn rss(title: &str, items: impl IntoIterator<Item = (String, String)>) -> Response {
+    let items: String = items
+        .into_iter()
+        .map(|(title, description)| {
+            format!(
+                "<item><title>{}</title><description>{}</description></item>",
+                escape_xml(&title),
+                escape_xml(&description)
+            )
+        })
+        .collect();
+    let feed = format!(
+        "<?xml version=\"1.0\" encoding=\"UTF-8\"?><rss version=\"2.0\"><channel><title>{title}</title>{items}</channel></rss>"
+    );
+
+    ([(header::CONTENT_TYPE, "application/rss+xml")], feed).into_response()
+}
+
+pub(crate) async fn torrents_mylist(State(state): State<MockState>, params: Params) -> Response {
+    let store = &mut *state.lock();
+    list(&mut store.torrents, &mut store.scripts, &params)
+}
+
+pub(crate) async fn torrents_create(
+    State(state): State<MockState>,
+    multipart: Multipart,
+) -> Response {
+    let (fields, file) = read_form(multipart).await;
+
+    let (hash, magnet_name) = match (fields.get("magnet"), &file) {
+        (Some(magnet), _) => match parse_magnet(magnet) {
+            (Some(hash), name) => (hash, name),
+            (None, _) => return fail(ApiErrorCode::BozoTorrent, "Invalid magnet link."),
+        },
+        (None, Some(bytes)) if !bytes.is_empty() => (format!("{:x}", Sha1::digest(bytes)), None),
+        _ => {
+            return fail(
+                ApiErrorCode::MissingRequiredOption,
+                "A magnet or a torrent file is required.",
+            );
+        }
+    };
+
+    let mut store = state.lock();
+
+    if let Some(existing) = store.torrents.iter().find(|t| t["hash"] == hash.as_str()) {
+        let id = existing["id"].clone();
+        return ok(
+            "Found cached torrent. Using cached torrent.",
+            json!({ "torrent_id": id, "hash": hash, "auth_id": store.auth_id }),
+        );
+    }
+
+    let id = store.next_id();
+    let name = fields
+        .get("name")
+        .cloned()
+        .or(magnet_name)
+        .unwrap_or_else(|| format!("Torrent {id}"));
+
+    if flag(&fields, "as_queued") {
+        let magnet = fields.get("magnet").map_or("", String::as_str);
+        let mut item = queued_value(id, QueuedType::Torrent, &name, magnet, &store.auth_id);
+        item["hash"] = json!(hash);
+        store.queued.push(item);
+        return ok(
+            "Successfully queued torrent.",
+            json!({ "queued_id": id, "hash": hash, "auth_id": store.auth_id }),
+        );
+    }
+
+    let torrent = torrent_value(
+        id,
+        &hash,
+        &name,
+        fields.get("magnet").cloned(),
+        &[(name.as_str(), 1024)],
+        &store.auth_id,
+    );
+    store.torrents.push(torrent);
+
+    ok(
+        "Successfully added torrent.",
+        json!({ "torrent_id": id, "hash": hash, "auth_id": store.auth_id }),
+    )
+}
+
+pub(crate) async fn torrents_control(
+    State(state): State<MockState>,
+    Json(body): Json<Value>,
+) -> Response {
+    let operation = body["operation"].as_str().unwrap_or_default().to_string();
+    control(
+        &mut state.lock().torrents,
+        body["torrent_id"].as_u64(),
+        body["all"].as_bool().unwrap_or(false),
+        &operation,
+    )
+}
+
+pub(crate) async fn torrents_edit(
+    State(state): State<MockState>,
+    Json(body): Json<Value>,
+) -> Response {
+    edit(
+        &mut state.lock().torrents,
+        body["torrent_id"].as_u64(),
+        &body,
+    )
+}
+
+pub(crate) async fn torrents_requestdl(
+    State(state): State<MockState>,
+    headers: HeaderMap,
+    params: Params,
+) -> Response {
+    request_link(
+        host(&headers),
+        "torrents",
+        &state.lock().torrents,
+        &params,
+        "torrent_id",
+    )
+}
+
+/// Metadata of the stored torrent with this hash.
+///
+/// Unknown torrents answer with no data, like a lookup that timed out on the network.
+fn torrent_info(torrents: &[Value], hash: Option<&str>) -> Response {
+    let Some(torrent) = hash.and_then(|hash| {
+        torrents
+            .iter()
+            .find(|t| t["hash"] == hash.to_ascii_lowercase())
+    }) else {
+        return ok("Torrent not found on the network.", Value::Null);
+    };
+
+    let files: Vec<Value> = torrent["files"]
+        .as_array()
+        .into_iter()
+        .flatten()
+        .map(|f| json!({ "name": f["name"], "size": f["size"], "hash": null }))
+        .collect();
+
+    ok(
+        "Torrent info retrieved.",
+        json!({
+            "name": torrent["name"],
+            "hash": torrent["hash"],
+            "size": size(&torrent["size"]),
+            "trackers": [],
+            "seeds": torrent["seeds"],
+            "peers": torrent["peers"],
+            "files": files,
+        }),
+    )
+}
+
+pub(crate) async fn torrents_info_get(State(state): State<MockState>, params: Params) -> Response {
+    torrent_info(
+        &state.lock().torrents,
+        params.get("hash").map(String::as_str),
+    )
+}
+
+/// Looks a torrent up by `hash`, else by the hash of `magnet`. Torrent files are not parsed.
+pub(crate) async fn torrents_info_post(
+    State(state): State<MockState>,
+    multipart: Multipart,
+) -> Response {
+    let (fields, _) = read_form(multipart).await;
+    let hash = match (fields.get("hash"), fields.get("magnet")) {
+        (Some(hash), _) => Some(hash.clone()),
+        (None, Some(magnet)) => parse_magnet(magnet).0,
+        (None, None) => None,
+    };
+
+    torrent_info(&state.lock().torrents, hash.as_deref())
+}
+
+/// Exports the magnet of a torrent as JSON, or a single file `.torrent` built from its name and size.
+pub(crate) async fn torrents_exportdata(
+    State(state): State<MockState>,
+    params: Params,
+) -> Response {
+    let store = state.lock();
+    let Some(torrent) = number(&params, "torrent_id").and_then(|id| find(&store.torrents, id))
+    else {
+        return fail(ApiErrorCode::ItemNotFound, "Torrent not found.");
+    };
+    let name = torrent["name"].as_str().unwrap_or_default();
+
+    match params.get("type").map(String::as_str) {
+        Some("magnet") => {
+            let magnet = torrent["magnet"].as_str().map_or_else(
+                || {
+                    format!(
+                        "magnet:?xt=urn:btih:{}&dn={}",
+                        torrent["hash"].as_str().unwrap_or_default(),
+                        name.replace(' ', "+")
+                    )
+                },
+                str::to_string,
+            );
+            ok("Magnet exported.", magnet)
+        }
+        Some("file") => {
+            let mut file = format!(
+                "d4:infod6:lengthi{}e4:name{}:{name}12:piece lengthi16384e6:pieces20:",
+                size(&torrent["size"]),
+                name.len()
+            )
+            .into_bytes();
+            file.extend([0; 20]);
+            file.extend(b"ee");
+            ([(header::CONTENT_TYPE, "application/x-bittorrent")], file).into_response()
+        }
+        _ => fail(ApiErrorCode::InvalidOption, "Type must be magnet or file."),
+    }
+}
+
+pub(crate) async fn torrents_checkcached(
+    State(state): State<MockState>,
+    params: Params,
+) -> Response {
+    check_cached(&state.lock().torrents, &params)
+}
+
+pub(crate) async fn webdl_mylist(State(state): State<MockState>, params: Params) -> Response {
+    let store = &mut *state.lock();
+    list(&mut store.webdls, &mut store.scripts, &params)
+}
+
+pub(crate) async fn webdl_create(State(state): State<MockState>, multipart: Multipart) -> Response {
+    let (fields, _) = read_form(multipart).await;
+
+    let Some(link) = fields.get("link") else {
+        return fail(ApiErrorCode::MissingRequiredOption, "A link is required.");
+    };
+
+    let mut store = state.lock();
+    let id = store.next_id();
+    let hash = link_hash(link);
+    let name = fields.get("name").cloned().unwrap_or_else(|| {
+        link.rsplit('/')
+            .next()
+            .filter(|s| !s.is_empty())
+            .unwrap_or("download")
+            .to_string()
+    });
+    let webdl = webdl_value(
+        id,
+        &hash,
+        &name,
+        link,
+        &[(name.as_str(), 1024)],
+        &store.auth_id,
+    );
+    store.webdls.push(webdl);
+
+    ok(
+        "Successfully added web download.",
+        json!({ "webdownload_id": id, "hash": hash, "auth_id": store.auth_id }),
+    )
+}
+
+pub(crate) async fn webdl_control(
+    State(state): State<MockState>,
+    Json(body): Json<Value>,
+) -> Response {
+    let operation = body["operation"].as_str().unwrap_or_default().to_string();
+    control(
+        &mut state.lock().webdls,
+        body["webdl_id"].as_u64(),
+        body["all"].as_bool().unwrap_or(false),
+        &operation,
+    )
+}
+
+pub(crate) async fn webdl_edit(
+    State(state): State<MockState>,
+    Json(body): Json<Value>,
+) -> Response {
+    edit(&mut state.lock().webdls, body["webdl_id"].as_u64(), &body)
+}
+
+pub(crate) async fn webdl_requestdl(
+    State(state): State<MockState>,
+    headers: HeaderMap,
+    params: Params,
+) -> Response {
+    request_link(
+        host(&headers),
+        "webdl",
+        &state.lock().webdls,
+        &params,
+        "web_id",
+    )
+}
+
+/// Web downloads are cached when their hash is stored, answered as a list without files.
+pub(crate) async fn webdl_checkcached(State(state): State<MockState>, params: Params) -> Response {
+    let Some(hashes) = hashes(&params) else {
+        return fail(ApiErrorCode::InvalidOption, "Too many hashes.");
+    };
+
+    let cached: Vec<Value> = state
+        .lock()
+        .webdls
+        .iter()
+        .filter(|item| hashes.iter().any(|hash| item["hash"] == *hash))
+        .map(|item| {
+            json!({
+                "name": item["name"],
+                "size": size(&item["size"]),
+                "hash": item["hash"],
+                "files": null,
+            })
+        })
+        .collect();
+
+    ok("Cached items found.", cached)
+}
+
+/// A single hoster, `example.com`, without limits.
+pub(crate) async fn webdl_hosters() -> Response {
+    ok(
+        "Hosters retrieved.",
+        json!([{
+            "id": 1,
+            "name": "Example",
+            "domains": ["example.com"],
+            "url": "https://example.com",
+            "icon": "https://example.com/favicon.ico",
+            "status": true,
+            "type": "hoster",
+            "note": null,
+            "nsfw": false,
+            "daily_link_limit": 0,
+            "daily_link_used": 0,
+            "daily_bandwidth_limit": 0,
+            "daily_bandwidth_used": 0,
+            "per_link_size_limit": 0,
+            "regex": "^https?://(www\\.)?example\\.com/",
+        }]),
+    )
+}
+
+pub(crate) async fn usenet_mylist(State(state): State<MockState>, params: Params) -> Response {
+    let store = &mut *state.lock();
+    list(&mut store.usenet, &mut store.scripts, &params)
+}
+
+pub(crate) async fn usenet_create(
+    State(state): State<MockState>,
+    multipart: Multipart,
+) -> Response {
+    let (fields, file) = read_form(multipart).await;
+
+    let link = fields.get("link").cloned();
+    if link.is_none() && file.as_ref().is_none_or(|f| f.is_empty()) {
+        return fail(
+            ApiErrorCode::MissingRequiredOption,
+            "A link or an NZB file is required.",
+        );
+    }
+
+    let mut store = state.lock();
+    let id = store.next_id();
+    let hash = format!("{id:032x}");
+    let name = fields
+        .get("name")
+        .cloned()
+        .unwrap_or_else(|| format!("Usenet {id}"));
+    let usenet = usenet_value(
+        id,
+        &hash,
+        &name,
+        link,
+        &[(name.as_str(), 1024)],
+        &store.auth_id,
+    );
+    store.usenet.push(usenet);
+
+    ok(
+        "Successfully added usenet download.",
+        json!({ "usenetdownload_id": id, "hash": hash, "auth_id": store.auth_id }),
+    )
+}
+
+pub(crate) async fn usenet_control(
+    State(state): State<MockState>,
+    Json(body): Json<Value>,
+) -> Response {
+    let operation = body["operation"].as_str().unwrap_or_default().to_string();
+    control(
+        &mut state.lock().usenet,
+        body["usenet_id"].as_u64(),
+        body["all"].as_bool().unwrap_or(false),
+        &operation,
+    )
+}
+
+pub(crate) async fn usenet_requestdl(
+    State(state): State<MockState>,
+    headers: HeaderMap,
+    params: Params,
+) -> Response {
+    request_link(
+        host(&headers),
+        "usenet",
+        &state.lock().usenet,
+        &params,
+        "usenet_id",
+    )
+}
+
+pub(crate) async fn usenet_checkcached(State(state): State<MockState>, params: Params) -> Response {
+    check_cached(&state.lock().usenet, &params)
+}
+
+pub(crate) async fn user_me(State(state): State<MockState>, params: Params) -> Response {
+    let mut user = state.lock().user.clone();
+    if !flag(&params, "settings") {
+        user["settings"] = Value::Null;
+    }
+    ok("User data retrieved.", user)
+}
+
+/// Merges the settings sent into the current ones, a `null` setting is removed.
+pub(crate) async fn user_edit_settings(
+    State(state): State<MockState>,
+    Json(body): Json<Value>,
+) -> Response {
+    let Value::Object(changes) = body else {
+        return fail(ApiErrorCode::InvalidOption, "Settings must be an object.");
+    };
+
+    let mut store = state.lock();
+    let settings = store.user["settings"]
+        .as_object_mut()
+        .expect("Mock user settings are an object");
+    for (key, value) in changes {
+        match value {
+            Value::Null => settings.remove(&key),
+            value => settings.insert(key, value),
+        };
+    }
+
+    ok("Settings updated.", Value::Null)
+}
+
+/// Replaces the API token with a new one when given the session token.
+pub(crate) async fn user_refresh_token(
+    State(state): State<MockState>,
+    Json(body): Json<Value>,
+) -> Response {
+    let mut store = state.lock();
+    if body["session_token"]["token"] != store.session_token.as_str() {
+        return fail(ApiErrorCode::AuthError, "Invalid session token.");
+    }
+
+    let id = store.next_id();
+    store.token = format!("{MOCK_TOKEN}-{id}");
+
+    ok("Token refreshed.", &store.token)
+}
+
+pub(crate) async fn user_getconfirmation(State(state): State<MockState>) -> Response {
+    let email = state.lock().user["email"].clone();
+    ok(&format!("Confirmation code sent to {email}."), Value::Null)
+}
+
+/// Accepts any referral code but the user's own.
+pub(crate) async fn user_addreferral(State(state): State<MockState>, params: Params) -> Response {
+    let store = state.lock();
+    match params.get("referral") {
+        None => fail(
+            ApiErrorCode::MissingRequiredOption,
+            "A referral code is required.",
+        ),
+        Some(referral) if store.user["user_referral"] == referral.as_str() => {
+            fail(ApiErrorCode::InvalidOption, "You cannot refer yourself.")
+        }
+        Some(_) => ok("Referral added.", Value::Null),
+    }
+}
+
+pub(crate) async fn user_referraldata(State(state): State<MockState>) -> Response {
+    let store = state.lock();
+    ok(
+        "Referral data retrieved.",
+        json!({
+            "referred_accounts": 0,
+            "referral_code": store.user["user_referral"],
+            "purchases_referred": store.user["purchases_referred"],
+        }),
+    )
+}
+
+pub(crate) async fn user_subscriptions(State(state): State<MockState>) -> Response {
+    let store = state.lock();
+    let now = Utc::now().fixed_offset().to_rfc3339();
+    ok(
+        "Subscriptions retrieved.",
+        json!({
+            "created_at": now,
+            "updated_at": now,
+            "subscription_code": "sub_mock",
+            "email_token": "mock",
+            "auth_id": store.auth_id,
+            "plan_code": "pro_monthly",
+            "status": "active",
+            "gateway": "stripe",
+            "plan_name": "Pro",
+        }),
+    )
+}
+
+pub(crate) async fn user_transactions() -> Response {
+    ok(
+        "Transactions retrieved.",
+        json!([{
+            "at": Utc::now().fixed_offset().to_rfc3339(),
+            "type": "subscription",
+            "amount": 10,
+            "transaction_id": "txn_mock",
+        }]),
+    )
+}
+
+/// The search engine with the `id` given, or every search engine of the user.
+pub(crate) async fn user_searchengines(State(state): State<MockState>, params: Params) -> Response {
+    let store = state.lock();
+    match number(&params, "id") {
+        Some(id) => match find(&store.search_engines, id) {
+            Some(engine) => ok("Search engine retrieved.", [engine]),
+            None => fail(ApiErrorCode::ItemNotFound, "Search engine not found."),
+        },
+        None => ok("Search engines retrieved.", &store.search_engines),
+    }
+}
+
+pub(crate) async fn user_control_searchengines(
+    State(state): State<MockState>,
+    Json(body): Json<Value>,
+) -> Response {
+    let mut store = state.lock();
+    let engines = &mut store.search_engines;
+    let ids: Vec<u64> = match body["id"].as_u64() {
+        Some(id) if find(engines, id).is_some() => vec![id],
+        Some(_) => return fail(ApiErrorCode::ItemNotFound, "Search engine not found."),
+        None if body["all"] == true => engines.iter().filter_map(|e| e["id"].as_u64()).collect(),
+        None => {
+            return fail(
+                ApiErrorCode::MissingRequiredOption,
+                "An id or all is required.",
+            );
+        }
+    };
+
+    let selected = |e: &Value| e["id"].as_u64().is_some_and(|id| ids.contains(&id));
+    match body["operation"].as_str() {
+        Some("delete") => engines.retain(|e| !selected(e)),
+        Some(operation @ ("enable" | "disable")) => {
+            for engine in engines.iter_mut().filter(|e| selected(e)) {
+                engine["active"] = json!(operation == "enable");
+            }
+        }
+        Some("check") => {
+            for engine in engines.iter_mut().filter(|e| selected(e)) {
+                engine["valid"] = json!(true);
+            }
+        }
+        _ => return fail(ApiErrorCode::InvalidOption, "Invalid operation."),
+    }
+
+    ok("Operation successful.", Value::Null)
+}
+
+pub(crate) async fn user_modify_searchengines(
+    State(state): State<MockState>,
+    Json(body): Json<Value>,
+) -> Response {
+    let mut store = state.lock();
+    let Some(engine) = body["id"]
+        .as_u64()
+        .and_then(|id| find_mut(&mut store.search_engines, id))
+    else {
+        return fail(ApiErrorCode::ItemNotFound, "Search engine not found.");
+    };
+
+    for key in ["type", "url", "apikey", "download_type"] {
+        if let Some(value) = body.get(key).filter(|v| !v.is_null()) {
+            engine[key] = value.clone();
+        }
+    }
+
+    ok("Search engine edited.", engine.clone())
+}
+
+/// Starts a device code login, approved later with `MockState::approve_device`.
+///
+/// Its interval is 0 so tests poll without waiting.
+pub(crate) async fn device_start(State(state): State<MockState>, params: Params) -> Response {
+    let Some(app) = params.get("app") else {
+        return fail(
+            ApiErrorCode::MissingRequiredOption,
+            "An app name is required.",
+        );
+    };
+
+    let mut store = state.lock();
+    let id = store.next_id();
+    let device = json!({
+        "id": id,
+        "app": app,
+        "device_code": format!("device-{id}"),
+        "code": format!("CODE{id:04}"),
+        "interval": 0,
+        "expires_at": Utc::now().fixed_offset() + chrono::Duration::minutes(10),
+        "verification_url": "https://torbox.app/link",
+        "friendly_verification_url": "https://tor.box/link",
+        "status": "authorization_pending",
+    });
+    store.devices.push(device.clone());
+
+    ok("Device code created.", device)
+}
+
+/// Answers a token poll with the status of the device code, applying its next scripted patch first.
+///
+/// Pending, slowed down, expired or denied codes fail with their OAuth error code as `error`.
+pub(crate) async fn device_token(
+    State(state): State<MockState>,
+    Json(body): Json<Value>,
+) -> Response {
+    let store = &mut *state.lock();
+    let Some(device) = store
+        .devices
+        .iter_mut()
+        .find(|device| device["device_code"] == body["device_code"])
+    else {
+        return fail(ApiErrorCode::InvalidOption, "Unknown device code.");
+    };
+
+    let id = device["id"].as_u64().unwrap_or_default();
+    let patch = store
+        .scripts
+        .get_mut(&id)
+        .and_then(|queue| queue.pop_front());
+    if let Some(Value::Object(fields)) = patch {
+        for (key, value) in fields {
+            device[key] = value;
+        }
+    }
+
+    let expired = device["expires_at"]
+        .as_str()
+        .and_then(|at| chrono::DateTime::parse_from_rfc3339(at).ok())
+        .is_some_and(|at| at <= Utc::now());
+    if expired {
+        device["status"] = json!("expired_token");
+    }
+
+    match device["status"].as_str() {
+        Some("authorized") => ok(
+            "Device authorized.",
+            json!({ "access_token": store.token, "token_type": "Bearer" }),
+        ),
+        status => (
+            StatusCode::BAD_REQUEST,
+            Json(json!({
+                "success": false,
+                "error": status,
+                "detail": "The device code is not authorized.",
+                "data": null,
+            })),
+        )
+            .into_response(),
+    }
+}
+
+pub(crate) async fn notifications_list(State(state): State<MockState>) -> Response {
+    ok("Notifications retrieved.", &state.lock().notifications)
+}
+
+/// The notifications as an RSS feed, the token being sent in the query.
+pub(crate) async fn notifications_rss(State(state): State<MockState>) -> Response {
+    let items: Vec<(String, String)> = state
+        .lock()
+        .notifications
+        .iter()
+        .map(|n| {
+            (
+                n["title"].as_str().unwrap_or_default().to_string(),
+                n["message"].as_str().unwrap_or_default().to_string(),
+            )
+        })
+        .collect();
+
+    rss("TorBox Notifications", items)
+}
+
+pub(crate) async fn notifications_clear(
+    State(state): State<MockState>,
+    params: Params,
+) -> Response {
+    let mut store = state.lock();
+
+    match number(&params, "id") {
+        Some(id) if find(&store.notifications, id).is_none() => {
+            fail(ApiErrorCode::ItemNotFound, "Notification not found.")
+        }
+        Some(id) => {
+            store.notifications.retain(|n| n["id"] != id);
+            ok("Notification cleared.", Value::Null)
+        }
+        None => {
+            store.notifications.clear();
+            ok("Notifications cleared.", Value::Null)
+        }
+    }
+}
+
+pub(crate) async fn notifications_test(State(state): State<MockState>) -> Response {
+    state.add_notification("Test notification", "This is a test notification.");
+    ok("Test notification sent.", Value::Null)
+}
+
+pub(crate) async fn rss_add(State(state): State<MockState>, Json(body): Json<Value>) -> Response {
+    let mut store = state.lock();
+    let (Some(url), Some(name)) = (body["url"].as_str(), body["name"].as_str()) else {
+        return fail(
+            ApiErrorCode::MissingRequiredOption,
+            "A url and name are required.",
+        );
+    };
+    if store.rss_feeds.iter().any(|feed| feed["url"] == url) {
+        return fail(ApiErrorCode::DuplicateItem, "This feed already exists.");
+    }
+
+    let id = store.next_id();
+    let now = Utc::now().fixed_offset();
+    let feed = json!({
+        "id": id,
+        "created_at": now,
+        "updated_at": now,
+        "auth_id": store.auth_id,
+        "name": name,
+        "url": url,
+        "do_regex": body["do_regex"],
+        "dont_regex": body["dont_regex"],
+        "dont_older_than": body["dont_older_than"].as_u64().unwrap_or(0),
+        "pass_check": body["pass_check"].as_bool().unwrap_or(false),
+        "scan_interval": body["scan_interval"].as_u64().unwrap_or(60),
+        "rss_type": body["rss_type"].as_str().unwrap_or("torrent"),
+        "torrent_seeding": body["torrent_seeding"].as_u64().unwrap_or(1),
+        "active": true,
+        "last_checked_at": null,
+        "status_message": null,
+    });
+    store.rss_feeds.push(feed);
+
+    ok("Feed added successfully.", Value::Null)
+}
+
+pub(crate) async fn rss_feeds(State(state): State<MockState>, params: Params) -> Response {
+    let store = state.lock();
+    match number(&params, "id") {
+        Some(id) => match find(&store.rss_feeds, id) {
+            Some(feed) => ok("Feed found.", feed),
+            None => fail(ApiErrorCode::ItemNotFound, "Feed not found."),
+        },
+        None => ok("Feeds retrieved.", &store.rss_feeds),
+    }
+}
+
+pub(crate) async fn rss_items(State(state): State<MockState>, params: Params) -> Response {
+    let store = state.lock();
+    let Some(id) = number(&params, "rss_feed_id") else {
+        return fail(
+            ApiErrorCode::MissingRequiredOption,
+            "A feed id is required.",
+        );
+    };
+    let items: Vec<&Value> = store
+        .rss_items
+        .iter()
+        .filter(|item| item["rss_feed_id"] == id)
+        .collect();
+
+    ok("Feed items retrieved.", items)
+}
+
+pub(crate) async fn rss_modify(
+    State(state): State<MockState>,
+    Json(body): Json<Value>,
+) -> Response {
+    let mut store = state.lock();
+    let Some(feed) = body["rss_feed_id"]
+        .as_u64()
+        .and_then(|id| find_mut(&mut store.rss_feeds, id))
+    else {
+        return fail(ApiErrorCode::ItemNotFound, "Feed not found.");
+    };
+
+    if let Value::Object(fields) = &body {
+        for (key, value) in fields.iter().filter(|(key, _)| *key != "rss_feed_id") {
+            feed[key] = value.clone();
+        }
+    }
+    feed["updated_at"] = json!(Utc::now().fixed_offset());
+
+    ok("Feed modified successfully.", Value::Null)
+}
+
+pub(crate) async fn rss_control(
+    State(state): State<MockState>,
+    Json(body): Json<Value>,
+) -> Response {
+    let operation = body["operation"].as_str().unwrap_or_default().to_string();
+    let feeds = &mut state.lock().rss_feeds;
+    let id = body["rss_feed_id"].as_u64();
+
+    if operation == "update" {
+        return match id {
+            Some(id) if find(feeds, id).is_none() => {
+                fail(ApiErrorCode::ItemNotFound, "Feed not found.")
+            }
+            _ => ok("Operation successful.", Value::Null),
+        };
+    }
+
+    control(
+        feeds,
+        id,
+        body["all"].as_bool().unwrap_or(false),
+        &operation,
+    )
+}
+
+/// Queues a job uploading a stored download to `provider`, which starts as `pending`.
+pub(crate) async fn integration_queue(
+    State(state): State<MockState>,
+    Path(provider): Path<String>,
+    Json(body): Json<Value>,
+) -> Response {
+    let integration = match provider.as_str() {
+        "googledrive" => "google",
+        "dropbox" | "onedrive" | "gofile" | "1fichier" => provider.as_str(),
+        _ => return not_found().await,
+    };
+    let token_key = match integration {
+        "1fichier" => "onefichier_token",
+        _ => &format!("{integration}_token"),
+    };
+    if integration != "gofile" && !body[token_key].is_string() {
+        return fail(
+            ApiErrorCode::MissingRequiredOption,
+            &format!("A {token_key} is required."),
+        );
+    }
+
+    let mut store = state.lock();
+    let item_type = body["type"].as_str().unwrap_or_default().to_string();
+    let items = match item_type.as_str() {
+        "torrent" => &store.torrents,
+        "usenet" => &store.usenet,
+        "webdl" => &store.webdls,
+        _ => return fail(ApiErrorCode::InvalidOption, "Unknown download type."),
+    };
+    let Some(hash) = body["id"]
+        .as_u64()
+        .and_then(|id| find(items, id))
+        .map(|item| item["hash"].clone())
+    else {
+        return fail(ApiErrorCode::ItemNotFound, "Download not found.");
+    };
+
+    let id = store.next_id();
+    let now = Utc::now().fixed_offset();
+    let job = json!({
+        "id": id,
+        "created_at": now,
+        "updated_at": now,
+        "auth_id": store.auth_id,
+        "hash": hash,
+        "type": item_type,
+        "integration": integration,
+        "file_id": body["file_id"],
+        "zip": body["zip"].as_bool().unwrap_or(false),
+        "progress": 0.0,
+        "detail": "Waiting for a worker.",
+        "download_url": null,
+        "status": "pending",
+    });
+    store.jobs.push(job.clone());
+
+    ok("Job queued.", job)
+}
+
+pub(crate) async fn integration_jobs(State(state): State<MockState>) -> Response {
+    ok("Jobs retrieved.", &state.lock().jobs)
+}
+
+pub(crate) async fn integration_jobs_by_hash(
+    State(state): State<MockState>,
+    Path(hash): Path<String>,
+) -> Response {
+    let store = state.lock();
+    let jobs: Vec<&Value> = store
+        .jobs
+        .iter()
+        .filter(|job| job["hash"] == hash)
+        .collect();
+    ok("Jobs retrieved.", jobs)
+}
+
+/// Gets a job, applying the next scripted patch for it first.
+pub(crate) async fn integration_job(
+    State(state): State<MockState>,
+    Path(id): Path<u64>,
+) -> Response {
+    let store = &mut *state.lock();
+    let Some(job) = find_mut(&mut store.jobs, id) else {
+        return fail(ApiErrorCode::ItemNotFound, "Job not found.");
+    };
+
+    let patch = store
+        .scripts
+        .get_mut(&id)
+        .and_then(|queue| queue.pop_front());
+    if let Some(Value::Object(fields)) = patch {
+        for (key, value) in fields {
+            job[key] = value;
+        }
+    }
+
+    ok("Job found.", job)
+}
+
+pub(crate) async fn integration_job_cancel(
+    State(state): State<MockState>,
+    Path(id): Path<u64>,
+) -> Response {
+    let mut store = state.lock();
+    let Some(job) = find_mut(&mut store.jobs, id) else {
+        return fail(ApiErrorCode::ItemNotFound, "Job not found.");
+    };
+    if !matches!(job["status"].as_str(), Some("pending" | "uploading")) {
+        return fail(
+            ApiErrorCode::InvalidOption,
+            "This job has already finished.",
+        );
+    }
+
+    job["status"] = json!("cancelled");
+    ok("Job cancelled.", Value::Null)
+}
+
+pub(crate) async fn queued_list(State(state): State<MockState>, params: Params) -> Response {
+    let store = state.lock();
+    if let Some(id) = number(&params, "id") {
+        return match find(&store.queued, id) {
+            Some(item) => ok("Queued item found.", item),
+            None => fail(ApiErrorCode::ItemNotFound, "Queued item not found."),
+        };
+    }
+
+    let offset = number(&params, "offset").unwrap_or(0) as usize;
+    let limit = number(&params, "limit").unwrap_or(1000) as usize;
+    let page: Vec<&Value> = store
+        .queued
+        .iter()
+        .filter(|item| {
+            params
+                .get("type")
+                .is_none_or(|kind| item["type"] == kind.as_str())
+        })
+        .skip(offset)
+        .take(limit)
+        .collect();
+
+    ok("Queue retrieved.", page)
+}
+
+/// Starting an item creates its download, as a finished one, and removes it from the queue.
+pub(crate) async fn queued_control(
+    State(state): State<MockState>,
+    Json(body): Json<Value>,
+) -> Response {
+    let store = &mut *state.lock();
+    let ids: Vec<u64> = match body["queued_id"].as_u64() {
+        Some(id) if find(&store.queued, id).is_some() => vec![id],
+        Some(_) => return fail(ApiErrorCode::ItemNotFound, "Queued item not found."),
+        None if body["all"].as_bool() == Some(true) => store
+            .queued
+            .iter()
+            .filter(|item| body["type"].is_null() || item["type"] == body["type"])
+            .filter_map(|item| item["id"].as_u64())
+            .collect(),
+        None => {
+            return fail(
+                ApiErrorCode::MissingRequiredOption,
+                "A queued_id or all is required.",
+            );
+        }
+    };
+
+    let operation = body["operation"].as_str().unwrap_or_default();
+    if !matches!(operation, "start" | "delete") {
+        return fail(ApiErrorCode::InvalidOption, "Invalid operation.");
+    }
+
+    let (started, kept) = store
+        .queued
+        .drain(..)
+        .partition(|item| item["id"].as_u64().is_some_and(|id| ids.contains(&id)));
+    store.queued = kept;
+
+    if operation == "start" {
+        for item in started {
+            let id = store.next_id();
+            let name = item["name"].as_str().unwrap_or("download");
+            let hash = item["hash"].as_str().map(str::to_string);
+            let files = [(name, 1024)];
+            match item["type"].as_str() {
+                Some("torrent") => {
+                    let hash = hash.unwrap_or_else(|| format!("{id:040x}"));
+                    let magnet = item["magnet"].as_str().map(str::to_string);
+                    let torrent = torrent_value(id, &hash, name, magnet, &files, &store.auth_id);
+                    store.torrents.push(torrent);
+                }
+                Some("webdl") => {
+                    let hash = hash.unwrap_or_else(|| format!("{id:032x}"));
+                    let link = item["link"].as_str().unwrap_or_default();
+                    let webdl = webdl_value(id, &hash, name, link, &files, &store.auth_id);
+                    store.webdls.push(webdl);
+                }
+                _ => {
+                    let hash = hash.unwrap_or_else(|| format!("{id:032x}"));
+                    let link = item["link"].as_str().map(str::to_string);
+                    let usenet = usenet_value(id, &hash, name, link, &files, &store.auth_id);
+                    store.usenet.push(usenet);
+                }
+            }
+        }
+    }
+
+    ok("Operation successful.", Value::Null)
+}
+
+/// Streams are only created for files that exist, their playlist is served under `cdn/hls/`.
+pub(crate) async fn stream_create(
+    State(state): State<MockState>,
+    headers: HeaderMap,
+    params: Params,
+) -> Response {
+    let store = state.lock();
+    let kind = params.get("type").map_or("", String::as_str);
+    let items = match kind {
+        "torrent" => &store.torrents,
+        "usenet" => &store.usenet,
+        "webdl" => &store.webdls,
+        _ => return fail(ApiErrorCode::InvalidOption, "Unknown download type."),
+    };
+    let (Some(id), Some(file_id)) = (number(&params, "id"), number(&params, "file_id")) else {
+        return fail(
+            ApiErrorCode::MissingRequiredOption,
+            "An id and file_id are required.",
+        );
+    };
+    let Some(item) = find(items, id) else {
+        return fail(ApiErrorCode::ItemNotFound, "Download not found.");
+    };
+    let has_file = item["files"]
+        .as_array()
+        .is_some_and(|files| files.iter().any(|file| file["id"] == file_id));
+    if !has_file {
+        return fail(ApiErrorCode::ItemNotFound, "File not found.");
+    }
+
+    let host = host(&headers);
+    ok(
+        "Stream created.",
+        json!({
+            "hls_url": format!("http://{host}/cdn/hls/{kind}/{id}/{file_id}/master.m3u8"),
+            "needs_transcoding": false,
+            "presigned_token": format!("presigned-{id}-{file_id}"),
+            "user_token": store.token,
+            "metadata": {
+                "audios": [
+                    { "index": 0, "language": "eng", "title": "English", "codec": "aac", "channels": 2 },
+                    { "index": 1, "language": "fre", "title": "Français", "codec": "ac3", "channels": 6 },
+                ],
+                "subtitles": [
+                    { "index": 0, "language": "eng", "title": "English", "codec": "subrip" },
+                ],
+                "resolutions": [
+                    { "index": 0, "width": 1920, "height": 1080 },
+                    { "index": 1, "width": 1280, "height": 720 },
+                ],
+                "duration": 60.0,
+            },
+        }),
+    )
+}
+
+pub(crate) async fn stream_master() -> Response {
+    let playlist = "#EXTM3U\n\
+        #EXT-X-MEDIA:TYPE=AUDIO,GROUP-ID=\"aud\",NAME=\"English\",LANGUAGE=\"eng\",DEFAULT=YES,URI=\"audio/0.m3u8\"\n\
+        #EXT-X-MEDIA:TYPE=AUDIO,GROUP-ID=\"aud\",NAME=\"Français\",LANGUAGE=\"fre\",URI=\"audio/1.m3u8\"\n\
+        #EXT-X-MEDIA:TYPE=SUBTITLES,GROUP-ID=\"subs\",NAME=\"English\",LANGUAGE=\"eng\",URI=\"subs/0.m3u8\"\n\
+        #EXT-X-STREAM-INF:BANDWIDTH=5000000,RESOLUTION=1920x1080,AUDIO=\"aud\",SUBTITLES=\"subs\"\n\
+        1080p/index.m3u8\n\
+        #EXT-X-STREAM-INF:BANDWIDTH=2500000,RESOLUTION=1280x720,AUDIO=\"aud\",SUBTITLES=\"subs\"\n\
+        720p/index.m3u8\n";
+
+    (
+        [(header::CONTENT_TYPE, "application/vnd.apple.mpegurl")],
+        playlist,
+    )
+        .into_response()
+}
+
+/// The registered vendor account, or the failure its endpoints answer with.
+fn vendor(vendor: &mut Option<Value>) -> Result<&mut Value, (ApiErrorCode, &'static str)> {
+    match vendor {
+        None => Err((ApiErrorCode::ItemNotFound, "No vendor account registered.")),
+        Some(vendor) if vendor["status"] == "disabled" => Err((
+            ApiErrorCode::VendorDisabled,
+            "This vendor account has been disabled.",
+        )),
+        Some(vendor) => Ok(vendor),
+    }
+}
+
+pub(crate) async fn vendor_register(
+    State(state): State<MockState>,
+    Json(body): Json<Value>,
+) -> Response {
+    let mut store = state.lock();
+    if store.vendor.is_some() {
+        return fail(ApiErrorCode::DuplicateItem, "You are already a vendor.");
+    }
+
+    let id = store.next_id();
+    let now = Utc::now().fixed_offset();
+    let account = json!({
+        "id": id,
+        "created_at": now,
+        "updated_at": now,
+        "auth_id": store.auth_id,
+        "vendor_name": body["vendor_name"],
+        "vendor_url": body["vendor_url"],
+        "vendor_webhook": body["vendor_webhook"],
+        "status": "active",
+        "users_allowed": VENDOR_USERS_ALLOWED,
+        "current_users": 0,
+        "total_bytes_downloaded": 0,
+        "total_bytes_uploaded": 0,
+    });
+    store.vendor = Some(account.clone());
+    store.user["is_vendor"] = json!(true);
+    store.user["vendor_id"] = json!(id.to_string());
+
+    ok("Vendor registered.", account)
+}
+
+pub(crate) async fn vendor_account(State(state): State<MockState>) -> Response {
+    let store = &mut *state.lock();
+    match vendor(&mut store.vendor) {
+        Ok(account) => {
+            account["current_users"] = json!(store.vendor_users.len());
+            ok("Vendor account retrieved.", account)
+        }
+        Err((code, detail)) => fail(code, detail),
+    }
+}
+
+pub(crate) async fn vendor_update(
+    State(state): State<MockState>,
+    Json(body): Json<Value>,
+) -> Response {
+    let mut store = state.lock();
+    let account = match vendor(&mut store.vendor) {
+        Ok(account) => account,
+        Err((code, detail)) => return fail(code, detail),
+    };
+    for key in ["vendor_url", "vendor_webhook"] {
+        if !body[key].is_null() {
+            account[key] = body[key].clone();
+        }
+    }
+    account["updated_at"] = json!(Utc::now().fixed_offset());
+
+    ok("Vendor account updated.", Value::Null)
+}
+
+pub(crate) async fn vendor_users(State(state): State<MockState>) -> Response {
+    let store = &mut *state.lock();
+    if let Err((code, detail)) = vendor(&mut store.vendor) {
+        return fail(code, detail);
+    }
+    ok("Accounts retrieved.", &store.vendor_users)
+}
+
+pub(crate) async fn vendor_user(State(state): State<MockState>, params: Params) -> Response {
+    let store = &mut *state.lock();
+    if let Err((code, detail)) = vendor(&mut store.vendor) {
+        return fail(code, detail);
+    }
+    let auth_id = params.get("user_auth_id").map_or("", String::as_str);
+    match store
+        .vendor_users
+        .iter()
+        .find(|user| user["auth_id"] == auth_id)
+    {
+        Some(user) => ok("Account retrieved.", user),
+        None => fail(ApiErrorCode::ItemNotFound, "Account not found."),
+    }
+}
+
+pub(crate) async fn vendor_register_user(
+    State(state): State<MockState>,
+    Json(body): Json<Value>,
+) -> Response {
+    let store = &mut *state.lock();
+    if let Err((code, detail)) = vendor(&mut store.vendor) {
+        return fail(code, detail);
+    }
+    let Some(email) = body["user_email"].as_str() else {
+        return fail(
+            ApiErrorCode::MissingRequiredOption,
+            "A user_email is required.",
+        );
+    };
+    if store.vendor_users.iter().any(|user| user["email"] == email) {
+        return fail(
+            ApiErrorCode::DuplicateItem,
+            "This email is already registered.",
+        );
+    }
+    if store.vendor_users.len() as u64 >= VENDOR_USERS_ALLOWED {
+        return fail(
+            ApiErrorCode::PlanRestrictedFeature,
+            "You have reached the number of accounts allowed.",
+        );
+    }
+
+    let id = store.next_id();
+    let auth_id = format!("{id:08x}-0000-0000-0000-000000000000");
+    store.vendor_users.push(json!({
+        "id": id,
+        "created_at": Utc::now().fixed_offset(),
+        "auth_id": auth_id,
+        "email": email,
+        "plan": 0,
+        "premium_expires_at": null,
+        "total_bytes_downloaded": 0,
+        "total_bytes_uploaded": 0,
+    }));
+
+    ok(
+        "Account registered.",
+        json!({ "auth_id": auth_id, "token": format!("vendor-token-{id}") }),
+    )
+}
+
+pub(crate) async fn vendor_remove_user(
+    State(state): State<MockState>,
+    Json(body): Json<Value>,
+) -> Response {
+    let store = &mut *state.lock();
+    if let Err((code, detail)) = vendor(&mut store.vendor) {
+        return fail(code, detail);
+    }
+    let count = store.vendor_users.len();
+    store
+        .vendor_users
+        .retain(|user| user["auth_id"] != body["user_auth_id"]);
+
+    if store.vendor_users.len() == count {
+        return fail(ApiErrorCode::ItemNotFound, "Account not found.");
+    }
+    ok("Account removed.", Value::Null)
+}
+
+/// Every refresh hands out a new token, numbered from the shared id counter.
+pub(crate) async fn vendor_refresh(
+    State(state): State<MockState>,
+    Json(body): Json<Value>,
+) -> Response {
+    let store = &mut *state.lock();
+    if let Err((code, detail)) = vendor(&mut store.vendor) {
+        return fail(code, detail);
+    }
+    let exists = store
+        .vendor_users
+        .iter()
+        .any(|user| user["auth_id"] == body["user_auth_id"]);
+    if !exists {
+        return fail(ApiErrorCode::ItemNotFound, "Account not found.");
+    }
+
+    let token = format!("vendor-token-{}", store.next_id());
+    ok(
+        "Token refreshed.",
+        json!({ "auth_id": body["user_auth_id"], "token": token }),
+    )
+}
+
+pub(crate) async fn stats(State(state): State<MockState>) -> Response {
+    let store = state.lock();
+    let mut stats = store.stats.clone();
+    stats["active_torrents"] = json!(store.torrents.len());
+    stats["active_web_downloads"] = json!(store.webdls.len());
+    stats["active_usenet_downloads"] = json!(store.usenet.len());
+    ok("Stats retrieved.", stats)
+}
+
+/// A single version, released now.
+fn changelog_versions() -> Value {
+    json!([{
+        "id": "v1",
+        "name": "v1.0.0",
+        "html": "<p>Initial release.</p>",
+        "markdown": "Initial release.",
+        "link": "https://torbox.app/changelog#v1",
+        "created_at": Utc::now().fixed_offset(),
+    }])
+}
+
+pub(crate) async fn changelogs_json() -> Response {
+    ok("Changelogs retrieved.", changelog_versions())
+}
+
+pub(crate) async fn changelogs_rss() -> Response {
+    let items = changelog_versions()
+        .as_array()
+        .into_iter()
+        .flatten()
+        .map(|v| {
+            (
+                v["name"].as_str().unwrap_or_default().to_string(),
+                v["markdown"].as_str().unwrap_or_default().to_string(),
+            )
+        })
+        .collect::<Vec<_>>();
+
+    rss("TorBox Changelog", items)
+}
+
+/// One test file per region, `us` being the closest, filtered by `region` and sized by `test_length`.
+pub(crate) async fn speedtest(params: Params) -> Response {
+    let length = params.get("test_length").map_or("long", String::as_str);
+    let files: Vec<Value> = [
+        ("us", "New York", 40.7, -74.0),
+        ("eu", "Amsterdam", 52.4, 4.9),
+    ]
+    .into_iter()
+    .filter(|(region, ..)| params.get("region").is_none_or(|r| r == region))
+    .map(|(region, name, lat, lng)| {
+        let domain = format!("{region}.speedtest.torbox.app");
+        let path = format!("/{length}.bin");
+        json!({
+            "region": region,
+            "name": name,
+            "domain": domain,
+            "url": format!("https://{domain}{path}"),
+            "path": path,
+            "closest": region == "us",
+            "coordinates": { "lat": lat, "lng": lng },
+        })
+    })
+    .collect();
+
+    ok("Speedtest files retrieved.", files)
+}
+
+pub(crate) async fn search_torrents(
+    State(state): State<MockState>,
+    Path(query): Path<String>,
+    params: Params,
+) -> Response {
+    let data = search(&state.lock().torrents, &query, &params, "torrent");
+    ok("Found torrents.", data)
+}
+
+pub(crate) async fn search_usenet(
+    State(state): State<MockState>,
+    Path(query): Path<String>,
+    params: Params,
+) -> Response {
+    let data = search(&state.lock().usenet, &query, &params, "usenet");
+    ok("Found usenet downloads.", data)
+}
+
+pub(crate) async fn search_torrents_by_id(
+    State(state): State<MockState>,
+    Path(id): Path<String>,
+    params: Params,
+) -> Response {
+    search_by_id(&state.lock().torrents, &id, &params, "torrent")
+}
+
+pub(crate) async fn search_usenet_by_id(
+    State(state): State<MockState>,
+    Path(id): Path<String>,
+    params: Params,
+) -> Response {
+    search_by_id(&state.lock().usenet, &id, &params, "usenet")
+}
+
+/// Every download matches an `imdb:`, `tmdb:` or `tvdb:` id, the mock keeps no metadata to filter on.
+fn search_by_id(
+    items: &[Value],
+    id: &str,
+    params: &HashMap<String, String>,
+    kind: &str,
+) -> Response {
+    let known = ["imdb:", "tmdb:", "tvdb:"]
+        .iter()
+        .any(|prefix| id.strip_prefix(prefix).is_some_and(|rest| !rest.is_empty()));
+    if !known {
+        return fail(ApiErrorCode::SearchError, "Unknown id.");
+    }
+
+    let mut data = search(items, "", params, kind);
+    if data["metadata"].is_object() {
+        data["metadata"]["title"] = json!(id);
+    }
+    ok("Found results.", data)
+}
+
+/// Serves the content of a download file, honoring `Range: bytes=N-` requests.
+pub(crate) async fn cdn(
+    State(state): State<MockState>,
+    Path((kind, id, file)): Path<(String, u64, String)>,
+    interrupt: Option<Extension<Interrupt>>,
+    headers: HeaderMap,
+) -> Response {
+    let size = {
+        let store = state.lock();
+        let items = match kind.as_str() {
+            "torrents" => &store.torrents,
+            "webdl" => &store.webdls,
+            "usenet" => &store.usenet,
+            _ => return fail(ApiErrorCode::EndpointNotFound, "Unknown download kind."),
+        };
+        let Some(item) = find(items, id) else {
+            return fail(ApiErrorCode::ItemNotFound, "Download not found.");
+        };
+
+        let size = match file.parse::<u64>() {
+            Ok(file_id) => item["files"]
+                .as_array()
+                .and_then(|files| files.iter().find(|f| f["id"] == file_id))
+                .map(|f| &f["size"]),
+            Err(_) => Some(&item["size"]),
+        };
+        match size.and_then(Value::as_f64) {
+            Some(size) => size as u64,
+            None => return fail(ApiErrorCode::ItemNotFound, "File not found."),
+        }
+    };
+
+    let start = headers
+        .get(header::RANGE)
+        .and_then(|v| v.to_str().ok())
+        .and_then(|v| v.strip_prefix("bytes="))
+        .and_then(|v| v.strip_suffix('-'))
+        .and_then(|v| v.parse::<u64>().ok());
+
+    let (status, start) = match start {
+        Some(start) if start >= size => {
+            return (
+                StatusCode::RANGE_NOT_SATISFIABLE,
+                [(header::CONTENT_RANGE, format!("bytes */{size}"))],
+            )
+                .into_response();
+        }
+        Some(start) => (StatusCode::PARTIAL_CONTENT, start),
+        None => (StatusCode::OK, 0),
+    };
+
+    let content = file_content(size).split_off(start as usize);
+    let len = content.len();
+
+    let body = match interrupt {
+        Some(Extension(Interrupt(after))) => {
+            let sent = content[..(after as usize).min(len)].to_vec();
+            Body::from_stream(stream::iter([
+                Ok(Bytes::from(sent)),
+                Err(io::Error::other("Injected interruption")),
+            ]))
+        }
+        None => Body::from(content),
+    };
+
+    let mut res = (status, body).into_response();
+    res.headers_mut()
+        .insert(header::CONTENT_LENGTH, HeaderValue::from(len));
+    res.headers_mut()
+        .insert(header::ACCEPT_RANGES, HeaderValue::from_static("bytes"));
+    if status == StatusCode::PARTIAL_CONTENT {
+        let range = format!("bytes {start}-{}/{size}", size - 1);
+        res.headers_mut().insert(
+            header::CONTENT_RANGE,
+            HeaderValue::from_str(&range).unwrap(),
+        );
+    }
+    res
+}
+
+pub(crate) async fn not_found() -> Response {
+    fail(ApiErrorCode::EndpointNotFound, "Endpoint not found.")
+}
@@ -0,0 +1,298 @@
+//! In-memory mock of the TorBox API.
+//!
+//! [`MockServer`] serves the endpoints used by the SDK (torrents, web downloads, usenet, user, notifications, RSS,
+//! integrations, queued downloads, streams, vendors, general and search) on a random local port, so test suites can
+//! run offline by pointing a client at it:
+//!
+//! ```no_run
+//! # async fn run() {
+//! use torbox_mock::MockServer;
+//!
+//! let server = MockServer::start().await;
+//! let id = server.state().add_torrent("ubuntu", &[("ubuntu.iso", 4096)]);
+//!
+//! // Same as `TorboxClient::new(server.token()).with_base_url(server.base_url())`
+//! let client = server.client();
+//! # }
+//! ```
+//!
+//! Download links point back to the server, under `cdn/{kind}/{id}/{file_id or zip}`, and serve
+//! [`file_content`] with support for `Range` requests. Streams get a fixed HLS master playlist under
+//! `cdn/hls/{kind}/{id}/{file_id}/master.m3u8`.
+//!
+//! Failures can be injected per endpoint path with [`MockState::inject_error`] and [`MockState::inject`].
+
+mod handlers;
+mod state;
+
+use std::net::SocketAddr;
+
+use axum::{
+    Router, middleware,
+    routing::{delete, get, post, put},
+};
+use tokio::{net::TcpListener, task::JoinHandle};
+use torbox_core_rs::client::TorboxClient;
+
+pub use crate::state::{Injection, MockState, RecordedRequest, file_content};
+
+/// Token accepted by [`MockServer::start`].
+pub const MOCK_TOKEN: &str = "mock-token";
+
+/// Session token accepted by `api/user/refreshtoken`, which then replaces the API token with a new one.
+pub const MOCK_SESSION_TOKEN: &str = "mock-session";
+
+/// A running mock server, stopped when dropped.
+#[derive(Debug)]
+pub struct MockServer {
+    addr: SocketAddr,
+    state: MockState,
+    handle: JoinHandle<()>,
+}
+
+impl MockServer {
+    /// Starts a server accepting [`MOCK_TOKEN`].
+    pub async fn start() -> Self {
+        Self::start_with_token(MOCK_TOKEN).await
+    }
+
+    /// Starts a server accepting `token` only.
+    pub async fn start_with_token(token: impl Into<String>) -> Self {
+        let state = MockState::new(token);
+        let listener = TcpListener::bind("127.0.0.1:0")
+            .await
+            .expect("Failed to bind the mock server");
+        let addr = listener.local_addr().expect("Mock server has no address");
+
+        let app = router(state.clone());
+        let handle = tokio::spawn(async move {
+            axum::serve(listener, app).await.ok();
+        });
+
+        Self {
+            addr,
+            state,
+            handle,
+        }
+    }
+
+    /// Base URL to give to `TorboxClient::with_base_url`, e.g. `http://127.0.0.1:4242/v1`.
+    pub fn base_url(&self) -> String {
+        format!("http://{}/v1", self.addr)
+    }
+
+    /// Base URL to give to `SearchApi::with_base_url`, e.g. `http://127.0.0.1:4242/search`.
+    pub fn search_url(&self) -> String {
+        format!("http://{}/search", self.addr)
+    }
+
+    pub fn token(&self) -> String {
+        self.state.token()
+    }
+
+    /// A client authenticated with the server token and pointed at the server.
+    pub fn client(&self) -> TorboxClient {
+        TorboxClient::new(self.token()).with_base_url(self.base_url())
+    }
+
+    pub fn state(&self) -> &MockState {
+        &self.state
+    }
+}
+
+impl Drop for MockServer {
+    fn drop(&mut self) {
+        self.handle.abort();
+    }
+}
+
+fn router(state: MockState) -> Router {
+    Router::new()
+        .route("/v1/api/torrents/mylist", get(handlers::torrents_mylist))
+        .route(
+            "/v1/api/torrents/createtorrent",
+            post(handlers::torrents_create),
+        )
+        .route(
+            "/v1/api/torrents/controltorrent",
+            post(handlers::torrents_control),
+        )
+        .route("/v1/api/torrents/edittorrent", put(handlers::torrents_edit))
+        .route(
+            "/v1/api/torrents/requestdl",
+            get(handlers::torrents_requestdl),
+        )
+        .route(
+            "/v1/api/torrents/checkcached",
+            get(handlers::torrents_checkcached),
+        )
+        .route(
+            "/v1/api/torrents/torrentinfo",
+            get(handlers::torrents_info_get).post(handlers::torrents_info_post),
+        )
+        .route(
+            "/v1/api/torrents/exportdata",
+            get(handlers::torrents_exportdata),
+        )
+        .route("/v1/api/webdl/mylist", get(handlers::webdl_mylist))
+        .route(
+            "/v1/api/webdl/createwebdownload",
+            post(handlers::webdl_create),
+        )
+        .route(
+            "/v1/api/webdl/controlwebdownload",
+            post(handlers::webdl_control),
+        )
+        .route("/v1/api/webdl/editwebdownload", put(handlers::webdl_edit))
+        .route("/v1/api/webdl/requestdl", get(handlers::webdl_requestdl))
+        .route(
+            "/v1/api/webdl/checkcached",
+            get(handlers::webdl_checkcached),
+        )
+        .route("/v1/api/webdl/hosters", get(handlers::webdl_hosters))
+        .route("/v1/api/usenet/mylist", get(handlers::usenet_mylist))
+        .route(
+            "/v1/api/usenet/createusenetdownload",
+            post(handlers::usenet_create),
+        )
+        .route(
+            "/v1/api/usenet/controlusenetdownload",
+            post(handlers::usenet_control),
+        )
+        .route("/v1/api/usenet/requestdl", get(handlers::usenet_requestdl))
+        .route(
+            "/v1/api/usenet/checkcached",
+            get(handlers::usenet_checkcached),
+        )
+        .route("/v1/api/user/me", get(handlers::user_me))
+        .route(
+            "/v1/api/user/settings/editsettings",
+            put(handlers::user_edit_settings),
+        )
+        .route(
+            "/v1/api/user/getconfirmation",
+            get(handlers::user_getconfirmation),
+        )
+        .route("/v1/api/user/addreferral", post(handlers::user_addreferral))
+        .route(
+            "/v1/api/user/referraldata",
+            get(handlers::user_referraldata),
+        )
+        .route(
+            "/v1/api/user/subscriptions",
+            get(handlers::user_subscriptions),
+        )
+        .route(
+            "/v1/api/user/transactions",
+            get(handlers::user_transactions),
+        )
+        .route(
+            "/v1/api/user/settings/searchengines",
+            get(handlers::user_searchengines),
+        )
+        .route(
+            "/v1/api/user/settings/controlsearchengines",
+            get(handlers::user_control_searchengines).post(handlers::user_control_searchengines),
+        )
+        .route(
+            "/v1/api/user/settings/modifysearchengines",
+            get(handlers::user_modify_searchengines).post(handlers::user_modify_searchengines),
+        )
+        .route(
+            "/v1/api/user/refreshtoken",
+            post(handlers::user_refresh_token),
+        )
+        .route(
+            "/v1/api/user/auth/device/start",
+            get(handlers::device_start),
+        )
+        .route(
+            "/v1/api/user/auth/device/token",
+            post(handlers::device_token),
+        )
+        .route(
+            "/v1/api/notifications/mynotifications",
+            get(handlers::notifications_list),
+        )
+        .route(
+            "/v1/api/notifications/rss",
+            get(handlers::notifications_rss),
+        )
+        .route(
+            "/v1/api/notifications/clear",
+            post(handlers::notifications_clear),
+        )
+        .route(
+            "/v1/api/notifications/test",
+            post(handlers::notifications_test),
+        )
+        .route("/v1/api/rss/addrss", post(handlers::rss_add))
+        .route("/v1/api/rss/getfeeds", get(handlers::rss_feeds))
+        .route("/v1/api/rss/getfeeditems", get(handlers::rss_items))
+        .route("/v1/api/rss/modifyrss", post(handlers::rss_modify))
+        .route("/v1/api/rss/controlrss", post(handlers::rss_control))
+        .route(
+            "/v1/api/integration/{provider}",
+            post(handlers::integration_queue),
+        )
+        .route("/v1/api/integration/jobs", get(handlers::integration_jobs))
+        .route(
+            "/v1/api/integration/jobs/{hash}",
+            get(handlers::integration_jobs_by_hash),
+        )
+        .route(
+            "/v1/api/integration/job/{id}",
+            get(handlers::integration_job).delete(handlers::integration_job_cancel),
+        )
+        .route("/v1/api/queued/getqueued", get(handlers::queued_list))
+        .route(
+            "/v1/api/queued/controlqueued",
+            post(handlers::queued_control),
+        )
+        .route("/v1/api/stream/createstream", get(handlers::stream_create))
+        .route("/v1/api/vendors/register", post(handlers::vendor_register))
+        .route("/v1/api/vendors/account", get(handlers::vendor_account))
+        .route(
+            "/v1/api/vendors/updateaccount",
+            put(handlers::vendor_update),
+        )
+        .route("/v1/api/vendors/getaccounts", get(handlers::vendor_users))
+        .route("/v1/api/vendors/getaccount", get(handlers::vendor_user))
+        .route(
+            "/v1/api/vendors/registeruser",
+            post(handlers::vendor_register_user),
+        )
+        .route(
+            "/v1/api/vendors/removeuser",
+            delete(handlers::vendor_remove_user),
+        )
+        .route("/v1/api/vendors/refresh", post(handlers::vendor_refresh))
+        .route("/v1/api/stats", get(handlers::stats))
+        .route("/v1/api/changelogs/json", get(handlers::changelogs_json))
+        .route("/v1/api/changelogs/rss", get(handlers::changelogs_rss))
+        .route("/v1/api/speedtest", get(handlers::speedtest))
+        .route(
+            "/search/torrents/search/{query}",
+            get(handlers::search_torrents),
+        )
+        .route(
+            "/search/usenet/search/{query}",
+            get(handlers::search_usenet),
+        )
+        .route(
+            "/search/torrents/{id}",
+            get(handlers::search_torrents_by_id),
+        )
+        .route("/search/usenet/{id}", get(handlers::search_usenet_by_id))
+        .route("/cdn/{kind}/{id}/{file}", get(handlers::cdn))
+        .route(
+            "/cdn/hls/{kind}/{id}/{file}/master.m3u8",
+            get(handlers::stream_master),
+        )
+        .fallback(handlers::not_found)
+        .layer(middleware::from_fn_with_state(
+            state.clone(),
+            handlers::guard,
+        ))
+        .with_state(state)
+}
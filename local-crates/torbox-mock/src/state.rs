@@ -0,0 +1,595 @@
+use std::{
+    collections::{HashMap, VecDeque},
+    sync::{Arc, Mutex, MutexGuard},
+};
+
+use chrono::Utc;
+use serde_json::{Value, json};
+use torbox_core_rs::{
+    data::{
+        hash::InfoHash,
+        queued::{QueuedDownload, QueuedType},
+        torrent::{TorrentDownloadState, TorrentFile, TorrentStatus},
+        usenet::{UsenetFile, UsenetStatus},
+        webdownload::{WebdownloadFile, WebdownloadStatus},
+    },
+    error::ApiErrorCode,
+};
+
+use crate::MOCK_SESSION_TOKEN;
+
+/// Failure returned instead of the normal response for the next request to a path.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Injection {
+    /// `{"success": false, "error": <code>}` with a matching HTTP status.
+    Error(ApiErrorCode),
+    /// A bare HTTP status, with an optional `Retry-After` header in seconds.
+    Status {
+        status: u16,
+        retry_after: Option<u64>,
+    },
+    /// Cuts the connection after sending `after` bytes of the body, meant for `cdn/` download paths.
+    Interrupt { after: u64 },
+    /// A `307 Temporary Redirect` to `location`, sent as is so it may be relative.
+    Redirect { location: String },
+    /// Lets the request through, so that the next injection hits a later request to the same path.
+    Pass,
+}
+
+/// Content served for a download file of `size` bytes.
+pub fn file_content(size: u64) -> Vec<u8> {
+    (0..size).map(|i| (i % 251) as u8).collect()
+}
+
+fn content_md5(size: u64) -> Option<String> {
+    Some(format!("{:x}", md5::compute(file_content(size))))
+}
+
+/// Hash of a web download, the MD5 of its link like on TorBox.
+pub(crate) fn link_hash(link: &str) -> String {
+    format!("{:x}", md5::compute(link))
+}
+
+/// A request received by the mock, in the order it arrived.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RecordedRequest {
+    pub method: String,
+    /// Path relative to the base URL, e.g. `api/torrents/mylist`.
+    pub path: String,
+    pub query: Option<String>,
+}
+
+#[derive(Debug)]
+pub(crate) struct Store {
+    pub token: String,
+    pub session_token: String,
+    pub auth_id: String,
+    next_id: u64,
+    pub torrents: Vec<Value>,
+    pub webdls: Vec<Value>,
+    pub usenet: Vec<Value>,
+    pub notifications: Vec<Value>,
+    pub rss_feeds: Vec<Value>,
+    pub rss_items: Vec<Value>,
+    pub jobs: Vec<Value>,
+    pub queued: Vec<Value>,
+    pub vendor: Option<Value>,
+    pub vendor_users: Vec<Value>,
+    pub devices: Vec<Value>,
+    pub search_engines: Vec<Value>,
+    pub user: Value,
+    pub stats: Value,
+    pub injections: HashMap<String, VecDeque<Injection>>,
+    pub scripts: HashMap<u64, VecDeque<Value>>,
+    pub requests: Vec<RecordedRequest>,
+}
+
+impl Store {
+    pub fn next_id(&mut self) -> u64 {
+        self.next_id += 1;
+        self.next_id
+    }
+}
+
+/// Shared in-memory state of a [`MockServer`](crate::MockServer).
+///
+/// Every download added here is serialized from the `torbox-core-rs` data types, so the mock always
+/// answers with the shapes the SDK expects.
+#[derive(Debug, Clone)]
+pub struct MockState {
+    inner: Arc<Mutex<Store>>,
+}
+
+impl MockState {
+    pub fn new(token: impl Into<String>) -> Self {
+        let auth_id = "00000000-0000-0000-0000-000000000000".to_string();
+        let now = Utc::now().fixed_offset();
+
+        let user = json!({
+            "id": 1,
+            "auth_id": auth_id,
+            "created_at": now,
+            "updated_at": now,
+            "plan": 2,
+            "total_downloaded": 0,
+            "customer": "cus_mock",
+            "is_subscribed": true,
+            "premium_expires_at": now,
+            "cooldown_until": now,
+            "email": "mock@torbox.app",
+            "user_referral": auth_id,
+            "base_email": "mock@torbox.app",
+            "server": 1,
+            "total_bytes_downloaded": 0,
+            "total_bytes_uploaded": 0,
+            "torrents_downloaded": 0,
+            "web_downloads_downloaded": 0,
+            "usenet_downloads_downloaded": 0,
+            "additional_concurrent_slots": 0,
+            "long_term_seeding": false,
+            "long_term_storage": false,
+            "is_vendor": false,
+            "vendor_id": null,
+            "purchases_referred": 0,
+            "settings": {
+                "seed_torrents": 1,
+                "allow_zipped": true,
+                "email_notifications": true,
+                "web_notifications": true,
+                "discord_notifications": false,
+                "webhook_notifications": false,
+                "webhook_url": null,
+                "stremio_seed_torrents": 3,
+                "webdav_flatten": false,
+            },
+        });
+
+        let stats = json!({
+            "total_downloads": 0,
+            "total_users": 1,
+            "total_bytes_downloaded": 0,
+            "total_bytes_uploaded": 0,
+            "active_torrents": 0,
+            "active_usenet_downloads": 0,
+            "active_web_downloads": 0,
+            "total_usenet_downloads": 0,
+            "total_torrent_downloads": 0,
+            "total_web_downloads": 0,
+            "total_servers": 1,
+        });
+
+        Self {
+            inner: Arc::new(Mutex::new(Store {
+                token: token.into(),
+                session_token: MOCK_SESSION_TOKEN.to_string(),
+                auth_id,
+                next_id: 0,
+                torrents: Vec::new(),
+                webdls: Vec::new(),
+                usenet: Vec::new(),
+                notifications: Vec::new(),
+                rss_feeds: Vec::new(),
+                rss_items: Vec::new(),
+                jobs: Vec::new(),
+                queued: Vec::new(),
+                vendor: None,
+                vendor_users: Vec::new(),
+                devices: Vec::new(),
+                search_engines: Vec::new(),
+                user,
+                stats,
+                injections: HashMap::new(),
+                scripts: HashMap::new(),
+                requests: Vec::new(),
+            })),
+        }
+    }
+
+    pub(crate) fn lock(&self) -> MutexGuard<'_, Store> {
+        self.inner.lock().unwrap()
+    }
+
+    /// The API token the mock accepts.
+    pub fn token(&self) -> String {
+        self.lock().token.clone()
+    }
+
+    /// Replaces the accepted API token, requests made with the previous one then fail with `BAD_TOKEN`.
+    pub fn set_token(&self, token: impl Into<String>) {
+        self.lock().token = token.into();
+    }
+
+    /// Makes the next request to `path` (e.g. `api/torrents/createtorrent`) fail with `injection`.
+    ///
+    /// Injections for the same path are consumed in the order they were added.
+    pub fn inject(&self, path: impl Into<String>, injection: Injection) {
+        self.lock()
+            .injections
+            .entry(path.into())
+            .or_default()
+            .push_back(injection);
+    }
+
+    /// Shorthand for `inject(path, Injection::Error(code))`.
+    pub fn inject_error(&self, path: impl Into<String>, code: ApiErrorCode) {
+        self.inject(path, Injection::Error(code));
+    }
+
+    /// Every request received so far.
+    pub fn requests(&self) -> Vec<RecordedRequest> {
+        self.lock().requests.clone()
+    }
+
+    /// Number of requests received for `path`.
+    pub fn request_count(&self, path: &str) -> usize {
+        self.lock()
+            .requests
+            .iter()
+            .filter(|req| req.path == path)
+            .count()
+    }
+
+    /// Adds a finished torrent with the given files (name, size) and returns its id.
+    pub fn add_torrent(&self, name: &str, files: &[(&str, u64)]) -> u64 {
+        let mut store = self.lock();
+        let id = store.next_id();
+        let hash = format!("{id:040x}");
+        let torrent = torrent_value(id, &hash, name, None, files, &store.auth_id);
+        store.torrents.push(torrent);
+        id
+    }
+
+    /// Adds a finished web download with the given files (name, size) and returns its id.
+    pub fn add_webdownload(&self, name: &str, link: &str, files: &[(&str, u64)]) -> u64 {
+        let mut store = self.lock();
+        let id = store.next_id();
+        let hash = link_hash(link);
+        let webdl = webdl_value(id, &hash, name, link, files, &store.auth_id);
+        store.webdls.push(webdl);
+        id
+    }
+
+    /// Adds a finished usenet download with the given files (name, size) and returns its id.
+    pub fn add_usenet(&self, name: &str, files: &[(&str, u64)]) -> u64 {
+        let mut store = self.lock();
+        let id = store.next_id();
+        let hash = format!("{id:032x}");
+        let usenet = usenet_value(id, &hash, name, None, files, &store.auth_id);
+        store.usenet.push(usenet);
+        id
+    }
+
+    /// Adds a download waiting for an active slot and returns its id.
+    ///
+    /// `source` is the magnet of a torrent, or the link of a web or usenet download.
+    pub fn add_queued(&self, queued_type: QueuedType, name: &str, source: &str) -> u64 {
+        let mut store = self.lock();
+        let id = store.next_id();
+        let item = queued_value(id, queued_type, name, source, &store.auth_id);
+        store.queued.push(item);
+        id
+    }
+
+    /// Adds a notification to the user's feed and returns its id.
+    pub fn add_notification(&self, title: &str, message: &str) -> u64 {
+        let mut store = self.lock();
+        let id = store.next_id();
+        let auth_id = store.auth_id.clone();
+        store.notifications.push(json!({
+            "id": id,
+            "created_at": Utc::now().fixed_offset(),
+            "title": title,
+            "message": message,
+            "auth_id": auth_id,
+        }));
+        id
+    }
+
+    /// Adds an item found in the RSS feed `feed_id` and returns its id.
+    pub fn add_rss_item(&self, feed_id: u64, title: &str) -> u64 {
+        let mut store = self.lock();
+        let id = store.next_id();
+        store.rss_items.push(json!({
+            "id": id,
+            "created_at": Utc::now().fixed_offset(),
+            "rss_feed_id": feed_id,
+            "title": title,
+            "link": format!("https://example.com/{id}.torrent"),
+            "downloaded": false,
+        }));
+        id
+    }
+
+    /// Adds a search engine of the user, e.g. a `prowlarr` instance at `url`, and returns its id.
+    pub fn add_search_engine(&self, kind: &str, url: &str) -> u64 {
+        let mut store = self.lock();
+        let id = store.next_id();
+        let auth_id = store.auth_id.clone();
+        store.search_engines.push(json!({
+            "id": id,
+            "created_at": Utc::now().fixed_offset(),
+            "auth_id": auth_id,
+            "type": kind,
+            "url": url,
+            "apikey": "",
+            "active": true,
+            "valid": true,
+            "download_type": "torrents",
+            "indexers": [],
+        }));
+        id
+    }
+
+    /// Queues changes applied to a download, integration job or device code each time it is fetched by id,
+    /// one patch per request.
+    ///
+    /// Each patch is a JSON object whose fields replace the ones of the item, which lets tests script the
+    /// progress seen by a polling client, e.g. `json!({"download_state": "downloading", "progress": 0.5})`.
+    pub fn script(&self, id: u64, patches: impl IntoIterator<Item = Value>) {
+        self.lock().scripts.entry(id).or_default().extend(patches);
+    }
+
+    /// Current JSON of the torrent with this id, if it exists.
+    pub fn torrent(&self, id: u64) -> Option<Value> {
+        find(&self.lock().torrents, id).cloned()
+    }
+
+    /// Current JSON of the web download with this id, if it exists.
+    pub fn webdownload(&self, id: u64) -> Option<Value> {
+        find(&self.lock().webdls, id).cloned()
+    }
+
+    /// Current JSON of the usenet download with this id, if it exists.
+    pub fn usenet(&self, id: u64) -> Option<Value> {
+        find(&self.lock().usenet, id).cloned()
+    }
+
+    /// Current JSON of the RSS feed with this id, if it exists.
+    pub fn rss_feed(&self, id: u64) -> Option<Value> {
+        find(&self.lock().rss_feeds, id).cloned()
+    }
+
+    /// Current JSON of the integration job with this id, if it exists.
+    pub fn job(&self, id: u64) -> Option<Value> {
+        find(&self.lock().jobs, id).cloned()
+    }
+
+    /// Current JSON of the queued item with this id, if it exists.
+    pub fn queued(&self, id: u64) -> Option<Value> {
+        find(&self.lock().queued, id).cloned()
+    }
+
+    /// Current JSON of the search engine with this id, if it exists.
+    pub fn search_engine(&self, id: u64) -> Option<Value> {
+        find(&self.lock().search_engines, id).cloned()
+    }
+
+    /// Current JSON of the vendor account, if the user registered one.
+    pub fn vendor(&self) -> Option<Value> {
+        self.lock().vendor.clone()
+    }
+
+    /// Disables the vendor account, every vendor endpoint then fails with `VENDOR_DISABLED`.
+    pub fn disable_vendor(&self) {
+        if let Some(vendor) = &mut self.lock().vendor {
+            vendor["status"] = json!("disabled");
+        }
+    }
+
+    /// Current JSON of the device code login started with this `device_code`, if any.
+    ///
+    /// Its `status` is the answer of the next token poll: `authorization_pending`, `slow_down`,
+    /// `expired_token`, `access_denied` or `authorized`.
+    pub fn device(&self, device_code: &str) -> Option<Value> {
+        let store = self.lock();
+        store
+            .devices
+            .iter()
+            .find(|device| device["device_code"] == device_code)
+            .cloned()
+    }
+
+    /// Approves a device code login, as the user would by entering its `code`, the next token poll
+    /// then grants the mock token.
+    pub fn approve_device(&self, code: &str) {
+        let mut store = self.lock();
+        if let Some(device) = store
+            .devices
+            .iter_mut()
+            .find(|device| device["code"] == code)
+        {
+            device["status"] = json!("authorized");
+        }
+    }
+
+    /// Replaces the user profile returned by `api/user/me`.
+    pub fn set_user(&self, user: Value) {
+        self.lock().user = user;
+    }
+}
+
+pub(crate) fn find(items: &[Value], id: u64) -> Option<&Value> {
+    items.iter().find(|item| item["id"] == id)
+}
+
+pub(crate) fn find_mut(items: &mut [Value], id: u64) -> Option<&mut Value> {
+    items.iter_mut().find(|item| item["id"] == id)
+}
+
+pub(crate) fn torrent_value(
+    id: u64,
+    hash: &str,
+    name: &str,
+    magnet: Option<String>,
+    files: &[(&str, u64)],
+    auth_id: &str,
+) -> Value {
+    let now = Utc::now().fixed_offset();
+    let status = TorrentStatus {
+        id,
+        hash: InfoHash::new(hash).expect("Mock torrents have a valid hash"),
+        created_at: Some(now),
+        updated_at: Some(now),
+        magnet,
+        size: files.iter().map(|(_, size)| *size as f64).sum(),
+        active: false,
+        auth_id: auth_id.to_string(),
+        download_state: TorrentDownloadState::Cached,
+        seeds: 0,
+        peers: 0,
+        ratio: 0.0,
+        progress: 1.0,
+        download_speed: 0.0,
+        upload_speed: 0.0,
+        name: name.to_string(),
+        eta: 0.0,
+        server: 1,
+        torrent_file: false,
+        expires_at: None,
+        download_present: true,
+        download_finished: true,
+        files: files
+            .iter()
+            .enumerate()
+            .map(|(i, (file, size))| TorrentFile {
+                id: Some(i as u64),
+                name: file.to_string(),
+                size: *size as f64,
+                hash: None,
+            })
+            .collect(),
+        inactive_check: None,
+        availability: 1.0,
+    };
+
+    serde_json::to_value(status).unwrap()
+}
+
+pub(crate) fn webdl_value(
+    id: u64,
+    hash: &str,
+    name: &str,
+    link: &str,
+    files: &[(&str, u64)],
+    auth_id: &str,
+) -> Value {
+    let now = Utc::now().fixed_offset();
+    let status = WebdownloadStatus {
+        id,
+        hash: InfoHash::new(hash).expect("Mock web downloads have a valid hash"),
+        created_at: now,
+        updated_at: now,
+        size: files.iter().map(|(_, size)| size).sum(),
+        active: false,
+        auth_id: auth_id.to_string(),
+        download_state: "cached".to_string(),
+        progress: 1.0,
+        download_speed: 0,
+        original_url: link.to_string(),
+        name: name.to_string(),
+        eta: 0,
+        server: 1,
+        expires_at: None,
+        download_present: true,
+        download_finished: true,
+        error: None,
+        cached: true,
+        cached_at: Some(now),
+        download_id: None,
+        files: files
+            .iter()
+            .enumerate()
+            .map(|(i, (file, size))| WebdownloadFile {
+                id: i as u64,
+                md5: content_md5(*size),
+                s3_path: format!("{hash}/{file}"),
+                name: format!("{name}/{file}"),
+                size: *size,
+                zipped: false,
+                infected: false,
+                opensubtitles_hash: None,
+                mimetype: "application/octet-stream".to_string(),
+                short_name: file.to_string(),
+                absolute_path: format!("/{hash}/{file}"),
+            })
+            .collect(),
+        alternative_hashes: Vec::new(),
+        tags: Vec::new(),
+    };
+
+    serde_json::to_value(status).unwrap()
+}
+
+pub(crate) fn usenet_value(
+    id: u64,
+    hash: &str,
+    name: &str,
+    link: Option<String>,
+    files: &[(&str, u64)],
+    auth_id: &str,
+) -> Value {
+    let now = Utc::now().fixed_offset();
+    let status = UsenetStatus {
+        id,
+        hash: InfoHash::new(hash).expect("Mock usenet downloads have a valid hash"),
+        created_at: Some(now),
+        updated_at: Some(now),
+        size: files.iter().map(|(_, size)| size).sum(),
+        active: false,
+        auth_id: auth_id.to_string(),
+        download_state: "cached".to_string(),
+        progress: 1.0,
+        download_speed: 0,
+        original_url: link,
+        name: name.to_string(),
+        eta: 0,
+        server: Some(1),
+        expires_at: None,
+        download_present: true,
+        download_finished: true,
+        cached: true,
+        cached_at: Some(now),
+        download_id: None,
+        inactive_check: None,
+        availability: Some(1.0),
+        files: files
+            .iter()
+            .enumerate()
+            .map(|(i, (file, size))| UsenetFile {
+                id: i as u64,
+                md5: content_md5(*size),
+                s3_path: format!("{hash}/{file}"),
+                name: format!("{name}/{file}"),
+                size: *size,
+                mimetype: "application/octet-stream".to_string(),
+                short_name: file.to_string(),
+                absolute_path: format!("/{hash}/{file}"),
+            })
+            .collect(),
+    };
+
+    serde_json::to_value(status).unwrap()
+}
+
+pub(crate) fn queued_value(
+    id: u64,
+    queued_type: QueuedType,
+    name: &str,
+    source: &str,
+    auth_id: &str,
+) -> Value {
+    let is_torrent = queued_type == QueuedType::Torrent;
+    let item = QueuedDownload {
+        id,
+        created_at: Utc::now().fixed_offset(),
+        auth_id: auth_id.to_string(),
+        name: Some(name.to_string()),
+        hash: None,
+        queued_type,
+        magnet: is_torrent.then(|| source.to_string()),
+        torrent_file: None,
+        link: (!is_torrent).then(|| source.to_string()),
+    };
+
+    serde_json::to_value(item).unwrap()
+}
@@ -39,7 +39,7 @@ impl<'a> NotificationApi<'a> {
         Endpoint::<GetRssNotifFeedEp>::new(self.client)
             .call_query_raw(
                 RssFeedQuery {
-                    token: self.client.token().to_string(),
+                    token: self.client.token()?,
                 },
                 CONTENT_XML,
             )
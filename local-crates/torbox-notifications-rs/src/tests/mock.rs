@@ -0,0 +1,56 @@
+use torbox_core_rs::error::ApiErrorCode;
+use torbox_mock::MockServer;
+
+use crate::NotificationApi;
+
+#[tokio::test]
+async fn test_feed_lists_and_clears_notifications() {
+    let server = MockServer::start().await;
+    let client = server.client().with_strict_errors(true);
+    let api = NotificationApi::new(&client);
+    let first = server
+        .state()
+        .add_notification("Download ready", "ubuntu.iso");
+    server
+        .state()
+        .add_notification("Download ready", "debian.iso");
+
+    let feed = api.get_feed().await.unwrap().into_data().unwrap();
+    assert_eq!(feed.len(), 2);
+
+    api.clear(first).await.unwrap();
+    let feed = api.get_feed().await.unwrap().into_data().unwrap();
+    assert_eq!(feed.len(), 1);
+    assert_eq!(feed[0].message, "debian.iso");
+
+    let err = api.clear(first).await.unwrap_err();
+    assert_eq!(err.code(), Some(&ApiErrorCode::ItemNotFound));
+
+    api.clear_all().await.unwrap();
+    assert!(
+        api.get_feed()
+            .await
+            .unwrap()
+            .into_data()
+            .unwrap()
+            .is_empty()
+    );
+}
+
+#[tokio::test]
+async fn test_rss_feed_sends_token_and_escapes_items() {
+    let server = MockServer::start().await;
+    let client = server.client().with_strict_errors(true);
+    let api = NotificationApi::new(&client);
+    api.send_test().await.unwrap();
+    server.state().add_notification("Tom & Jerry", "<ready>");
+
+    let feed = api.get_rss_feed().await.unwrap();
+    assert!(feed.contains("<title>Test notification</title>"));
+    assert!(feed.contains("<title>Tom &amp; Jerry</title>"));
+    assert!(feed.contains("<description>&lt;ready&gt;</description>"));
+
+    let request = server.state().requests().pop().unwrap();
+    assert_eq!(request.path, "api/notifications/rss");
+    assert_eq!(request.query, Some(format!("token={}", server.token())));
+}
@@ -1,12 +1,13 @@
+#[cfg(test)]
+mod mock;
+
 #[cfg(test)]
 pub mod notification_test {
     use std::env;
 
     use crate::NotificationApi;
     use dotenvy::from_filename;
-    use torbox_core_rs::{
-        client::TorboxClient, data::notifications::NotificationFeed, error::ApiError,
-    };
+    use torbox_core_rs::client::TorboxClient;
 
     pub fn load_token_from_file() -> Option<String> {
         if let Err(err) = from_filename(".token") {
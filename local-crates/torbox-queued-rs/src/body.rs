@@ -0,0 +1,18 @@
+use serde::Serialize;
+use torbox_core_rs::data::queued::QueuedType;
+
+use crate::types::{QueuedControlSource, QueuedOperation};
+
+/// Request body of [`control_queued`](crate::QueuedApi::control_queued).
+#[derive(Debug, Serialize)]
+#[cfg_attr(feature = "specta", derive(specta::Type))]
+pub struct QueuedControlBody {
+    // Queued ID or All
+    #[serde(flatten)]
+    pub source: QueuedControlSource,
+    // the operation you want to perform on the queued item
+    pub operation: QueuedOperation,
+    /// Restricts `all` to the items that will become this kind of download. Optional.
+    #[serde(rename = "type", skip_serializing_if = "Option::is_none")]
+    pub queued_type: Option<QueuedType>,
+}
@@ -0,0 +1,22 @@
+use reqwest::Method;
+use torbox_core_rs::{client::EndpointSpec, data::queued::QueuedDownload, enums::OneOrMany};
+
+use crate::{body::QueuedControlBody, query::QueuedListQuery};
+
+pub struct QueuedListGetEp;
+
+impl EndpointSpec for QueuedListGetEp {
+    type Req = QueuedListQuery;
+    type Resp = Option<OneOrMany<QueuedDownload>>;
+    const PATH: &'static str = "api/queued/getqueued";
+    const METHOD: Method = Method::GET;
+}
+
+pub struct QueuedControlPostEp;
+
+impl EndpointSpec for QueuedControlPostEp {
+    type Req = QueuedControlBody;
+    type Resp = ();
+    const PATH: &'static str = "api/queued/controlqueued";
+    const METHOD: Method = Method::POST;
+}
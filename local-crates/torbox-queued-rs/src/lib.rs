@@ -0,0 +1,212 @@
+pub mod body;
+pub mod endpoint;
+pub mod query;
+pub mod tests;
+pub mod types;
+
+use futures::{Stream, TryStreamExt};
+use torbox_core_rs::{
+    api::ApiResponse,
+    client::{Endpoint, TorboxClient},
+    data::{
+        limits::ActiveLimitStatus,
+        queued::{QueuedDownload, QueuedType},
+    },
+    enums::OneOrMany,
+    error::ApiError,
+    pagination::{Pagination, paginate},
+};
+
+use crate::{
+    body::QueuedControlBody,
+    endpoint::{QueuedControlPostEp, QueuedListGetEp},
+    query::QueuedListQuery,
+    types::{QueuedControlSource, QueuedOperation, StartNextReport},
+};
+
+/// Main interface for the TorBox download queue
+///
+/// Provides methods for all queue-related API calls including:
+/// - Listing the torrents, web downloads and usenet downloads waiting for an active slot
+/// - Starting or deleting them, one by one or all at once
+/// - Starting as many as the active limit allows, see [`start_next`](Self::start_next)
+///
+/// Downloads are queued by creating them with `as_queued`, or by TorBox itself when the active limit is reached.
+#[cfg_attr(feature = "specta", derive(specta::Type))]
+pub struct QueuedApi<'a> {
+    client: &'a TorboxClient,
+}
+
+impl<'a> QueuedApi<'a> {
+    pub fn new(client: &'a TorboxClient) -> Self {
+        Self { client }
+    }
+
+    /// Gets the queued items, of every kind unless `query.queued_type` is given.
+    pub async fn list_queued(
+        &self,
+        query: QueuedListQuery,
+    ) -> Result<ApiResponse<Option<Vec<QueuedDownload>>>, ApiError> {
+        let resp = Endpoint::<QueuedListGetEp>::new(self.client)
+            .call_query(query)
+            .await?;
+
+        let normalized = resp.map(|opt| {
+            opt.map(|one_or_many| match one_or_many {
+                OneOrMany::One(item) => vec![item],
+                OneOrMany::Many(list) => list,
+            })
+        });
+
+        Ok(normalized)
+    }
+
+    /// Streams every queued item, fetching the pages as they are consumed.
+    pub fn list_all(
+        &self,
+        queued_type: Option<QueuedType>,
+        pagination: Pagination,
+    ) -> impl Stream<Item = Result<QueuedDownload, ApiError>> + use<'a> {
+        let client = self.client;
+
+        paginate(pagination, move |offset, limit| async move {
+            let query = QueuedListQuery {
+                bypass_cache: Some(pagination.bypass_cache),
+                id: None,
+                offset: Some(offset),
+                limit: Some(limit),
+                queued_type,
+            };
+            let page = QueuedApi::new(client)
+                .list_queued(query)
+                .await?
+                .into_data()?;
+            Ok(page.unwrap_or_default())
+        })
+    }
+
+    /// Gets a single queued item.
+    pub async fn get_queued(&self, id: u64) -> Result<ApiResponse<QueuedDownload>, ApiError> {
+        let resp = Endpoint::<QueuedListGetEp>::new(self.client)
+            .call_query(QueuedListQuery {
+                id: Some(id),
+                ..Default::default()
+            })
+            .await?;
+
+        let item = resp
+            .data
+            .flatten()
+            .and_then(|one_or_many| match one_or_many {
+                OneOrMany::One(item) => Some(item),
+                OneOrMany::Many(list) => list.into_iter().next(),
+            });
+
+        Ok(ApiResponse {
+            success: resp.success,
+            error: resp.error,
+            detail: resp.detail,
+            data: item,
+        })
+    }
+
+    /// Controls a queued item (or all of them), see [`QueuedOperation`].
+    pub async fn control_queued(
+        &self,
+        body: QueuedControlBody,
+    ) -> Result<ApiResponse<()>, ApiError> {
+        Endpoint::<QueuedControlPostEp>::new(self.client)
+            .call_json(body)
+            .await
+    }
+
+    /// Creates the download of a queued item right away.
+    ///
+    /// Fails with `ACTIVE_LIMIT` if every active slot is used.
+    pub async fn start_queued(&self, id: u64) -> Result<ApiResponse<()>, ApiError> {
+        self.control_one(id, QueuedOperation::Start).await
+    }
+
+    /// Removes an item from the queue without downloading it.
+    pub async fn delete_queued(&self, id: u64) -> Result<ApiResponse<()>, ApiError> {
+        self.control_one(id, QueuedOperation::Delete).await
+    }
+
+    /// Starts every queued item, or only the ones of `queued_type`.
+    pub async fn start_all(
+        &self,
+        queued_type: Option<QueuedType>,
+    ) -> Result<ApiResponse<()>, ApiError> {
+        self.control_all(QueuedOperation::Start, queued_type).await
+    }
+
+    /// Empties the queue, or only removes the items of `queued_type`.
+    pub async fn delete_all(
+        &self,
+        queued_type: Option<QueuedType>,
+    ) -> Result<ApiResponse<()>, ApiError> {
+        self.control_all(QueuedOperation::Delete, queued_type).await
+    }
+
+    /// Starts the oldest queued items, as many as `limit` has free slots for.
+    ///
+    /// `limit` usually comes from the `ACTIVE_LIMIT` error of a creation request, see
+    /// [`ActiveLimitStatus`]. Nothing is started when no slot is free.
+    ///
+    /// Stops at the first item that fails to start, the report then keeps the ones started before it.
+    pub async fn start_next(&self, limit: &ActiveLimitStatus) -> Result<StartNextReport, ApiError> {
+        let mut report = StartNextReport {
+            started: Vec::new(),
+            failed: None,
+        };
+        let slots = limit.available_slots() as usize;
+        if slots == 0 {
+            return Ok(report);
+        }
+
+        let mut queue: Vec<QueuedDownload> = self
+            .list_all(None, Pagination::default())
+            .try_collect()
+            .await?;
+        queue.sort_by_key(|item| (item.created_at, item.id));
+        queue.truncate(slots);
+
+        for item in queue {
+            match self.start_queued(item.id).await.and_then(|r| r.check()) {
+                Ok(_) => report.started.push(item),
+                Err(e) => {
+                    report.failed = Some((item, e));
+                    break;
+                }
+            }
+        }
+
+        Ok(report)
+    }
+
+    async fn control_one(
+        &self,
+        id: u64,
+        operation: QueuedOperation,
+    ) -> Result<ApiResponse<()>, ApiError> {
+        self.control_queued(QueuedControlBody {
+            source: QueuedControlSource::QueuedId(id),
+            operation,
+            queued_type: None,
+        })
+        .await
+    }
+
+    async fn control_all(
+        &self,
+        operation: QueuedOperation,
+        queued_type: Option<QueuedType>,
+    ) -> Result<ApiResponse<()>, ApiError> {
+        self.control_queued(QueuedControlBody {
+            source: QueuedControlSource::All(true),
+            operation,
+            queued_type,
+        })
+        .await
+    }
+}
@@ -0,0 +1,18 @@
+use serde::Serialize;
+use torbox_core_rs::data::queued::QueuedType;
+
+#[derive(Debug, Serialize, Default)]
+#[cfg_attr(feature = "specta", derive(specta::Type))]
+pub struct QueuedListQuery {
+    /// Allows you to bypass the cached data, and always get fresh information. Optional.
+    pub bypass_cache: Option<bool>,
+    /// Determines the queued item requested, will return an object rather than list. Optional.
+    pub id: Option<u64>,
+    /// Determines the offset of items to get from the database. Optional. Default is 0.
+    pub offset: Option<u32>,
+    /// Determines the number of items to receive per request. Optional. Default is 1000.
+    pub limit: Option<u32>,
+    /// Only returns the items that will become this kind of download. Optional.
+    #[serde(rename = "type")]
+    pub queued_type: Option<QueuedType>,
+}
@@ -0,0 +1,207 @@
+use futures::TryStreamExt;
+use torbox_core_rs::{
+    data::{limits::ActiveLimitStatus, queued::QueuedType},
+    error::ApiErrorCode,
+    pagination::Pagination,
+};
+use torbox_mock::{Injection, MockServer};
+use torbox_torrent_rs::{
+    TorrentApi, body::TorrentCreateBody, magnet::MagnetLink, payload::TorrentCreatePayload,
+};
+
+use crate::{QueuedApi, query::QueuedListQuery};
+
+const HASH: &str = "a492f8b92a25b0399c87715fc228c864ac5a7bfb";
+
+#[tokio::test]
+async fn test_torrent_created_as_queued() {
+    let server = MockServer::start().await;
+    let client = server.client().with_strict_errors(true);
+
+    let magnet = MagnetLink::from_info_hash(HASH)
+        .unwrap()
+        .with_name("archlinux");
+    let body = TorrentCreateBody {
+        as_queued: Some(true),
+        ..TorrentCreateBody::from_magnet(magnet)
+    };
+    let payload = TorrentApi::new(&client)
+        .create_torrent(body)
+        .await
+        .unwrap()
+        .into_data()
+        .unwrap();
+    let TorrentCreatePayload::Created(created) = payload else {
+        panic!("Unexpected payload {payload:?}");
+    };
+
+    let api = QueuedApi::new(&client);
+    let item = api
+        .get_queued(created.id)
+        .await
+        .unwrap()
+        .into_data()
+        .unwrap();
+    assert_eq!(item.queued_type, QueuedType::Torrent);
+    assert_eq!(item.name.as_deref(), Some("archlinux"));
+    assert_eq!(item.hash.as_deref(), Some(HASH));
+    assert!(item.source().unwrap().starts_with("magnet:?"));
+}
+
+#[tokio::test]
+async fn test_list_queued_by_type() {
+    let server = MockServer::start().await;
+    let client = server.client().with_strict_errors(true);
+    let api = QueuedApi::new(&client);
+
+    server
+        .state()
+        .add_queued(QueuedType::Webdl, "file", "https://example.com/file");
+    server
+        .state()
+        .add_queued(QueuedType::Usenet, "show", "https://example.com/show.nzb");
+    server
+        .state()
+        .add_queued(QueuedType::Webdl, "other", "https://example.com/other");
+
+    let all: Vec<_> = api
+        .list_all(None, Pagination::default().with_page_size(2))
+        .try_collect()
+        .await
+        .unwrap();
+    assert_eq!(all.len(), 3);
+
+    let webdls = api
+        .list_queued(QueuedListQuery {
+            queued_type: Some(QueuedType::Webdl),
+            ..Default::default()
+        })
+        .await
+        .unwrap()
+        .into_data()
+        .unwrap()
+        .unwrap();
+    assert_eq!(webdls.len(), 2);
+    assert!(webdls.iter().all(|item| item.link.is_some()));
+}
+
+#[tokio::test]
+async fn test_start_and_delete_queued() {
+    let server = MockServer::start().await;
+    let client = server.client().with_strict_errors(true);
+    let api = QueuedApi::new(&client);
+
+    let started = server
+        .state()
+        .add_queued(QueuedType::Webdl, "file", "https://example.com/file");
+    let deleted =
+        server
+            .state()
+            .add_queued(QueuedType::Usenet, "show", "https://example.com/show.nzb");
+
+    api.start_queued(started).await.unwrap();
+    api.delete_queued(deleted).await.unwrap();
+
+    assert!(server.state().queued(started).is_none());
+    assert!(server.state().queued(deleted).is_none());
+    assert_eq!(server.state().request_count("api/queued/controlqueued"), 2);
+
+    let err = api.start_queued(started).await.unwrap_err();
+    assert_eq!(err.code(), Some(&ApiErrorCode::ItemNotFound));
+}
+
+#[tokio::test]
+async fn test_delete_all_of_type() {
+    let server = MockServer::start().await;
+    let client = server.client().with_strict_errors(true);
+    let api = QueuedApi::new(&client);
+
+    let webdl = server
+        .state()
+        .add_queued(QueuedType::Webdl, "file", "https://example.com/file");
+    let usenet =
+        server
+            .state()
+            .add_queued(QueuedType::Usenet, "show", "https://example.com/show.nzb");
+
+    api.delete_all(Some(QueuedType::Usenet)).await.unwrap();
+
+    assert!(server.state().queued(webdl).is_some());
+    assert!(server.state().queued(usenet).is_none());
+}
+
+#[tokio::test]
+async fn test_start_next_fills_free_slots() {
+    let server = MockServer::start().await;
+    let client = server.client().with_strict_errors(true);
+    let api = QueuedApi::new(&client);
+
+    let ids: Vec<u64> = (0..3)
+        .map(|i| {
+            server.state().add_queued(
+                QueuedType::Webdl,
+                &format!("file{i}"),
+                &format!("https://example.com/file{i}"),
+            )
+        })
+        .collect();
+
+    let full = ActiveLimitStatus {
+        active_limit: 3,
+        current_active_downloads: 3,
+    };
+    let report = api.start_next(&full).await.unwrap();
+    assert!(report.started.is_empty() && report.failed.is_none());
+
+    let limit = ActiveLimitStatus {
+        active_limit: 3,
+        current_active_downloads: 1,
+    };
+    let started: Vec<u64> = api
+        .start_next(&limit)
+        .await
+        .unwrap()
+        .started
+        .iter()
+        .map(|item| item.id)
+        .collect();
+    assert_eq!(started, ids[..2]);
+    assert!(server.state().queued(ids[2]).is_some());
+}
+
+#[tokio::test]
+async fn test_start_next_keeps_started_items_on_failure() {
+    let server = MockServer::start().await;
+    let client = server.client().with_strict_errors(true);
+    let api = QueuedApi::new(&client);
+
+    let ids: Vec<u64> = (0..3)
+        .map(|i| {
+            server.state().add_queued(
+                QueuedType::Webdl,
+                &format!("file{i}"),
+                &format!("https://example.com/file{i}"),
+            )
+        })
+        .collect();
+    server
+        .state()
+        .inject("api/queued/controlqueued", Injection::Pass);
+    server
+        .state()
+        .inject_error("api/queued/controlqueued", ApiErrorCode::ActiveLimit);
+
+    let limit = ActiveLimitStatus {
+        active_limit: 3,
+        current_active_downloads: 0,
+    };
+    let report = api.start_next(&limit).await.unwrap();
+
+    let started: Vec<u64> = report.started.iter().map(|item| item.id).collect();
+    assert_eq!(started, ids[..1]);
+    let (failed, err) = report.failed.unwrap();
+    assert_eq!(failed.id, ids[1]);
+    assert_eq!(err.code(), Some(&ApiErrorCode::ActiveLimit));
+    assert!(server.state().queued(ids[2]).is_some());
+    assert_eq!(server.state().request_count("api/queued/controlqueued"), 2);
+}
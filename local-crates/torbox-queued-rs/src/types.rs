@@ -0,0 +1,31 @@
+use serde::{Deserialize, Serialize};
+use torbox_core_rs::{data::queued::QueuedDownload, error::ApiError};
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+#[cfg_attr(feature = "specta", derive(specta::Type))]
+pub enum QueuedOperation {
+    /// Creates the download right away, using an active slot
+    Start,
+    /// Removes the item from the queue without downloading it
+    Delete,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+#[cfg_attr(feature = "specta", derive(specta::Type))]
+pub enum QueuedControlSource {
+    /// the queued item's id, optional if using "all" parameter
+    QueuedId(u64),
+    /// if you want to do this operation to all queued items in your account, optional if using the "queued_id" parameter
+    All(bool),
+}
+
+/// Outcome of [`start_next`](crate::QueuedApi::start_next).
+#[derive(Debug)]
+pub struct StartNextReport {
+    /// The items that were started, oldest first.
+    pub started: Vec<QueuedDownload>,
+    /// The item whose start failed with its error, the items after it were not tried.
+    pub failed: Option<(QueuedDownload, ApiError)>,
+}
@@ -0,0 +1,148 @@
+use fancy_regex::Regex;
+use serde::Serialize;
+use torbox_core_rs::{error::ApiError, validation::validate_url};
+
+use crate::types::{RssControlSource, RssOperation, RssType};
+
+/// Request body of [`add_feed`](crate::RssApi::add_feed).
+#[derive(Debug, Serialize, Clone)]
+#[cfg_attr(feature = "specta", derive(specta::Type))]
+pub struct RssAddBody {
+    /// The URL of the RSS feed.
+    pub url: String,
+    /// The name you want the feed to be.
+    pub name: String,
+    /// Items whose title matches this regex are downloaded. Use `.*` to download everything.
+    pub do_regex: String,
+    /// Items whose title matches this regex are skipped, even if they match `do_regex`. Optional.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dont_regex: Option<String>,
+    /// Items older than this many days are skipped. Optional, default is 0 which doesn't filter.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dont_older_than: Option<u32>,
+    /// Skip items the user already downloaded. Optional.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pass_check: Option<bool>,
+    /// Minutes between two scans of the feed. Optional, at least 10.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub scan_interval: Option<u32>,
+    pub rss_type: RssType,
+    /// Tells TorBox your preference for seeding the torrents of this feed.
+    /// 1 is auto. 2 is seed. 3 is don't seed.
+    ///
+    /// Optional. Only used for `torrent` feeds.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub torrent_seeding: Option<u8>,
+}
+
+impl RssAddBody {
+    /// A torrent feed downloading every item of `url`.
+    pub fn new(url: impl Into<String>, name: impl Into<String>) -> Self {
+        Self {
+            url: url.into(),
+            name: name.into(),
+            do_regex: ".*".into(),
+            dont_regex: None,
+            dont_older_than: None,
+            pass_check: None,
+            scan_interval: None,
+            rss_type: RssType::Torrent,
+            torrent_seeding: None,
+        }
+    }
+
+    /// Checks the URL, regexes and scan interval before the request is sent.
+    pub fn validate(&self) -> Result<(), ApiError> {
+        validate_url("url", &self.url)?;
+        validate_filters(
+            Some(&self.do_regex),
+            self.dont_regex.as_deref(),
+            self.scan_interval,
+        )
+    }
+}
+
+/// Request body of [`modify_feed`](crate::RssApi::modify_feed).
+///
+/// Fields left to `None` are not sent and keep their previous value.
+#[derive(Debug, Serialize, Default, Clone)]
+#[cfg_attr(feature = "specta", derive(specta::Type))]
+pub struct RssModifyBody {
+    /// The feed's ID that you want to modify.
+    pub rss_feed_id: u64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub do_regex: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dont_regex: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dont_older_than: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pass_check: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub scan_interval: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rss_type: Option<RssType>,
+    /// 1 is auto. 2 is seed. 3 is don't seed.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub torrent_seeding: Option<u8>,
+}
+
+impl RssModifyBody {
+    /// Checks the regexes and scan interval before the request is sent.
+    pub fn validate(&self) -> Result<(), ApiError> {
+        validate_filters(
+            self.do_regex.as_deref(),
+            self.dont_regex.as_deref(),
+            self.scan_interval,
+        )
+    }
+}
+
+#[derive(Debug, Serialize)]
+#[cfg_attr(feature = "specta", derive(specta::Type))]
+pub struct RssControlBody {
+    // Feed ID or All
+    #[serde(flatten)]
+    pub source: RssControlSource,
+    // the operation you want to perform on the feed
+    pub operation: RssOperation,
+}
+
+/// Shortest scan interval TorBox accepts, in minutes.
+pub const MIN_SCAN_INTERVAL: u32 = 10;
+
+/// Checks that `pattern` compiles, so TorBox does not answer with `BOZO_REGEX`.
+///
+/// Patterns are compiled with `fancy-regex`, so look-arounds and backreferences are accepted
+/// like they are by TorBox.
+pub fn validate_regex(field: &str, pattern: &str) -> Result<(), ApiError> {
+    if pattern.is_empty() {
+        return Err(ApiError::InvalidInput(format!("{field} cannot be empty")));
+    }
+
+    Regex::new(pattern)
+        .map(|_| ())
+        .map_err(|e| ApiError::InvalidInput(format!("{field} is not a valid regex: {e}")))
+}
+
+fn validate_filters(
+    do_regex: Option<&str>,
+    dont_regex: Option<&str>,
+    scan_interval: Option<u32>,
+) -> Result<(), ApiError> {
+    if let Some(pattern) = do_regex {
+        validate_regex("do_regex", pattern)?;
+    }
+    if let Some(pattern) = dont_regex {
+        validate_regex("dont_regex", pattern)?;
+    }
+
+    match scan_interval {
+        Some(minutes) if minutes < MIN_SCAN_INTERVAL => Err(ApiError::InvalidInput(format!(
+            "scan_interval must be at least {MIN_SCAN_INTERVAL} minutes, got {minutes}"
+        ))),
+        _ => Ok(()),
+    }
+}
@@ -0,0 +1,56 @@
+use reqwest::Method;
+use torbox_core_rs::{
+    client::EndpointSpec,
+    data::rss::{RssFeed, RssFeedItem},
+    enums::OneOrMany,
+};
+
+use crate::{
+    body::{RssAddBody, RssControlBody, RssModifyBody},
+    query::{RssFeedItemsQuery, RssFeedsQuery},
+};
+
+pub struct RssAddPostEp;
+
+impl EndpointSpec for RssAddPostEp {
+    type Req = RssAddBody;
+    type Resp = ();
+    const PATH: &'static str = "api/rss/addrss";
+    const METHOD: Method = Method::POST;
+}
+
+pub struct RssControlPostEp;
+
+impl EndpointSpec for RssControlPostEp {
+    type Req = RssControlBody;
+    type Resp = ();
+    const PATH: &'static str = "api/rss/controlrss";
+    const METHOD: Method = Method::POST;
+}
+
+pub struct RssModifyPostEp;
+
+impl EndpointSpec for RssModifyPostEp {
+    type Req = RssModifyBody;
+    type Resp = ();
+    const PATH: &'static str = "api/rss/modifyrss";
+    const METHOD: Method = Method::POST;
+}
+
+pub struct RssFeedsGetEp;
+
+impl EndpointSpec for RssFeedsGetEp {
+    type Req = RssFeedsQuery;
+    type Resp = Option<OneOrMany<RssFeed>>;
+    const PATH: &'static str = "api/rss/getfeeds";
+    const METHOD: Method = Method::GET;
+}
+
+pub struct RssFeedItemsGetEp;
+
+impl EndpointSpec for RssFeedItemsGetEp {
+    type Req = RssFeedItemsQuery;
+    type Resp = Vec<RssFeedItem>;
+    const PATH: &'static str = "api/rss/getfeeditems";
+    const METHOD: Method = Method::GET;
+}
@@ -0,0 +1,139 @@
+pub mod body;
+pub mod endpoint;
+pub mod query;
+pub mod tests;
+pub mod types;
+
+use torbox_core_rs::{
+    api::ApiResponse,
+    client::{Endpoint, TorboxClient},
+    data::rss::{RssFeed, RssFeedItem},
+    enums::OneOrMany,
+    error::ApiError,
+};
+
+use crate::{
+    body::{RssAddBody, RssControlBody, RssModifyBody},
+    endpoint::{RssAddPostEp, RssControlPostEp, RssFeedItemsGetEp, RssFeedsGetEp, RssModifyPostEp},
+    query::{RssFeedItemsQuery, RssFeedsQuery},
+    types::{RssControlSource, RssOperation},
+};
+
+/// Main interface for TorBox RSS automation
+///
+/// Provides methods for all RSS-related API calls including:
+/// - Adding feeds with their regex filters, scan interval and seeding preference
+/// - Listing feeds and the items found in them
+/// - Modifying, pausing, resuming and deleting feeds
+///
+/// Regexes are checked locally before any request, see [`validate_regex`](body::validate_regex).
+#[cfg_attr(feature = "specta", derive(specta::Type))]
+pub struct RssApi<'a> {
+    client: &'a TorboxClient,
+}
+
+impl<'a> RssApi<'a> {
+    pub fn new(client: &'a TorboxClient) -> Self {
+        Self { client }
+    }
+
+    /// Adds a feed TorBox scans every `scan_interval` minutes, downloading the items matching its filters.
+    ///
+    /// An invalid URL, regex or scan interval returns `ApiError::InvalidInput` without sending the request.
+    pub async fn add_feed(&self, body: RssAddBody) -> Result<ApiResponse<()>, ApiError> {
+        body.validate()?;
+
+        Endpoint::<RssAddPostEp>::new(self.client)
+            .call_json(body)
+            .await
+    }
+
+    /// Gets every feed of the user.
+    pub async fn list_feeds(&self) -> Result<ApiResponse<Option<Vec<RssFeed>>>, ApiError> {
+        let resp = Endpoint::<RssFeedsGetEp>::new(self.client)
+            .call_query(RssFeedsQuery::default())
+            .await?;
+
+        let normalized = resp.map(|opt| {
+            opt.map(|one_or_many| match one_or_many {
+                OneOrMany::One(item) => vec![item],
+                OneOrMany::Many(list) => list,
+            })
+        });
+
+        Ok(normalized)
+    }
+
+    /// Gets a single feed.
+    pub async fn get_feed(&self, id: u64) -> Result<ApiResponse<RssFeed>, ApiError> {
+        let resp = Endpoint::<RssFeedsGetEp>::new(self.client)
+            .call_query(RssFeedsQuery { id: Some(id) })
+            .await?;
+
+        let feed = resp
+            .data
+            .flatten()
+            .and_then(|one_or_many| match one_or_many {
+                OneOrMany::One(feed) => Some(feed),
+                OneOrMany::Many(list) => list.into_iter().next(),
+            });
+
+        Ok(ApiResponse {
+            success: resp.success,
+            error: resp.error,
+            detail: resp.detail,
+            data: feed,
+        })
+    }
+
+    /// Gets the items found in a feed so far.
+    pub async fn feed_items(&self, id: u64) -> Result<ApiResponse<Vec<RssFeedItem>>, ApiError> {
+        Endpoint::<RssFeedItemsGetEp>::new(self.client)
+            .call_query(RssFeedItemsQuery { rss_feed_id: id })
+            .await
+    }
+
+    /// Changes the name, filters, scan interval or seeding preference of a feed.
+    ///
+    /// An invalid regex or scan interval returns `ApiError::InvalidInput` without sending the request.
+    pub async fn modify_feed(&self, body: RssModifyBody) -> Result<ApiResponse<()>, ApiError> {
+        body.validate()?;
+
+        Endpoint::<RssModifyPostEp>::new(self.client)
+            .call_json(body)
+            .await
+    }
+
+    /// Controls a feed (or all of them), see [`RssOperation`].
+    pub async fn control_feed(&self, body: RssControlBody) -> Result<ApiResponse<()>, ApiError> {
+        Endpoint::<RssControlPostEp>::new(self.client)
+            .call_json(body)
+            .await
+    }
+
+    /// Stops scanning a feed until it is resumed.
+    pub async fn pause_feed(&self, id: u64) -> Result<ApiResponse<()>, ApiError> {
+        self.control_one(id, RssOperation::Pause).await
+    }
+
+    pub async fn resume_feed(&self, id: u64) -> Result<ApiResponse<()>, ApiError> {
+        self.control_one(id, RssOperation::Resume).await
+    }
+
+    /// Deletes a feed permanently, the downloads it created are kept.
+    pub async fn delete_feed(&self, id: u64) -> Result<ApiResponse<()>, ApiError> {
+        self.control_one(id, RssOperation::Delete).await
+    }
+
+    async fn control_one(
+        &self,
+        id: u64,
+        operation: RssOperation,
+    ) -> Result<ApiResponse<()>, ApiError> {
+        self.control_feed(RssControlBody {
+            source: RssControlSource::RssFeedId(id),
+            operation,
+        })
+        .await
+    }
+}
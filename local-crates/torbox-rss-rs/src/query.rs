@@ -0,0 +1,15 @@
+use serde::Serialize;
+
+#[derive(Debug, Serialize, Default)]
+#[cfg_attr(feature = "specta", derive(specta::Type))]
+pub struct RssFeedsQuery {
+    /// Determines the feed requested, will return an object rather than list. Optional.
+    pub id: Option<u64>,
+}
+
+#[derive(Debug, Serialize)]
+#[cfg_attr(feature = "specta", derive(specta::Type))]
+pub struct RssFeedItemsQuery {
+    /// The feed whose items you want to get.
+    pub rss_feed_id: u64,
+}
@@ -0,0 +1,55 @@
+use torbox_core_rs::error::ApiError;
+
+use crate::{
+    body::{RssAddBody, RssModifyBody, validate_regex},
+    types::RssType,
+};
+
+#[test]
+fn test_validate_regex() {
+    assert!(validate_regex("do_regex", r"(?i)^show\.s\d{2}e\d{2}.*1080p").is_ok());
+    assert!(validate_regex("dont_regex", r"^(?!.*1080p).*$").is_ok());
+    assert!(validate_regex("do_regex", r"(\w+)\.\1").is_ok());
+
+    for pattern in ["", "(unclosed", "[a-", "*start"] {
+        let err = validate_regex("do_regex", pattern).unwrap_err();
+        assert!(matches!(err, ApiError::InvalidInput(_)), "{pattern:?}");
+    }
+}
+
+#[test]
+fn test_add_body_validation() {
+    let body = RssAddBody::new("https://example.com/feed.xml", "Shows");
+    assert!(body.validate().is_ok());
+
+    let bad_url = RssAddBody::new("ftp://example.com/feed.xml", "Shows");
+    assert!(bad_url.validate().is_err());
+
+    let bad_regex = RssAddBody {
+        dont_regex: Some("(cam|ts".into()),
+        ..body.clone()
+    };
+    assert!(bad_regex.validate().is_err());
+
+    let too_frequent = RssAddBody {
+        scan_interval: Some(1),
+        ..body
+    };
+    assert!(too_frequent.validate().is_err());
+}
+
+#[test]
+fn test_modify_body_only_sends_given_fields() {
+    let body = RssModifyBody {
+        rss_feed_id: 3,
+        do_regex: Some("1080p".into()),
+        rss_type: Some(RssType::Usenet),
+        ..Default::default()
+    };
+
+    assert!(body.validate().is_ok());
+    assert_eq!(
+        serde_json::to_value(&body).unwrap(),
+        serde_json::json!({"rss_feed_id": 3, "do_regex": "1080p", "rss_type": "usenet"})
+    );
+}
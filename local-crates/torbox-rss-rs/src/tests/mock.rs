@@ -0,0 +1,111 @@
+use torbox_core_rs::error::{ApiError, ApiErrorCode};
+use torbox_mock::MockServer;
+
+use crate::{
+    RssApi,
+    body::{RssAddBody, RssModifyBody},
+    types::RssType,
+};
+
+#[tokio::test]
+async fn test_add_then_list_feed() {
+    let server = MockServer::start().await;
+    let client = server.client().with_strict_errors(true);
+    let api = RssApi::new(&client);
+
+    api.add_feed(RssAddBody {
+        do_regex: r"(?i)1080p".into(),
+        scan_interval: Some(30),
+        rss_type: RssType::Usenet,
+        ..RssAddBody::new("https://example.com/feed.xml", "Shows")
+    })
+    .await
+    .unwrap();
+
+    let feeds = api
+        .list_feeds()
+        .await
+        .unwrap()
+        .into_data()
+        .unwrap()
+        .unwrap();
+    assert_eq!(feeds.len(), 1);
+    assert_eq!(feeds[0].name, "Shows");
+    assert_eq!(feeds[0].rss_type, RssType::Usenet);
+    assert_eq!(feeds[0].scan_interval, 30);
+
+    let err = api
+        .add_feed(RssAddBody::new("https://example.com/feed.xml", "Again"))
+        .await
+        .unwrap_err();
+    assert_eq!(err.code(), Some(&ApiErrorCode::DuplicateItem));
+}
+
+#[tokio::test]
+async fn test_modify_pause_and_delete_feed() {
+    let server = MockServer::start().await;
+    let client = server.client().with_strict_errors(true);
+    let api = RssApi::new(&client);
+
+    api.add_feed(RssAddBody::new("https://example.com/feed.xml", "Shows"))
+        .await
+        .unwrap();
+    let id = api
+        .list_feeds()
+        .await
+        .unwrap()
+        .into_data()
+        .unwrap()
+        .unwrap()[0]
+        .id;
+    server.state().add_rss_item(id, "Show.S01E01.1080p");
+
+    api.modify_feed(RssModifyBody {
+        rss_feed_id: id,
+        name: Some("Renamed".into()),
+        dont_regex: Some("(?i)cam".into()),
+        ..Default::default()
+    })
+    .await
+    .unwrap();
+    api.pause_feed(id).await.unwrap();
+
+    let feed = api.get_feed(id).await.unwrap().into_data().unwrap();
+    assert_eq!(feed.name, "Renamed");
+    assert_eq!(feed.dont_regex.as_deref(), Some("(?i)cam"));
+    assert!(!feed.active);
+
+    let items = api.feed_items(id).await.unwrap().into_data().unwrap();
+    assert_eq!(items[0].title, "Show.S01E01.1080p");
+
+    api.delete_feed(id).await.unwrap();
+    assert!(server.state().rss_feed(id).is_none());
+}
+
+#[tokio::test]
+async fn test_invalid_regex_is_rejected_before_request() {
+    let server = MockServer::start().await;
+    let client = server.client();
+    let api = RssApi::new(&client);
+
+    let err = api
+        .add_feed(RssAddBody {
+            do_regex: "(1080p|720p".into(),
+            ..RssAddBody::new("https://example.com/feed.xml", "Shows")
+        })
+        .await
+        .unwrap_err();
+    assert!(matches!(err, ApiError::InvalidInput(_)));
+
+    let err = api
+        .modify_feed(RssModifyBody {
+            rss_feed_id: 1,
+            do_regex: Some("[".into()),
+            ..Default::default()
+        })
+        .await
+        .unwrap_err();
+    assert!(matches!(err, ApiError::InvalidInput(_)));
+
+    assert!(server.state().requests().is_empty());
+}
@@ -0,0 +1,44 @@
+#[cfg(test)]
+mod body;
+#[cfg(test)]
+mod mock;
+
+#[cfg(test)]
+pub mod rss_test {
+    use std::env;
+
+    use crate::RssApi;
+
+    use dotenvy::from_filename;
+    use torbox_core_rs::client::TorboxClient;
+
+    pub fn load_token_from_file() -> Option<String> {
+        if let Err(err) = from_filename(".token") {
+            eprintln!(
+                "Could not load .token file, please create one before starting unit testing: {err}"
+            );
+            return None;
+        }
+        env::var("TORBOX_TOKEN").ok()
+    }
+
+    fn test_client() -> TorboxClient {
+        let token = load_token_from_file().expect("Missing TORBOX_TOKEN in .token file");
+        TorboxClient::new(token)
+    }
+
+    #[tokio::test]
+    async fn test_list_feeds_success() {
+        let client = test_client();
+        let api = RssApi::new(&client);
+
+        let result = api.list_feeds().await;
+        match result {
+            Ok(response) => {
+                println!("RSS Feeds: {:?}", response);
+                assert!(response.success, "API responded with success=false");
+            }
+            Err(e) => panic!("API call failed: {e:?}"),
+        }
+    }
+}
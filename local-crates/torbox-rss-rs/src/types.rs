@@ -0,0 +1,27 @@
+use serde::{Deserialize, Serialize};
+
+pub use torbox_core_rs::data::rss::RssType;
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+#[cfg_attr(feature = "specta", derive(specta::Type))]
+pub enum RssOperation {
+    /// Scans the feed right away instead of waiting for the next interval
+    Update,
+    /// Deletes the feed from your account permanently
+    Delete,
+    /// Stops scanning the feed
+    Pause,
+    /// Resumes scanning a paused feed
+    Resume,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+#[cfg_attr(feature = "specta", derive(specta::Type))]
+pub enum RssControlSource {
+    /// the feed's id, optional if using "all" parameter
+    RssFeedId(u64),
+    /// if you want to do this operation to all feeds in your account, optional if using the "rss_feed_id" parameter
+    All(bool),
+}
@@ -0,0 +1,138 @@
+use percent_encoding::{AsciiSet, NON_ALPHANUMERIC, utf8_percent_encode};
+use reqwest::Method;
+use serde::de::DeserializeOwned;
+use torbox_core_rs::{api::ApiResponse, client::TorboxClient, error::ApiError};
+
+use crate::{
+    payload::{TorrentSearchPayload, UsenetSearchPayload},
+    query::SearchQuery,
+    types::SearchId,
+};
+
+pub mod payload;
+pub mod query;
+pub mod tests;
+pub mod types;
+
+/// Base URL of the TorBox Search API, which is separate from the main API.
+pub const SEARCH_API_URL: &str = "https://search-api.torbox.app";
+
+/// Main interface for the TorBox Search API
+///
+/// Provides methods to search torrents and usenet releases:
+/// - By text query
+/// - By IMDb, TMDb or TVDB id
+/// - Narrowed to a season or episode
+///
+/// Failures are reported with the `SEARCH_ERROR` code.
+#[cfg_attr(feature = "specta", derive(specta::Type))]
+pub struct SearchApi<'a> {
+    client: &'a TorboxClient,
+    base_url: String,
+}
+
+impl<'a> SearchApi<'a> {
+    pub fn new(client: &'a TorboxClient) -> Self {
+        Self {
+            client,
+            base_url: SEARCH_API_URL.to_string(),
+        }
+    }
+
+    /// Points the API at another Search API host, e.g. a mock server.
+    pub fn with_base_url(mut self, base_url: impl Into<String>) -> Self {
+        self.base_url = base_url.into();
+        self
+    }
+
+    /// Searches torrents by title, e.g. `"the matrix 1999"`.
+    ///
+    /// ```no_run
+    /// # use torbox_search_rs::{SearchApi, query::SearchQuery};
+    /// # async fn run(api: SearchApi<'_>) -> Result<(), torbox_core_rs::error::ApiError> {
+    /// let results = api
+    ///     .search_torrents("the expanse", SearchQuery::default().with_episode(1, 3).with_check_cache(true))
+    ///     .await?
+    ///     .into_data()?;
+    /// let best = results.torrents.iter().filter(|t| t.cached).max_by_key(|t| t.last_known_seeders);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn search_torrents(
+        &self,
+        query: &str,
+        options: SearchQuery,
+    ) -> Result<ApiResponse<TorrentSearchPayload>, ApiError> {
+        self.get(&format!("torrents/search/{}", encode(query)?), &options)
+            .await
+    }
+
+    /// Searches torrents of a movie or series by its id.
+    pub async fn search_torrents_by_id(
+        &self,
+        id: &SearchId,
+        options: SearchQuery,
+    ) -> Result<ApiResponse<TorrentSearchPayload>, ApiError> {
+        self.get(&format!("torrents/{}", encode(&id.to_string())?), &options)
+            .await
+    }
+
+    /// Searches usenet releases by title.
+    pub async fn search_usenet(
+        &self,
+        query: &str,
+        options: SearchQuery,
+    ) -> Result<ApiResponse<UsenetSearchPayload>, ApiError> {
+        self.get(&format!("usenet/search/{}", encode(query)?), &options)
+            .await
+    }
+
+    /// Searches usenet releases of a movie or series by its id.
+    pub async fn search_usenet_by_id(
+        &self,
+        id: &SearchId,
+        options: SearchQuery,
+    ) -> Result<ApiResponse<UsenetSearchPayload>, ApiError> {
+        self.get(&format!("usenet/{}", encode(&id.to_string())?), &options)
+            .await
+    }
+
+    async fn get<T: DeserializeOwned>(
+        &self,
+        path: &str,
+        options: &SearchQuery,
+    ) -> Result<ApiResponse<T>, ApiError> {
+        let client = self.client.with_base_url(self.base_url.as_str());
+        let resp = client
+            .request_with_query(Method::GET, path, options)
+            .await?;
+        self.client.checked(resp)
+    }
+}
+
+/// Characters encoded in a path segment, every one but the unreserved ones and the `:` and `@` that RFC 3986
+/// allows in segments, so ids such as `imdb:tt0111161` are sent as is.
+const PATH_SEGMENT: &AsciiSet = &NON_ALPHANUMERIC
+    .remove(b'-')
+    .remove(b'.')
+    .remove(b'_')
+    .remove(b'~')
+    .remove(b':')
+    .remove(b'@');
+
+/// Encodes a query or id as a single path segment.
+fn encode(segment: &str) -> Result<String, ApiError> {
+    let segment = segment.trim();
+    if segment.is_empty() {
+        return Err(ApiError::InvalidInput(
+            "A search query cannot be empty".into(),
+        ));
+    }
+
+    // `.` and `..` would be resolved as relative segments by the URL parser.
+    if segment.chars().all(|c| c == '.') {
+        return Ok(segment.replace('.', "%2E"));
+    }
+
+    Ok(utf8_percent_encode(segment, PATH_SEGMENT).to_string())
+}
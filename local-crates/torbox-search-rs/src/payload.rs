@@ -0,0 +1,147 @@
+use serde::{Deserialize, Deserializer, Serialize};
+use torbox_core_rs::{data::hash::InfoHash, error::ApiError};
+use torbox_torrent_rs::{body::TorrentCreateBody, magnet::MagnetLink};
+
+use crate::types::SearchResultKind;
+
+/// Metadata of the movie or series a search matched, when `metadata` was requested.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "specta", derive(specta::Type))]
+pub struct SearchMetadata {
+    pub title: String,
+    #[serde(default)]
+    pub description: Option<String>,
+    #[serde(default)]
+    pub genres: Vec<String>,
+    /// `movie` or `series`.
+    #[serde(default, rename = "mediaType")]
+    pub media_type: Option<String>,
+}
+
+/// A torrent found by the Search API.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "specta", derive(specta::Type))]
+pub struct TorrentSearchResult {
+    /// `None` when the tracker gave no hash or an invalid one.
+    #[serde(default, deserialize_with = "lenient_hash")]
+    pub hash: Option<InfoHash>,
+    /// Title as announced by the tracker.
+    pub raw_title: String,
+    /// Cleaned up title, without the release tags.
+    pub title: String,
+    #[serde(default)]
+    pub magnet: Option<String>,
+    /// Link to the `.torrent` file, when the tracker gives one.
+    #[serde(default)]
+    pub torrent: Option<String>,
+    #[serde(default)]
+    pub last_known_seeders: i64,
+    #[serde(default)]
+    pub last_known_peers: i64,
+    /// Size in bytes.
+    pub size: u64,
+    #[serde(default)]
+    pub tracker: Option<String>,
+    #[serde(default)]
+    pub categories: Vec<String>,
+    /// Number of files in the torrent.
+    #[serde(default)]
+    pub files: u64,
+    #[serde(rename = "type")]
+    pub kind: SearchResultKind,
+    /// Age of the release, e.g. `12d`.
+    #[serde(default)]
+    pub age: Option<String>,
+    /// Whether the result comes from one of the user's search engines.
+    #[serde(default)]
+    pub user_search: bool,
+    /// Whether TorBox has it cached, only checked with `check_cache`.
+    #[serde(default)]
+    pub cached: bool,
+    /// Whether the user already has it, only checked with `check_owned`.
+    #[serde(default)]
+    pub owned: bool,
+}
+
+impl TorrentSearchResult {
+    /// A [`TorrentCreateBody`] adding this torrent, named after its title.
+    ///
+    /// Uses the result's magnet link, or one built from its hash when the tracker gave none.
+    pub fn to_create_body(&self) -> Result<TorrentCreateBody, ApiError> {
+        let magnet = match (&self.magnet, &self.hash) {
+            (Some(magnet), _) => MagnetLink::parse(magnet)?,
+            (None, Some(hash)) => {
+                MagnetLink::from_info_hash(hash.as_str())?.with_name(&self.raw_title)
+            }
+            (None, None) => {
+                return Err(ApiError::InvalidInput(format!(
+                    "{} has neither a magnet nor a valid hash",
+                    self.raw_title
+                )));
+            }
+        };
+
+        let mut body = TorrentCreateBody::from_magnet(magnet);
+        body.name = Some(self.title.clone());
+        Ok(body)
+    }
+}
+
+/// A usenet release found by the Search API.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "specta", derive(specta::Type))]
+pub struct UsenetSearchResult {
+    /// `None` when the indexer gave no hash or an invalid one.
+    #[serde(default, deserialize_with = "lenient_hash")]
+    pub hash: Option<InfoHash>,
+    pub raw_title: String,
+    pub title: String,
+    /// Link to the NZB file, to give to `UsenetSource::Link`.
+    #[serde(default)]
+    pub nzb: Option<String>,
+    /// Size in bytes.
+    pub size: u64,
+    #[serde(default)]
+    pub tracker: Option<String>,
+    #[serde(default)]
+    pub categories: Vec<String>,
+    #[serde(default)]
+    pub files: u64,
+    #[serde(rename = "type")]
+    pub kind: SearchResultKind,
+    #[serde(default)]
+    pub age: Option<String>,
+    #[serde(default)]
+    pub user_search: bool,
+    #[serde(default)]
+    pub cached: bool,
+    #[serde(default)]
+    pub owned: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "specta", derive(specta::Type))]
+pub struct TorrentSearchPayload {
+    #[serde(default)]
+    pub metadata: Option<SearchMetadata>,
+    #[serde(default)]
+    pub torrents: Vec<TorrentSearchResult>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "specta", derive(specta::Type))]
+pub struct UsenetSearchPayload {
+    #[serde(default)]
+    pub metadata: Option<SearchMetadata>,
+    #[serde(default)]
+    pub nzbs: Vec<UsenetSearchResult>,
+}
+
+/// Reads a missing or malformed hash as `None`, so one bad result does not fail the whole search.
+fn lenient_hash<'de, D>(deserializer: D) -> Result<Option<InfoHash>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let hash = Option::<String>::deserialize(deserializer)?;
+    Ok(hash.and_then(|hash| InfoHash::new(&hash).ok()))
+}
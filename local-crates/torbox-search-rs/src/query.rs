@@ -0,0 +1,60 @@
+use serde::Serialize;
+
+/// Options shared by every search endpoint, unset ones use the defaults of the Search API.
+#[derive(Debug, Clone, Default, Serialize)]
+#[cfg_attr(feature = "specta", derive(specta::Type))]
+pub struct SearchQuery {
+    /// Whether to return the metadata (title, description, genres...) of the matched media.
+    ///
+    /// Default is true. Optional.
+    pub metadata: Option<bool>,
+
+    /// Season to search for, series only.
+    pub season: Option<u32>,
+
+    /// Episode to search for, requires `season`.
+    pub episode: Option<u32>,
+
+    /// Whether to check if each result is cached on TorBox, filling their `cached` field.
+    ///
+    /// Default is false. Optional.
+    pub check_cache: Option<bool>,
+
+    /// Whether to check if each result is already in the user's account, filling their `owned` field.
+    ///
+    /// Default is false. Optional.
+    pub check_owned: Option<bool>,
+
+    /// Whether to also search the user's own search engines, see `UserApi::get_search_engines`.
+    ///
+    /// Default is false. Optional.
+    pub search_user_engines: Option<bool>,
+}
+
+impl SearchQuery {
+    pub fn with_episode(mut self, season: u32, episode: u32) -> Self {
+        self.season = Some(season);
+        self.episode = Some(episode);
+        self
+    }
+
+    pub fn with_season(mut self, season: u32) -> Self {
+        self.season = Some(season);
+        self
+    }
+
+    pub fn with_check_cache(mut self, check_cache: bool) -> Self {
+        self.check_cache = Some(check_cache);
+        self
+    }
+
+    pub fn with_check_owned(mut self, check_owned: bool) -> Self {
+        self.check_owned = Some(check_owned);
+        self
+    }
+
+    pub fn with_search_user_engines(mut self, search_user_engines: bool) -> Self {
+        self.search_user_engines = Some(search_user_engines);
+        self
+    }
+}
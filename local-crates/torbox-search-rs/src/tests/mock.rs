@@ -0,0 +1,104 @@
+use torbox_core_rs::error::ApiError;
+use torbox_mock::MockServer;
+
+use crate::{SearchApi, query::SearchQuery, types::SearchId};
+
+#[tokio::test]
+async fn test_search_torrents_returns_matches() {
+    let server = MockServer::start().await;
+    server
+        .state()
+        .add_torrent("Big Buck Bunny", &[("bunny.mkv", 4096)]);
+    server
+        .state()
+        .add_torrent("Sintel", &[("sintel.mkv", 2048)]);
+    let client = server.client();
+    let api = SearchApi::new(&client).with_base_url(server.search_url());
+
+    let payload = api
+        .search_torrents("buck bunny", SearchQuery::default().with_check_cache(true))
+        .await
+        .unwrap()
+        .into_data()
+        .unwrap();
+
+    assert_eq!(payload.metadata.unwrap().title, "buck bunny");
+    assert_eq!(payload.torrents.len(), 1);
+    assert_eq!(payload.torrents[0].size, 4096);
+    assert!(payload.torrents[0].cached);
+
+    let request = server.state().requests().pop().unwrap();
+    assert_eq!(request.path, "search/torrents/search/buck%20bunny");
+    assert_eq!(request.query.as_deref(), Some("check_cache=true"));
+}
+
+#[tokio::test]
+async fn test_search_usenet_with_episode() {
+    let server = MockServer::start().await;
+    server
+        .state()
+        .add_usenet("Show S01E03", &[("show.mkv", 1024)]);
+    let client = server.client();
+    let api = SearchApi::new(&client).with_base_url(server.search_url());
+
+    let payload = api
+        .search_usenet(
+            "show",
+            SearchQuery::default()
+                .with_episode(1, 3)
+                .with_search_user_engines(true),
+        )
+        .await
+        .unwrap()
+        .into_data()
+        .unwrap();
+
+    assert_eq!(payload.nzbs.len(), 1);
+    let request = server.state().requests().pop().unwrap();
+    assert_eq!(
+        request.query.as_deref(),
+        Some("season=1&episode=3&search_user_engines=true")
+    );
+}
+
+#[tokio::test]
+async fn test_empty_query_is_rejected_before_request() {
+    let server = MockServer::start().await;
+    let client = server.client();
+    let api = SearchApi::new(&client).with_base_url(server.search_url());
+
+    let err = api
+        .search_torrents("  ", SearchQuery::default())
+        .await
+        .unwrap_err();
+
+    assert!(matches!(err, ApiError::InvalidInput(_)));
+    assert!(server.state().requests().is_empty());
+}
+
+#[tokio::test]
+async fn test_search_by_imdb_id_keeps_the_colon() {
+    let server = MockServer::start().await;
+    server
+        .state()
+        .add_torrent("The Shawshank Redemption", &[("movie.mkv", 4096)]);
+    let client = server.client();
+    let api = SearchApi::new(&client).with_base_url(server.search_url());
+
+    let payload = api
+        .search_torrents_by_id(&SearchId::Imdb("tt0111161".into()), SearchQuery::default())
+        .await
+        .unwrap()
+        .into_data()
+        .unwrap();
+
+    assert_eq!(payload.torrents.len(), 1);
+    let request = server.state().requests().pop().unwrap();
+    assert_eq!(request.path, "search/torrents/imdb:tt0111161");
+
+    api.search_torrents("v1.0 rip_x~", SearchQuery::default())
+        .await
+        .unwrap();
+    let request = server.state().requests().pop().unwrap();
+    assert_eq!(request.path, "search/torrents/search/v1.0%20rip_x~");
+}
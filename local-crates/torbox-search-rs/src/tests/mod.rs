@@ -0,0 +1,66 @@
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod payload;
+
+#[cfg(test)]
+pub mod search_test {
+    use std::env;
+
+    use crate::{SearchApi, query::SearchQuery, types::SearchId};
+
+    use dotenvy::from_filename;
+    use torbox_core_rs::client::TorboxClient;
+
+    pub fn load_token_from_file() -> Option<String> {
+        if let Err(err) = from_filename(".token") {
+            eprintln!(
+                "Could not load .token file, please create one before starting unit testing: {err}"
+            );
+            return None;
+        }
+        env::var("TORBOX_TOKEN").ok()
+    }
+
+    fn test_client() -> TorboxClient {
+        let token = load_token_from_file().expect("Missing TORBOX_TOKEN in .token file");
+        TorboxClient::new(token)
+    }
+
+    #[tokio::test]
+    async fn test_search_torrents_success() {
+        let client = test_client();
+        let api = SearchApi::new(&client);
+
+        let result = api
+            .search_torrents("big buck bunny", SearchQuery::default())
+            .await;
+        match result {
+            Ok(response) => {
+                println!("Torrent Search: {:?}", response);
+                assert!(response.success, "API responded with success=false");
+            }
+            Err(e) => panic!("API call failed: {e:?}"),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_search_usenet_by_id_success() {
+        let client = test_client();
+        let api = SearchApi::new(&client);
+
+        let result = api
+            .search_usenet_by_id(
+                &SearchId::Imdb("tt1254207".into()),
+                SearchQuery::default().with_check_cache(true),
+            )
+            .await;
+        match result {
+            Ok(response) => {
+                println!("Usenet Search: {:?}", response);
+                assert!(response.success, "API responded with success=false");
+            }
+            Err(e) => panic!("API call failed: {e:?}"),
+        }
+    }
+}
@@ -0,0 +1,118 @@
+use torbox_core_rs::error::ApiError;
+use torbox_torrent_rs::{magnet::MagnetLink, types::TorrentSource};
+
+use crate::{
+    payload::{TorrentSearchPayload, TorrentSearchResult},
+    types::{SearchId, SearchResultKind},
+};
+
+const RESPONSE: &str = r#"{
+    "metadata": {
+        "title": "Big Buck Bunny",
+        "description": "A giant rabbit takes revenge.",
+        "genres": ["Animation", "Short"],
+        "mediaType": "movie",
+        "releaseYears": 2008
+    },
+    "torrents": [{
+        "hash": "DD8255ECDC7CA55FB0BBF81323D87062DB1F6D1C",
+        "raw_title": "Big.Buck.Bunny.2008.1080p.BluRay.x264",
+        "title": "Big Buck Bunny",
+        "title_parsed_data": {"resolution": "1080p"},
+        "magnet": "magnet:?xt=urn:btih:dd8255ecdc7ca55fb0bbf81323d87062db1f6d1c&dn=Big+Buck+Bunny",
+        "torrent": null,
+        "last_known_seeders": 42,
+        "last_known_peers": 3,
+        "size": 276134947,
+        "tracker": "bbb-tracker",
+        "categories": ["movies"],
+        "files": 3,
+        "type": "torrent",
+        "nzb": null,
+        "age": "4d",
+        "user_search": false,
+        "cached": true,
+        "owned": false
+    }, {
+        "hash": "c9e15763f722f23e98a29decdfae341b98d53056",
+        "raw_title": "Big Buck Bunny 720p",
+        "title": "Big Buck Bunny",
+        "size": 1024,
+        "type": "torrent"
+    }, {
+        "hash": "not-a-hash",
+        "raw_title": "Big Buck Bunny CAM",
+        "title": "Big Buck Bunny",
+        "size": 512,
+        "type": "torrent"
+    }]
+}"#;
+
+fn results() -> Vec<TorrentSearchResult> {
+    serde_json::from_str::<TorrentSearchPayload>(RESPONSE)
+        .unwrap()
+        .torrents
+}
+
+#[test]
+fn test_torrent_search_payload_parses() {
+    let payload: TorrentSearchPayload = serde_json::from_str(RESPONSE).unwrap();
+
+    let metadata = payload.metadata.unwrap();
+    assert_eq!(metadata.title, "Big Buck Bunny");
+    assert_eq!(metadata.media_type.as_deref(), Some("movie"));
+
+    let first = &payload.torrents[0];
+    assert_eq!(
+        first.hash.as_ref().unwrap(),
+        "dd8255ecdc7ca55fb0bbf81323d87062db1f6d1c"
+    );
+    assert_eq!(first.last_known_seeders, 42);
+    assert_eq!(first.size, 276134947);
+    assert_eq!(first.kind, SearchResultKind::Torrent);
+    assert!(first.cached);
+
+    let second = &payload.torrents[1];
+    assert!(second.magnet.is_none());
+    assert!(!second.cached);
+
+    assert!(payload.torrents[2].hash.is_none());
+}
+
+#[test]
+fn test_to_create_body_uses_magnet_or_hash() {
+    let results = results();
+
+    let body = results[0].to_create_body().unwrap();
+    assert_eq!(body.name.as_deref(), Some("Big Buck Bunny"));
+    let TorrentSource::Magnet(magnet) = &body.source else {
+        panic!("Expected a magnet source");
+    };
+    assert_eq!(
+        MagnetLink::parse(magnet).unwrap().info_hash,
+        results[0].hash
+    );
+
+    let body = results[1].to_create_body().unwrap();
+    let TorrentSource::Magnet(magnet) = &body.source else {
+        panic!("Expected a magnet source");
+    };
+    let magnet = MagnetLink::parse(magnet).unwrap();
+    assert_eq!(magnet.info_hash, results[1].hash);
+    assert_eq!(magnet.name.as_deref(), Some("Big Buck Bunny 720p"));
+
+    assert!(matches!(
+        results[2].to_create_body(),
+        Err(ApiError::InvalidInput(_))
+    ));
+}
+
+#[test]
+fn test_search_id_display() {
+    assert_eq!(
+        SearchId::Imdb("tt1254207".into()).to_string(),
+        "imdb:tt1254207"
+    );
+    assert_eq!(SearchId::Tmdb(10378).to_string(), "tmdb:10378");
+    assert_eq!(SearchId::Tvdb(81189).to_string(), "tvdb:81189");
+}
@@ -0,0 +1,36 @@
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+
+/// External id of a movie or series, searched with `search_*_by_id`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+#[cfg_attr(feature = "specta", derive(specta::Type))]
+pub enum SearchId {
+    /// IMDb id, `tt` prefix included, e.g. `tt0944947`.
+    Imdb(String),
+    /// TMDb id.
+    Tmdb(u64),
+    /// TVDB id.
+    Tvdb(u64),
+}
+
+/// Formats the id the way the Search API expects it in paths, e.g. `imdb:tt0944947`.
+impl fmt::Display for SearchId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SearchId::Imdb(id) => write!(f, "imdb:{id}"),
+            SearchId::Tmdb(id) => write!(f, "tmdb:{id}"),
+            SearchId::Tvdb(id) => write!(f, "tvdb:{id}"),
+        }
+    }
+}
+
+/// Kind of a search result.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+#[cfg_attr(feature = "specta", derive(specta::Type))]
+pub enum SearchResultKind {
+    Torrent,
+    Usenet,
+}
@@ -0,0 +1,13 @@
+use reqwest::Method;
+use torbox_core_rs::client::EndpointSpec;
+
+use crate::{payload::StreamData, query::StreamCreateQuery};
+
+pub struct StreamCreateGetEp;
+
+impl EndpointSpec for StreamCreateGetEp {
+    type Req = StreamCreateQuery;
+    type Resp = StreamData;
+    const PATH: &'static str = "api/stream/createstream";
+    const METHOD: Method = Method::GET;
+}
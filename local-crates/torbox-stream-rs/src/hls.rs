@@ -0,0 +1,259 @@
+//! Parser for HLS master playlists (RFC 8216), enough to let a player pick a variant and its tracks.
+
+use std::collections::HashMap;
+
+use reqwest::Url;
+use serde::Serialize;
+use torbox_core_rs::error::ApiError;
+
+const EXTM3U: &str = "#EXTM3U";
+const STREAM_INF: &str = "#EXT-X-STREAM-INF:";
+const MEDIA: &str = "#EXT-X-MEDIA:";
+
+/// A variant stream (`#EXT-X-STREAM-INF`), one rendition of the video at a given bitrate.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(feature = "specta", derive(specta::Type))]
+pub struct HlsVariant {
+    /// URL of the media playlist, resolved against the master playlist URL when one was given.
+    pub uri: String,
+    /// Peak bitrate, in bits per second.
+    pub bandwidth: u64,
+    pub average_bandwidth: Option<u64>,
+    /// Width and height, in pixels.
+    pub resolution: Option<(u32, u32)>,
+    pub codecs: Option<String>,
+    pub frame_rate: Option<f64>,
+    /// `GROUP-ID` of the audio renditions to play along.
+    pub audio: Option<String>,
+    /// `GROUP-ID` of the subtitle renditions available.
+    pub subtitles: Option<String>,
+}
+
+impl HlsVariant {
+    pub fn height(&self) -> Option<u32> {
+        self.resolution.map(|(_, height)| height)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[cfg_attr(feature = "specta", derive(specta::Type))]
+pub enum HlsMediaType {
+    Audio,
+    Video,
+    Subtitles,
+    ClosedCaptions,
+}
+
+/// An alternative rendition (`#EXT-X-MEDIA`), such as an audio or subtitle track.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(feature = "specta", derive(specta::Type))]
+pub struct HlsMedia {
+    pub media_type: HlsMediaType,
+    pub group_id: String,
+    pub name: String,
+    pub language: Option<String>,
+    pub default: bool,
+    pub autoselect: bool,
+    /// URL of the rendition's media playlist, `None` when it is muxed into the variants.
+    pub uri: Option<String>,
+}
+
+/// A parsed HLS master playlist.
+///
+/// ```
+/// # use torbox_stream_rs::hls::HlsMasterPlaylist;
+/// let playlist = HlsMasterPlaylist::parse(
+///     "#EXTM3U\n\
+///      #EXT-X-STREAM-INF:BANDWIDTH=800000,RESOLUTION=1280x720\n\
+///      720p/index.m3u8\n\
+///      #EXT-X-STREAM-INF:BANDWIDTH=2500000,RESOLUTION=1920x1080\n\
+///      1080p/index.m3u8\n",
+///     Some("https://stream.torbox.app/abc/master.m3u8"),
+/// )
+/// .unwrap();
+///
+/// let best = playlist.best_variant().unwrap();
+/// assert_eq!(best.uri, "https://stream.torbox.app/abc/1080p/index.m3u8");
+/// assert_eq!(playlist.variant_for_height(900).unwrap().height(), Some(720));
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+#[cfg_attr(feature = "specta", derive(specta::Type))]
+pub struct HlsMasterPlaylist {
+    pub variants: Vec<HlsVariant>,
+    pub media: Vec<HlsMedia>,
+}
+
+impl HlsMasterPlaylist {
+    /// Parses a master playlist, resolving its relative URIs against `base_url` if given.
+    ///
+    /// Unknown tags are ignored, a media playlist (without variants) is rejected.
+    pub fn parse(text: &str, base_url: Option<&str>) -> Result<Self, ApiError> {
+        let base = base_url
+            .map(|url| Url::parse(url).map_err(|e| invalid(&format!("base URL {url:?}: {e}"))))
+            .transpose()?;
+        let resolve = |uri: &str| match &base {
+            Some(base) => base
+                .join(uri)
+                .map(String::from)
+                .map_err(|e| invalid(&format!("URI {uri:?}: {e}"))),
+            None => Ok(uri.to_string()),
+        };
+
+        let mut lines = text.lines().map(str::trim).filter(|line| !line.is_empty());
+        if lines.next() != Some(EXTM3U) {
+            return Err(invalid("it must start with #EXTM3U"));
+        }
+
+        let mut playlist = Self::default();
+        while let Some(line) = lines.next() {
+            if let Some(attributes) = line.strip_prefix(STREAM_INF) {
+                let attributes = parse_attributes(attributes);
+                let uri = lines
+                    .find(|line| !line.starts_with('#'))
+                    .ok_or_else(|| invalid("#EXT-X-STREAM-INF without a URI"))?;
+                playlist.variants.push(variant(&attributes, resolve(uri)?)?);
+            } else if let Some(attributes) = line.strip_prefix(MEDIA) {
+                let attributes = parse_attributes(attributes);
+                let uri = attributes.get("URI").map(|uri| resolve(uri)).transpose()?;
+                playlist.media.extend(media(&attributes, uri)?);
+            }
+        }
+
+        if playlist.variants.is_empty() {
+            return Err(invalid(
+                "no #EXT-X-STREAM-INF variant, not a master playlist",
+            ));
+        }
+
+        Ok(playlist)
+    }
+
+    /// The variant with the highest bandwidth.
+    pub fn best_variant(&self) -> Option<&HlsVariant> {
+        self.variants.iter().max_by_key(|variant| variant.bandwidth)
+    }
+
+    /// The highest variant not taller than `max_height`, or the smallest one if they all are.
+    ///
+    /// Variants without a resolution are ignored.
+    pub fn variant_for_height(&self, max_height: u32) -> Option<&HlsVariant> {
+        let sized = self.variants.iter().filter(|v| v.height().is_some());
+
+        sized
+            .clone()
+            .filter(|v| v.height() <= Some(max_height))
+            .max_by_key(|v| (v.height(), v.bandwidth))
+            .or_else(|| sized.min_by_key(|v| (v.height(), v.bandwidth)))
+    }
+
+    /// Audio renditions, in playlist order.
+    pub fn audio_tracks(&self) -> impl Iterator<Item = &HlsMedia> {
+        self.media_of(HlsMediaType::Audio)
+    }
+
+    /// Subtitle renditions, in playlist order.
+    pub fn subtitle_tracks(&self) -> impl Iterator<Item = &HlsMedia> {
+        self.media_of(HlsMediaType::Subtitles)
+    }
+
+    fn media_of(&self, media_type: HlsMediaType) -> impl Iterator<Item = &HlsMedia> {
+        self.media
+            .iter()
+            .filter(move |media| media.media_type == media_type)
+    }
+}
+
+fn invalid(reason: &str) -> ApiError {
+    ApiError::InvalidInput(format!("Invalid HLS playlist: {reason}"))
+}
+
+/// Splits an attribute list (`KEY=value,KEY="quoted, value"`), unquoting quoted values.
+fn parse_attributes(list: &str) -> HashMap<String, String> {
+    let mut attributes = HashMap::new();
+    let mut rest = list;
+
+    while let Some((key, after)) = rest.split_once('=') {
+        let (value, after) = match after.strip_prefix('"') {
+            Some(quoted) => {
+                let end = quoted.find('"').unwrap_or(quoted.len());
+                let after = quoted.get(end + 1..).unwrap_or_default();
+                (&quoted[..end], after)
+            }
+            None => after.split_once(',').map_or((after, ""), |(v, a)| (v, a)),
+        };
+        attributes.insert(key.trim().to_string(), value.to_string());
+        rest = after.trim_start_matches(',');
+    }
+
+    attributes
+}
+
+fn number<T: std::str::FromStr>(
+    attributes: &HashMap<String, String>,
+    key: &str,
+) -> Result<Option<T>, ApiError> {
+    attributes
+        .get(key)
+        .map(|value| {
+            value
+                .parse()
+                .map_err(|_| invalid(&format!("{key} {value:?} is not a number")))
+        })
+        .transpose()
+}
+
+fn variant(attributes: &HashMap<String, String>, uri: String) -> Result<HlsVariant, ApiError> {
+    let resolution = attributes
+        .get("RESOLUTION")
+        .map(|value| {
+            value
+                .split_once('x')
+                .and_then(|(w, h)| Some((w.parse().ok()?, h.parse().ok()?)))
+                .ok_or_else(|| invalid(&format!("RESOLUTION {value:?} is not WIDTHxHEIGHT")))
+        })
+        .transpose()?;
+
+    Ok(HlsVariant {
+        uri,
+        bandwidth: number(attributes, "BANDWIDTH")?
+            .ok_or_else(|| invalid("#EXT-X-STREAM-INF without a BANDWIDTH"))?,
+        average_bandwidth: number(attributes, "AVERAGE-BANDWIDTH")?,
+        resolution,
+        codecs: attributes.get("CODECS").cloned(),
+        frame_rate: number(attributes, "FRAME-RATE")?,
+        audio: attributes.get("AUDIO").cloned(),
+        subtitles: attributes.get("SUBTITLES").cloned(),
+    })
+}
+
+/// `None` for a `TYPE` this version does not know, which is skipped like unknown tags.
+fn media(
+    attributes: &HashMap<String, String>,
+    uri: Option<String>,
+) -> Result<Option<HlsMedia>, ApiError> {
+    let media_type = match attributes.get("TYPE").map(String::as_str) {
+        Some("AUDIO") => HlsMediaType::Audio,
+        Some("VIDEO") => HlsMediaType::Video,
+        Some("SUBTITLES") => HlsMediaType::Subtitles,
+        Some("CLOSED-CAPTIONS") => HlsMediaType::ClosedCaptions,
+        Some(_) => return Ok(None),
+        None => return Err(invalid("#EXT-X-MEDIA without a TYPE")),
+    };
+    let required = |key: &str| {
+        attributes
+            .get(key)
+            .cloned()
+            .ok_or_else(|| invalid(&format!("#EXT-X-MEDIA without a {key}")))
+    };
+    let flag = |key: &str| attributes.get(key).is_some_and(|value| value == "YES");
+
+    Ok(Some(HlsMedia {
+        media_type,
+        group_id: required("GROUP-ID")?,
+        name: required("NAME")?,
+        language: attributes.get("LANGUAGE").cloned(),
+        default: flag("DEFAULT"),
+        autoselect: flag("AUTOSELECT"),
+        uri,
+    }))
+}
@@ -0,0 +1,69 @@
+pub mod endpoint;
+pub mod hls;
+pub mod payload;
+pub mod query;
+pub mod tests;
+pub mod types;
+
+use reqwest::Method;
+use torbox_core_rs::{
+    api::ApiResponse,
+    client::{Endpoint, TorboxClient},
+    error::ApiError,
+};
+
+use crate::{
+    endpoint::StreamCreateGetEp, hls::HlsMasterPlaylist, payload::StreamData,
+    query::StreamCreateQuery,
+};
+
+/// Main interface for TorBox streaming
+///
+/// Provides methods for all stream-related API calls including:
+/// - Creating an HLS stream of a torrent, web download or usenet video file
+/// - Listing its audio tracks, subtitles and resolutions
+/// - Fetching and parsing the HLS master playlist, so a player can pick a variant
+#[cfg_attr(feature = "specta", derive(specta::Type))]
+pub struct StreamApi<'a> {
+    client: &'a TorboxClient,
+}
+
+impl<'a> StreamApi<'a> {
+    pub fn new(client: &'a TorboxClient) -> Self {
+        Self { client }
+    }
+
+    /// Creates a stream of a video file, with the chosen audio, subtitle and resolution if any.
+    ///
+    /// The returned metadata lists the indexes the `chosen_*_index` options accept, create the stream
+    /// again with them to switch tracks.
+    pub async fn create_stream(
+        &self,
+        query: StreamCreateQuery,
+    ) -> Result<ApiResponse<StreamData>, ApiError> {
+        Endpoint::<StreamCreateGetEp>::new(self.client)
+            .call_query(query)
+            .await
+    }
+
+    /// Fetches and parses the master playlist of a stream.
+    pub async fn master_playlist(
+        &self,
+        stream: &StreamData,
+    ) -> Result<HlsMasterPlaylist, ApiError> {
+        self.fetch_playlist(&stream.hls_url).await
+    }
+
+    /// Fetches and parses the master playlist at `url`, its relative URIs are resolved against it.
+    pub async fn fetch_playlist(&self, url: &str) -> Result<HlsMasterPlaylist, ApiError> {
+        let request = self.client.client.get(url);
+        let response = self
+            .client
+            .send(&Method::GET, "stream", request)
+            .await?
+            .error_for_status()?;
+        let text = response.text().await?;
+
+        HlsMasterPlaylist::parse(&text, Some(url))
+    }
+}
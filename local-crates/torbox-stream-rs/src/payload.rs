@@ -0,0 +1,68 @@
+use serde::{Deserialize, Serialize};
+
+/// A stream created by [`create_stream`](crate::StreamApi::create_stream).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "specta", derive(specta::Type))]
+pub struct StreamData {
+    /// URL of the HLS master playlist, playable as is by HLS capable players.
+    pub hls_url: String,
+    /// Whether TorBox transcodes the file, instead of only remuxing it.
+    #[serde(default)]
+    pub needs_transcoding: bool,
+    #[serde(default)]
+    pub presigned_token: Option<String>,
+    #[serde(default)]
+    pub user_token: Option<String>,
+    #[serde(default)]
+    pub metadata: StreamMetadata,
+}
+
+/// Tracks and resolutions of the streamed file, their `index` is what the `chosen_*_index` options take.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[cfg_attr(feature = "specta", derive(specta::Type))]
+pub struct StreamMetadata {
+    #[serde(default)]
+    pub audios: Vec<AudioTrack>,
+    #[serde(default)]
+    pub subtitles: Vec<SubtitleTrack>,
+    #[serde(default)]
+    pub resolutions: Vec<StreamResolution>,
+    /// Duration of the video, in seconds.
+    #[serde(default)]
+    pub duration: Option<f64>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "specta", derive(specta::Type))]
+pub struct AudioTrack {
+    pub index: u32,
+    #[serde(default)]
+    pub language: Option<String>,
+    #[serde(default)]
+    pub title: Option<String>,
+    #[serde(default)]
+    pub codec: Option<String>,
+    #[serde(default)]
+    pub channels: Option<u32>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "specta", derive(specta::Type))]
+pub struct SubtitleTrack {
+    pub index: u32,
+    #[serde(default)]
+    pub language: Option<String>,
+    #[serde(default)]
+    pub title: Option<String>,
+    #[serde(default)]
+    pub codec: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "specta", derive(specta::Type))]
+pub struct StreamResolution {
+    pub index: u32,
+    #[serde(default)]
+    pub width: Option<u32>,
+    pub height: u32,
+}
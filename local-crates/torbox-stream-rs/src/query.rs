@@ -0,0 +1,51 @@
+use serde::Serialize;
+
+use crate::types::StreamType;
+
+#[derive(Debug, Serialize, Clone)]
+#[cfg_attr(feature = "specta", derive(specta::Type))]
+pub struct StreamCreateQuery {
+    /// The id of the torrent, web download or usenet download.
+    pub id: u32,
+    /// The id of the video file to stream.
+    pub file_id: u32,
+    #[serde(rename = "type")]
+    pub stream_type: StreamType,
+    /// Index of the subtitle track burned into the stream, see `StreamMetadata::subtitles`. Optional.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub chosen_subtitle_index: Option<u32>,
+    /// Index of the audio track, see `StreamMetadata::audios`. Optional, default is the first one.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub chosen_audio_index: Option<u32>,
+    /// Index of the resolution, see `StreamMetadata::resolutions`. Optional, default is the original one.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub chosen_resolution_index: Option<u32>,
+}
+
+impl StreamCreateQuery {
+    pub fn new(stream_type: StreamType, id: u32, file_id: u32) -> Self {
+        Self {
+            id,
+            file_id,
+            stream_type,
+            chosen_subtitle_index: None,
+            chosen_audio_index: None,
+            chosen_resolution_index: None,
+        }
+    }
+
+    pub fn with_subtitle(mut self, index: u32) -> Self {
+        self.chosen_subtitle_index = Some(index);
+        self
+    }
+
+    pub fn with_audio(mut self, index: u32) -> Self {
+        self.chosen_audio_index = Some(index);
+        self
+    }
+
+    pub fn with_resolution(mut self, index: u32) -> Self {
+        self.chosen_resolution_index = Some(index);
+        self
+    }
+}
@@ -0,0 +1,110 @@
+use torbox_core_rs::error::ApiError;
+
+use crate::hls::{HlsMasterPlaylist, HlsMediaType};
+
+const MASTER: &str = r#"#EXTM3U
+#EXT-X-VERSION:6
+#EXT-X-INDEPENDENT-SEGMENTS
+
+#EXT-X-MEDIA:TYPE=AUDIO,GROUP-ID="aud",NAME="English, 5.1",LANGUAGE="en",DEFAULT=YES,AUTOSELECT=YES,URI="audio/en.m3u8"
+#EXT-X-MEDIA:TYPE=AUDIO,GROUP-ID="aud",NAME="Français",LANGUAGE="fr",DEFAULT=NO,AUTOSELECT=YES,URI="audio/fr.m3u8"
+#EXT-X-MEDIA:TYPE=SUBTITLES,GROUP-ID="subs",NAME="English",LANGUAGE="en",URI="https://subs.torbox.app/en.m3u8"
+#EXT-X-STREAM-INF:BANDWIDTH=2500000,AVERAGE-BANDWIDTH=2000000,RESOLUTION=1920x1080,CODECS="avc1.640028,mp4a.40.2",FRAME-RATE=23.976,AUDIO="aud",SUBTITLES="subs"
+1080p/index.m3u8
+#EXT-X-STREAM-INF:BANDWIDTH=1200000,RESOLUTION=1280x720,CODECS="avc1.64001f,mp4a.40.2",AUDIO="aud",SUBTITLES="subs"
+720p/index.m3u8
+#EXT-X-STREAM-INF:BANDWIDTH=600000,RESOLUTION=854x480,AUDIO="aud"
+480p/index.m3u8
+"#;
+
+const BASE: &str = "https://stream.torbox.app/42/0/master.m3u8";
+
+#[test]
+fn test_parse_master_playlist() {
+    let playlist = HlsMasterPlaylist::parse(MASTER, Some(BASE)).unwrap();
+
+    assert_eq!(playlist.variants.len(), 3);
+    let first = &playlist.variants[0];
+    assert_eq!(first.uri, "https://stream.torbox.app/42/0/1080p/index.m3u8");
+    assert_eq!(first.bandwidth, 2_500_000);
+    assert_eq!(first.average_bandwidth, Some(2_000_000));
+    assert_eq!(first.resolution, Some((1920, 1080)));
+    assert_eq!(first.codecs.as_deref(), Some("avc1.640028,mp4a.40.2"));
+    assert_eq!(first.frame_rate, Some(23.976));
+    assert_eq!(first.audio.as_deref(), Some("aud"));
+    assert_eq!(first.subtitles.as_deref(), Some("subs"));
+
+    let audios: Vec<_> = playlist.audio_tracks().collect();
+    assert_eq!(audios.len(), 2);
+    assert_eq!(audios[0].name, "English, 5.1");
+    assert_eq!(audios[0].language.as_deref(), Some("en"));
+    assert!(audios[0].default && !audios[1].default);
+    assert_eq!(
+        audios[1].uri.as_deref(),
+        Some("https://stream.torbox.app/42/0/audio/fr.m3u8")
+    );
+
+    let subtitles: Vec<_> = playlist.subtitle_tracks().collect();
+    assert_eq!(subtitles.len(), 1);
+    assert_eq!(subtitles[0].media_type, HlsMediaType::Subtitles);
+    assert_eq!(
+        subtitles[0].uri.as_deref(),
+        Some("https://subs.torbox.app/en.m3u8")
+    );
+}
+
+#[test]
+fn test_variant_selection() {
+    let playlist = HlsMasterPlaylist::parse(MASTER, None).unwrap();
+
+    assert_eq!(playlist.best_variant().unwrap().uri, "1080p/index.m3u8");
+    assert_eq!(
+        playlist.variant_for_height(1080).unwrap().height(),
+        Some(1080)
+    );
+    assert_eq!(
+        playlist.variant_for_height(1000).unwrap().height(),
+        Some(720)
+    );
+    assert_eq!(
+        playlist.variant_for_height(240).unwrap().height(),
+        Some(480)
+    );
+}
+
+#[test]
+fn test_parse_rejects_invalid_playlists() {
+    let cases = [
+        "#EXT-X-STREAM-INF:BANDWIDTH=1\nindex.m3u8",
+        "#EXTM3U\n#EXT-X-TARGETDURATION:10\n#EXTINF:10,\nsegment0.ts",
+        "#EXTM3U\n#EXT-X-STREAM-INF:BANDWIDTH=1000\n",
+        "#EXTM3U\n#EXT-X-STREAM-INF:RESOLUTION=1280x720\nindex.m3u8",
+        "#EXTM3U\n#EXT-X-STREAM-INF:BANDWIDTH=1000,RESOLUTION=720p\nindex.m3u8",
+        "#EXTM3U\n#EXT-X-MEDIA:TYPE=AUDIO,NAME=\"en\"\n#EXT-X-STREAM-INF:BANDWIDTH=1\nindex.m3u8",
+    ];
+
+    for case in cases {
+        assert!(
+            matches!(
+                HlsMasterPlaylist::parse(case, None),
+                Err(ApiError::InvalidInput(_))
+            ),
+            "{case:?} was accepted"
+        );
+    }
+}
+
+#[test]
+fn test_unknown_media_types_are_skipped() {
+    let text = "#EXTM3U\n\
+        #EXT-X-MEDIA:TYPE=THUMBNAILS,GROUP-ID=\"thumbs\",NAME=\"Preview\",URI=\"thumbs.m3u8\"\n\
+        #EXT-X-MEDIA:TYPE=AUDIO,GROUP-ID=\"aud\",NAME=\"English\",URI=\"en.m3u8\"\n\
+        #EXT-X-STREAM-INF:BANDWIDTH=1000,AUDIO=\"aud\"\n\
+        index.m3u8";
+
+    let playlist = HlsMasterPlaylist::parse(text, None).unwrap();
+
+    assert_eq!(playlist.media.len(), 1);
+    assert_eq!(playlist.media[0].media_type, HlsMediaType::Audio);
+    assert_eq!(playlist.variants.len(), 1);
+}
@@ -0,0 +1,64 @@
+use torbox_core_rs::error::ApiErrorCode;
+use torbox_mock::MockServer;
+
+use crate::{StreamApi, query::StreamCreateQuery, types::StreamType};
+
+#[tokio::test]
+async fn test_create_stream_and_fetch_playlist() {
+    let server = MockServer::start().await;
+    let client = server.client().with_strict_errors(true);
+    let api = StreamApi::new(&client);
+    let id = server
+        .state()
+        .add_torrent("movie", &[("movie.mkv", 4096), ("movie.srt", 64)]) as u32;
+
+    let query = StreamCreateQuery::new(StreamType::Torrent, id, 0)
+        .with_audio(1)
+        .with_resolution(1);
+    let stream = api.create_stream(query).await.unwrap().into_data().unwrap();
+    assert!(
+        stream
+            .hls_url
+            .ends_with(&format!("/torrent/{id}/0/master.m3u8"))
+    );
+    assert_eq!(stream.metadata.audios.len(), 2);
+    assert_eq!(stream.metadata.audios[1].channels, Some(6));
+    assert_eq!(
+        stream.metadata.subtitles[0].language.as_deref(),
+        Some("eng")
+    );
+    assert_eq!(stream.metadata.resolutions[1].height, 720);
+
+    let request = server.state().requests().pop().unwrap();
+    let sent = request.query.unwrap();
+    assert!(sent.contains("type=torrent"));
+    assert!(sent.contains("chosen_audio_index=1"));
+    assert!(!sent.contains("chosen_subtitle_index"));
+
+    let playlist = api.master_playlist(&stream).await.unwrap();
+    let base = stream.hls_url.trim_end_matches("master.m3u8");
+    assert_eq!(playlist.variants.len(), 2);
+    assert_eq!(
+        playlist.variant_for_height(720).unwrap().uri,
+        format!("{base}720p/index.m3u8")
+    );
+    assert_eq!(playlist.audio_tracks().count(), 2);
+    assert_eq!(
+        playlist.subtitle_tracks().next().unwrap().uri,
+        Some(format!("{base}subs/0.m3u8"))
+    );
+}
+
+#[tokio::test]
+async fn test_create_stream_of_missing_file() {
+    let server = MockServer::start().await;
+    let client = server.client().with_strict_errors(true);
+    let api = StreamApi::new(&client);
+    let id = server.state().add_usenet("show", &[("show.mkv", 128)]) as u32;
+
+    let err = api
+        .create_stream(StreamCreateQuery::new(StreamType::Usenet, id, 3))
+        .await
+        .unwrap_err();
+    assert_eq!(err.code(), Some(&ApiErrorCode::ItemNotFound));
+}
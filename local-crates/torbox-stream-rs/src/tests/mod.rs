@@ -0,0 +1,46 @@
+#[cfg(test)]
+mod hls;
+#[cfg(test)]
+mod mock;
+
+#[cfg(test)]
+pub mod stream_test {
+    use std::env;
+
+    use crate::{StreamApi, query::StreamCreateQuery, types::StreamType};
+
+    use dotenvy::from_filename;
+    use torbox_core_rs::client::TorboxClient;
+
+    pub fn load_token_from_file() -> Option<String> {
+        if let Err(err) = from_filename(".token") {
+            eprintln!(
+                "Could not load .token file, please create one before starting unit testing: {err}"
+            );
+            return None;
+        }
+        env::var("TORBOX_TOKEN").ok()
+    }
+
+    fn test_client() -> TorboxClient {
+        let token = load_token_from_file().expect("Missing TORBOX_TOKEN in .token file");
+        TorboxClient::new(token)
+    }
+
+    #[tokio::test]
+    async fn test_create_stream_success() {
+        let client = test_client();
+        let api = StreamApi::new(&client);
+
+        let result = api
+            .create_stream(StreamCreateQuery::new(StreamType::Torrent, 1, 0))
+            .await;
+        match result {
+            Ok(response) => {
+                println!("Stream: {:?}", response);
+                assert!(response.success, "API responded with success=false");
+            }
+            Err(e) => panic!("API call failed: {e:?}"),
+        }
+    }
+}
@@ -0,0 +1,11 @@
+use serde::{Deserialize, Serialize};
+
+/// Kind of download the streamed file belongs to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+#[cfg_attr(feature = "specta", derive(specta::Type))]
+pub enum StreamType {
+    Torrent,
+    Usenet,
+    Webdl,
+}
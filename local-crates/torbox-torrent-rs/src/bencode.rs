@@ -0,0 +1,207 @@
+//! Minimal bencode decoder, enough to read `.torrent` files.
+
+use std::collections::BTreeMap;
+
+use torbox_core_rs::error::ApiError;
+
+/// Deepest nesting accepted, real torrents stay far below it.
+const MAX_DEPTH: usize = 64;
+
+/// A decoded bencode value.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum BencodeValue {
+    Int(i64),
+    Bytes(Vec<u8>),
+    List(Vec<BencodeValue>),
+    Dict(BTreeMap<Vec<u8>, BencodeValue>),
+}
+
+impl BencodeValue {
+    pub fn as_int(&self) -> Option<i64> {
+        match self {
+            BencodeValue::Int(i) => Some(*i),
+            _ => None,
+        }
+    }
+
+    pub fn as_bytes(&self) -> Option<&[u8]> {
+        match self {
+            BencodeValue::Bytes(bytes) => Some(bytes),
+            _ => None,
+        }
+    }
+
+    /// The value as text, replacing invalid UTF-8 sequences.
+    pub fn as_str(&self) -> Option<String> {
+        self.as_bytes()
+            .map(|bytes| String::from_utf8_lossy(bytes).into_owned())
+    }
+
+    pub fn as_list(&self) -> Option<&[BencodeValue]> {
+        match self {
+            BencodeValue::List(list) => Some(list),
+            _ => None,
+        }
+    }
+
+    pub fn as_dict(&self) -> Option<&BTreeMap<Vec<u8>, BencodeValue>> {
+        match self {
+            BencodeValue::Dict(dict) => Some(dict),
+            _ => None,
+        }
+    }
+
+    /// Looks `key` up if this is a dictionary.
+    pub fn get(&self, key: &str) -> Option<&BencodeValue> {
+        self.as_dict()?.get(key.as_bytes())
+    }
+}
+
+/// Decodes a complete bencode document, trailing bytes are an error.
+pub fn decode(data: &[u8]) -> Result<BencodeValue, ApiError> {
+    let mut decoder = Decoder { data, pos: 0 };
+    let value = decoder.value(0)?;
+    decoder.end()?;
+    Ok(value)
+}
+
+/// The raw encoded bytes of `key` in the top level dictionary of `data`.
+///
+/// Info hashes are computed over the `info` dictionary exactly as it was encoded, which this gives access to.
+pub fn raw_dict_value<'a>(data: &'a [u8], key: &str) -> Result<Option<&'a [u8]>, ApiError> {
+    let mut decoder = Decoder { data, pos: 0 };
+    decoder.expect(b'd')?;
+
+    let mut found = None;
+    while decoder.peek()? != b'e' {
+        let entry_key = decoder.bytes()?;
+        let start = decoder.pos;
+        decoder.value(1)?;
+        if entry_key == key.as_bytes() {
+            found = Some(&data[start..decoder.pos]);
+        }
+    }
+    decoder.pos += 1;
+    decoder.end()?;
+
+    Ok(found)
+}
+
+struct Decoder<'a> {
+    data: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> Decoder<'a> {
+    fn error(&self, reason: &str) -> ApiError {
+        ApiError::InvalidInput(format!("Invalid bencode at byte {}: {reason}", self.pos))
+    }
+
+    fn peek(&self) -> Result<u8, ApiError> {
+        self.data
+            .get(self.pos)
+            .copied()
+            .ok_or_else(|| self.error("unexpected end of data"))
+    }
+
+    fn expect(&mut self, byte: u8) -> Result<(), ApiError> {
+        if self.peek()? != byte {
+            return Err(self.error(&format!("expected {:?}", byte as char)));
+        }
+        self.pos += 1;
+        Ok(())
+    }
+
+    fn end(&self) -> Result<(), ApiError> {
+        if self.pos != self.data.len() {
+            return Err(self.error("trailing data"));
+        }
+        Ok(())
+    }
+
+    fn value(&mut self, depth: usize) -> Result<BencodeValue, ApiError> {
+        if depth > MAX_DEPTH {
+            return Err(self.error("nested too deeply"));
+        }
+
+        match self.peek()? {
+            b'i' => self.int().map(BencodeValue::Int),
+            b'0'..=b'9' => self.bytes().map(|b| BencodeValue::Bytes(b.to_vec())),
+            b'l' => {
+                self.pos += 1;
+                let mut list = Vec::new();
+                while self.peek()? != b'e' {
+                    list.push(self.value(depth + 1)?);
+                }
+                self.pos += 1;
+                Ok(BencodeValue::List(list))
+            }
+            b'd' => {
+                self.pos += 1;
+                let mut dict = BTreeMap::new();
+                while self.peek()? != b'e' {
+                    let key = self.bytes()?.to_vec();
+                    let value = self.value(depth + 1)?;
+                    dict.insert(key, value);
+                }
+                self.pos += 1;
+                Ok(BencodeValue::Dict(dict))
+            }
+            _ => Err(self.error("unknown value type")),
+        }
+    }
+
+    /// `i<digits>e`, without leading zeros or negative zero.
+    fn int(&mut self) -> Result<i64, ApiError> {
+        self.expect(b'i')?;
+        let digits = self.until(b'e')?;
+
+        let unsigned = digits.strip_prefix(b"-").unwrap_or(digits);
+        let canonical = !unsigned.is_empty()
+            && unsigned.iter().all(u8::is_ascii_digit)
+            && (unsigned == b"0" || unsigned[0] != b'0')
+            && digits != b"-0";
+        if !canonical {
+            return Err(self.error("invalid integer"));
+        }
+
+        std::str::from_utf8(digits)
+            .ok()
+            .and_then(|s| s.parse().ok())
+            .ok_or_else(|| self.error("integer out of range"))
+    }
+
+    /// `<length>:<bytes>`
+    fn bytes(&mut self) -> Result<&'a [u8], ApiError> {
+        let digits = self.until(b':')?;
+        if digits.is_empty() || !digits.iter().all(u8::is_ascii_digit) {
+            return Err(self.error("invalid string length"));
+        }
+
+        let len: usize = std::str::from_utf8(digits)
+            .ok()
+            .and_then(|s| s.parse().ok())
+            .ok_or_else(|| self.error("string length out of range"))?;
+        let end = self
+            .pos
+            .checked_add(len)
+            .filter(|end| *end <= self.data.len())
+            .ok_or_else(|| self.error("string longer than the data"))?;
+
+        let bytes = &self.data[self.pos..end];
+        self.pos = end;
+        Ok(bytes)
+    }
+
+    /// The bytes up to `delimiter`, which is consumed.
+    fn until(&mut self, delimiter: u8) -> Result<&'a [u8], ApiError> {
+        let start = self.pos;
+        let len = self.data[start..]
+            .iter()
+            .position(|b| *b == delimiter)
+            .ok_or_else(|| self.error("unexpected end of data"))?;
+
+        self.pos = start + len + 1;
+        Ok(&self.data[start..start + len])
+    }
+}
@@ -1,9 +1,17 @@
+use std::path::Path;
+
 use async_trait::async_trait;
 use reqwest::multipart::{Form, Part};
 use serde::Serialize;
-use torbox_core_rs::body::ToMultipart;
+use torbox_core_rs::{
+    body::ToMultipart, data::hash::InfoHash, error::ApiError, validation::validate_edit,
+};
 
-use crate::types::{TorrentControlSource, TorrentExportType, TorrentOperation, TorrentSource};
+use crate::{
+    magnet::MagnetLink,
+    metainfo::TorrentMetainfo,
+    types::{TorrentControlSource, TorrentOperation, TorrentSource},
+};
 
 /// Request body for retrieving torrent information from TorBox.
 ///
@@ -15,12 +23,12 @@ use crate::types::{TorrentControlSource, TorrentExportType, TorrentOperation, To
 /// At least one of these must be provided, or the request will be considered invalid.
 ///
 /// Use the [`try_new`](Self::try_new) constructor to safely build this struct.
-#[derive(Debug, Serialize)]
+#[derive(Debug, Clone, Serialize)]
 #[cfg_attr(feature = "specta", derive(specta::Type))]
 pub struct TorrentInfoBody {
     /// The torrent hash to search for (takes highest precedence).
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub hash: Option<String>,
+    pub hash: Option<InfoHash>,
 
     /// A magnet link, used if `hash` is not provided.
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -46,9 +54,11 @@ impl TorrentInfoBody {
     /// # Returns
     ///
     /// Returns `Some(Self)` if at least one of the three main fields is provided. Otherwise, returns `None`.
+    ///
+    /// The magnet is a parsed [`MagnetLink`], use `"magnet:?...".parse()` to build one from a string.
     pub fn try_new(
-        hash: Option<String>,
-        magnet: Option<String>,
+        hash: Option<InfoHash>,
+        magnet: Option<MagnetLink>,
         file: Option<String>,
         timeout: Option<u32>,
     ) -> Option<Self> {
@@ -61,7 +71,7 @@ impl TorrentInfoBody {
             }),
             (None, Some(magnet), _) => Some(Self {
                 hash: None,
-                magnet: Some(magnet),
+                magnet: Some(magnet.to_string()),
                 file: None,
                 timeout: timeout.unwrap_or(10),
             }),
@@ -82,7 +92,7 @@ impl ToMultipart for TorrentInfoBody {
         let mut form = Form::new();
 
         if let Some(hash) = self.hash {
-            form = form.text("hash", hash);
+            form = form.text("hash", hash.to_string());
         } else if let Some(magnet) = self.magnet {
             form = form.text("magnet", magnet);
         } else if let Some(file) = self.file {
@@ -95,7 +105,7 @@ impl ToMultipart for TorrentInfoBody {
     }
 }
 
-#[derive(Debug, Serialize)]
+#[derive(Debug, Clone, Serialize)]
 #[serde(rename_all = "snake_case")]
 #[cfg_attr(feature = "specta", derive(specta::Type))]
 pub struct TorrentCreateBody {
@@ -123,6 +133,46 @@ pub struct TorrentCreateBody {
 
     pub add_only_if_cached: Option<bool>,
 }
+
+impl TorrentCreateBody {
+    /// Parses a `.torrent` file and builds a body uploading it, returning its metainfo for a preview.
+    ///
+    /// A malformed file is reported here as `ApiError::InvalidInput` instead of a `BOZO_TORRENT` from TorBox.
+    pub fn from_torrent_bytes(bytes: Vec<u8>) -> Result<(Self, TorrentMetainfo), ApiError> {
+        let metainfo = TorrentMetainfo::parse(&bytes)?;
+        let body = Self {
+            source: TorrentSource::File(bytes),
+            seed: None,
+            allow_zip: true,
+            name: None,
+            as_queued: None,
+            add_only_if_cached: None,
+        };
+
+        Ok((body, metainfo))
+    }
+
+    /// A body adding the torrent of a magnet link, with the defaults of TorBox.
+    pub fn from_magnet(magnet: MagnetLink) -> Self {
+        Self {
+            source: magnet.into(),
+            seed: None,
+            allow_zip: true,
+            name: None,
+            as_queued: None,
+            add_only_if_cached: None,
+        }
+    }
+
+    /// Same as [`from_torrent_bytes`](Self::from_torrent_bytes), reading the file at `path`.
+    pub async fn from_torrent_file(
+        path: impl AsRef<Path>,
+    ) -> Result<(Self, TorrentMetainfo), ApiError> {
+        let bytes = tokio::fs::read(path).await?;
+        Self::from_torrent_bytes(bytes)
+    }
+}
+
 #[async_trait]
 impl ToMultipart for TorrentCreateBody {
     async fn to_multipart(self) -> Form {
@@ -173,3 +223,31 @@ pub struct TorrentControlBody {
     // the operation you want to perform on the torrent
     pub operation: TorrentOperation,
 }
+
+/// Request body of [`edit_torrent`](crate::TorrentApi::edit_torrent).
+///
+/// Fields left to `None` are not sent, the ones given overwrite the previous values entirely.
+#[derive(Debug, Serialize, Default, Clone)]
+#[cfg_attr(feature = "specta", derive(specta::Type))]
+pub struct TorrentEditBody {
+    /// The torrent's ID that you want to edit.
+    pub torrent_id: u32,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tags: Option<Vec<String>>,
+    /// MD5, SHA1 or SHA256 hashes the torrent can also be found with.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub alternative_hashes: Option<Vec<String>>,
+}
+
+impl TorrentEditBody {
+    /// Checks the name, tags and alternative hashes against the restrictions of the endpoint.
+    pub fn validate(&self) -> Result<(), ApiError> {
+        validate_edit(
+            self.name.as_deref(),
+            self.tags.as_deref(),
+            self.alternative_hashes.as_deref(),
+        )
+    }
+}
@@ -1,14 +1,18 @@
 use reqwest::Method;
-use torbox_core_rs::{client::EndpointSpec, data::torrent::TorrentStatus, enums::OneOrMany};
+use torbox_core_rs::{
+    client::EndpointSpec,
+    data::{cache::CacheAvailability, torrent::TorrentStatus},
+    enums::OneOrMany,
+};
 
 use crate::{
-    body::{TorrentControlBody, TorrentCreateBody, TorrentInfoBody},
+    body::{TorrentControlBody, TorrentCreateBody, TorrentEditBody, TorrentInfoBody},
     payload::{TorrentCreatePayload, TorrentInfoPayload},
     query::{
-        ListTorrentsQuery, TorrentExportDataQuery, TorrentInfoQuery, TorrentRequestLinkQuery,
-        TorrentStatusQuery,
+        ListTorrentsQuery, TorrentCachedAvailabilityQuery, TorrentExportDataQuery,
+        TorrentInfoQuery, TorrentRequestLinkQuery, TorrentStatusQuery,
     },
-    types::{TorrentDownloadResponse, TorrentExportResponse, TorrentSource},
+    types::TorrentDownloadResponse,
 };
 
 pub struct TorrentCreatePostEp;
@@ -82,3 +86,25 @@ impl EndpointSpec for TorrentExportDataGetEp {
     const PATH: &'static str = "api/torrents/exportdata";
     const METHOD: Method = Method::GET;
 }
+
+pub struct TorrentEditPutEp;
+
+impl EndpointSpec for TorrentEditPutEp {
+    type Req = TorrentEditBody;
+    type Resp = ();
+    const PATH: &'static str = "api/torrents/edittorrent";
+    const METHOD: Method = Method::PUT;
+}
+
+/// Takes in a list of comma separated torrent hashes and checks if the torrents are cached.
+///
+/// This endpoint only gets a max of around 100 at a time, due to http limits in queries.
+/// [`TorrentApi::check_cached_all`](crate::TorrentApi::check_cached_all) batches larger lists.
+pub struct TorrentCachedAvailabilityGetEp;
+
+impl EndpointSpec for TorrentCachedAvailabilityGetEp {
+    type Req = TorrentCachedAvailabilityQuery;
+    type Resp = Option<CacheAvailability>;
+    const PATH: &'static str = "api/torrents/checkcached";
+    const METHOD: Method = Method::GET;
+}
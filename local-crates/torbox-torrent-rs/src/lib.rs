@@ -1,30 +1,49 @@
+pub mod bencode;
 pub mod body;
 pub mod endpoint;
+pub mod magnet;
+pub mod metainfo;
 pub mod payload;
+pub mod permalink;
 pub mod query;
 pub mod tests;
 pub mod types;
 
+use std::{collections::HashMap, time::Duration};
+
+use futures::{Stream, StreamExt, stream};
 use torbox_core_rs::{
     api::ApiResponse,
-    client::{Endpoint, EndpointSpec, TorboxClient},
-    data::{creation::DownloadLinkResponse, torrent::TorrentStatus},
+    cache::{CacheCheck, check_cached_batched},
+    client::{Endpoint, TorboxClient},
+    data::{
+        cache::{CacheAvailability, CacheInfo},
+        creation::DownloadLinkResponse,
+        hash::InfoHash,
+        torrent::{TorrentDownloadState, TorrentStatus},
+    },
     enums::OneOrMany,
     error::ApiError,
+    link::LinkBuilder,
+    pagination::{Pagination, paginate},
 };
 
 use crate::{
-    body::{TorrentControlBody, TorrentCreateBody, TorrentInfoBody},
+    body::{TorrentControlBody, TorrentCreateBody, TorrentEditBody, TorrentInfoBody},
     endpoint::{
-        ListTorrentsGetEp, TorrentControlPostEp, TorrentCreatePostEp, TorrentExportDataGetEp,
-        TorrentInfoGetEp, TorrentInfoPostEp, TorrentRequestLinkGetEp, TorrentStatusGetEp,
+        ListTorrentsGetEp, TorrentCachedAvailabilityGetEp, TorrentControlPostEp,
+        TorrentCreatePostEp, TorrentEditPutEp, TorrentExportDataGetEp, TorrentInfoGetEp,
+        TorrentInfoPostEp, TorrentStatusGetEp,
     },
+    magnet::MagnetLink,
     payload::{TorrentCreatePayload, TorrentInfoPayload},
     query::{
-        ListTorrentsQuery, TorrentExportDataQuery, TorrentInfoQuery, TorrentRequestLinkQuery,
-        TorrentStatusQuery,
+        ListTorrentsQuery, TorrentCachedAvailabilityQuery, TorrentExportDataQuery,
+        TorrentInfoQuery, TorrentRequestLinkQuery, TorrentStatusQuery,
+    },
+    types::{
+        FormatType, TorrentExportResponse, TorrentExportType, TorrentSource, TorrentWatchEvent,
     },
-    types::{TorrentDownloadResponse, TorrentExportResponse, TorrentExportType, TorrentSource},
 };
 
 /// Main interface for TorBox torrent operations
@@ -44,7 +63,7 @@ impl<'a> TorrentApi<'a> {
     }
 
     #[allow(dead_code)]
-    pub(crate) fn token(&self) -> &str {
+    pub(crate) fn token(&self) -> Result<String, ApiError> {
         self.client.token()
     }
 
@@ -59,11 +78,16 @@ impl<'a> TorrentApi<'a> {
     ///
     /// # Returns
     ///
-    /// An `ApiResponse` with the torrent creation result
+    /// An `ApiResponse` with the torrent creation result, or `ApiError::InvalidInput` without any request
+    /// if the magnet link is invalid.
     pub async fn create_torrent(
         &self,
         body: TorrentCreateBody,
     ) -> Result<ApiResponse<TorrentCreatePayload>, ApiError> {
+        if let TorrentSource::Magnet(magnet) = &body.source {
+            MagnetLink::parse(magnet)?;
+        }
+
         Endpoint::<TorrentCreatePostEp>::new(self.client)
             .call_multipart(body)
             .await
@@ -95,6 +119,39 @@ impl<'a> TorrentApi<'a> {
         Ok(normalized)
     }
 
+    /// Streams every torrent of the user, requesting the list page by page.
+    ///
+    /// ```no_run
+    /// # use futures::StreamExt;
+    /// # use torbox_core_rs::pagination::Pagination;
+    /// # async fn run(api: torbox_torrent_rs::TorrentApi<'_>) {
+    /// let mut torrents = std::pin::pin!(api.list_all(Pagination::default().with_prefetch(2)));
+    /// while let Some(torrent) = torrents.next().await {
+    ///     println!("{:?}", torrent.map(|t| t.name));
+    /// }
+    /// # }
+    /// ```
+    pub fn list_all(
+        &self,
+        pagination: Pagination,
+    ) -> impl Stream<Item = Result<TorrentStatus, ApiError>> + use<'a> {
+        let client = self.client;
+
+        paginate(pagination, move |offset, limit| async move {
+            let query = ListTorrentsQuery {
+                id: None,
+                bypass_cache: Some(pagination.bypass_cache),
+                offset: Some(offset),
+                limit: Some(limit),
+            };
+            let page = TorrentApi::new(client)
+                .list_torrents_query(query)
+                .await?
+                .into_data()?;
+            Ok(page.unwrap_or_default())
+        })
+    }
+
     /// Gets detailed status for a specific torrent
     ///
     /// # Arguments
@@ -114,6 +171,70 @@ impl<'a> TorrentApi<'a> {
             .await
     }
 
+    /// Polls a torrent every `interval` and streams its progress.
+    ///
+    /// The first poll happens right away, and each one bypasses the list cache. Every poll yields a
+    /// [`TorrentWatchEvent::Progress`], preceded by a [`TorrentWatchEvent::StateChanged`] when the
+    /// `download_state` differs from the previous poll.
+    ///
+    /// The stream ends after the snapshot where `download_finished` is true or the torrent reached
+    /// `Error`/`Expired`, or right after yielding an error.
+    ///
+    /// ```no_run
+    /// # use std::time::Duration;
+    /// # use futures::StreamExt;
+    /// # async fn run(api: torbox_torrent_rs::TorrentApi<'_>) {
+    /// let mut events = std::pin::pin!(api.watch_torrent(42, Duration::from_secs(5)));
+    /// while let Some(event) = events.next().await {
+    ///     println!("{event:?}");
+    /// }
+    /// # }
+    /// ```
+    pub fn watch_torrent(
+        &self,
+        id: u32,
+        interval: Duration,
+    ) -> impl Stream<Item = Result<TorrentWatchEvent, ApiError>> + use<'a> {
+        let client = self.client;
+        let initial: Option<Option<TorrentDownloadState>> = Some(None);
+
+        stream::unfold(initial, move |previous| async move {
+            // `None` once the watch is over, `Some(None)` before the first poll.
+            let previous = previous?;
+
+            if previous.is_some() {
+                tokio::time::sleep(interval).await;
+            }
+
+            let api = TorrentApi::new(client);
+            let status = match api.status_query(true, id).await.and_then(|r| r.into_data()) {
+                Ok(Some(status)) => status,
+                Ok(None) => return Some((vec![Err(ApiError::UnexpectedPayload)], None)),
+                Err(err) => return Some((vec![Err(err)], None)),
+            };
+
+            let state = status.download_state.clone();
+            let finished = status.download_finished
+                || matches!(
+                    state,
+                    TorrentDownloadState::Error | TorrentDownloadState::Expired
+                );
+
+            let mut events = Vec::with_capacity(2);
+            if let Some(from) = previous.filter(|from| *from != state) {
+                events.push(Ok(TorrentWatchEvent::StateChanged {
+                    from,
+                    to: state.clone(),
+                }));
+            }
+            events.push(Ok(TorrentWatchEvent::Progress(Box::new(status))));
+
+            let next = if finished { None } else { Some(Some(state)) };
+            Some((events, next))
+        })
+        .flat_map(stream::iter)
+    }
+
     /// Fetches torrent metadata using a GET request with query parameters.
     ///
     /// This is a general-purpose route that takes a torrent `hash` and queries the BitTorrent network
@@ -140,7 +261,7 @@ impl<'a> TorrentApi<'a> {
     /// Unexpected JSON        → `ApiError::UnexpectedPayload`
     pub async fn info_query(
         &self,
-        hash: String,
+        hash: InfoHash,
         timeout: Option<u32>,
     ) -> Result<ApiResponse<TorrentInfoPayload>, ApiError> {
         Endpoint::<TorrentInfoGetEp>::new(self.client)
@@ -167,16 +288,76 @@ impl<'a> TorrentApi<'a> {
     ///
     /// # Returns
     ///
-    /// A deserialized `ApiResponse` containing metadata about the torrent.
+    /// A deserialized `ApiResponse` containing metadata about the torrent. An invalid magnet link is rejected
+    /// with `ApiError::InvalidInput` before the request is made.
     pub async fn info_body(
         &self,
         body: TorrentInfoBody,
     ) -> Result<ApiResponse<TorrentInfoPayload>, ApiError> {
+        if body.hash.is_none()
+            && let Some(magnet) = &body.magnet
+        {
+            MagnetLink::parse(magnet)?;
+        }
+
         Endpoint::<TorrentInfoPostEp>::new(self.client)
             .call_multipart(body)
             .await
     }
 
+    /// Checks whether the given torrent hashes are cached on TorBox.
+    ///
+    /// Only around 100 hashes are accepted per call, see [`TorrentCachedAvailabilityGetEp`], use
+    /// [`check_cached_all`](Self::check_cached_all) for more.
+    pub async fn check_cached(
+        &self,
+        query: TorrentCachedAvailabilityQuery,
+    ) -> Result<ApiResponse<Option<CacheAvailability>>, ApiError> {
+        Endpoint::<TorrentCachedAvailabilityGetEp>::new(self.client)
+            .call_query(query)
+            .await
+    }
+
+    /// Checks any number of torrent hashes, split into batches of up to 100 run concurrently.
+    ///
+    /// Returns the cached torrents keyed by hash, hashes missing from the map are not cached.
+    ///
+    /// ```no_run
+    /// # use torbox_core_rs::{cache::CacheCheck, data::hash::InfoHash};
+    /// # async fn run(api: torbox_torrent_rs::TorrentApi<'_>, hashes: Vec<InfoHash>) {
+    /// let cached = api
+    ///     .check_cached_all(hashes.clone(), CacheCheck::default().with_concurrency(2))
+    ///     .await
+    ///     .unwrap();
+    /// let missing = hashes.iter().filter(|hash| !cached.contains_key(*hash)).count();
+    /// # }
+    /// ```
+    pub async fn check_cached_all(
+        &self,
+        hashes: impl IntoIterator<Item = InfoHash>,
+        check: CacheCheck,
+    ) -> Result<HashMap<InfoHash, CacheInfo>, ApiError> {
+        let client = self.client;
+
+        check_cached_batched(hashes, check, move |hash| async move {
+            let query = TorrentCachedAvailabilityQuery {
+                hash,
+                format: FormatType::List,
+                list_files: Some(check.list_files),
+            };
+            TorrentApi::new(client)
+                .check_cached(query)
+                .await?
+                .into_data()
+        })
+        .await
+    }
+
+    /// Starts building download links or shareable permalinks for a torrent, see [`LinkBuilder`].
+    pub fn link_builder(&self, torrent_id: u32) -> LinkBuilder<'a> {
+        LinkBuilder::torrent(self.client, torrent_id)
+    }
+
     /// Requests a download link for a torrent
     ///
     /// Links are valid for 3 hours. Once downloading starts, the transfer
@@ -185,6 +366,9 @@ impl<'a> TorrentApi<'a> {
     ///
     /// Setting `redirect = true` truly helps torbox servers not to be overwhelmed.
     ///
+    /// Several `files_id` give several links, which this call rejects, get them with
+    /// [`link_builder`](Self::link_builder) instead.
+    ///
     /// # Arguments
     ///
     /// * `query` - Contains torrent ID and download options
@@ -196,31 +380,15 @@ impl<'a> TorrentApi<'a> {
         &self,
         query: TorrentRequestLinkQuery,
     ) -> Result<DownloadLinkResponse, ApiError> {
-        let endpoint = format!("{}/{}", self.client.base_url, TorrentRequestLinkGetEp::PATH);
-        let request = self.client.client.get(&endpoint).query(&query);
-
-        let response = request.send().await?;
-
-        if query.redirect {
-            if response.status().is_redirection() {
-                let location = response
-                    .headers()
-                    .get("Location")
-                    .ok_or(ApiError::RedirectError("Missing Location header".into()))?
-                    .to_str()
-                    .map_err(|_| ApiError::RedirectError("Invalid Location header".into()))?;
-
-                Ok(DownloadLinkResponse::Redirect(location.to_string()))
-            } else {
-                match response.json::<ApiResponse<String>>().await {
-                    Ok(json) => Ok(DownloadLinkResponse::Json(json)),
-                    Err(_) => Err(ApiError::UnexpectedPayload),
-                }
-            }
-        } else {
-            let json = response.json::<ApiResponse<String>>().await?;
-            Ok(DownloadLinkResponse::Json(json))
+        let mut builder = self.link_builder(query.torrent_id);
+        if !query.zip_link {
+            builder = builder.files(query.files_id.into_iter().flatten().map(u64::from));
+        }
+        if let Some(user_ip) = query.user_ip {
+            builder = builder.user_ip(user_ip);
         }
+
+        builder.request(query.redirect).await
     }
 
     /// Controls torrent state (start, stop, delete, etc.)
@@ -241,6 +409,21 @@ impl<'a> TorrentApi<'a> {
             .await
     }
 
+    /// Renames, tags or sets the alternative hashes of a torrent.
+    ///
+    /// The given fields overwrite the previous ones entirely. The torrent must be cached, and the edit does
+    /// not affect the cached database or any other user's torrent.
+    ///
+    /// The body is validated first (see [`TorrentEditBody::validate`]), an invalid one returns
+    /// `ApiError::InvalidInput` without sending the request.
+    pub async fn edit_torrent(&self, body: TorrentEditBody) -> Result<ApiResponse<()>, ApiError> {
+        body.validate()?;
+
+        Endpoint::<TorrentEditPutEp>::new(self.client)
+            .call_json(body)
+            .await
+    }
+
     /// Exports the magnet or torrent file.
     ///
     /// Requires a type to be passed. If type is magnet, it will return a JSON response with the magnet as a string in the data key.
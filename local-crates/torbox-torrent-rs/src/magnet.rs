@@ -0,0 +1,169 @@
+//! Typed magnet URIs, parsed and validated before they are sent to TorBox.
+
+use std::{fmt, str::FromStr};
+
+use torbox_core_rs::{data::hash::InfoHash, error::ApiError};
+
+const BTIH_PREFIX: &str = "urn:btih:";
+const BTMH_PREFIX: &str = "urn:btmh:";
+
+/// Multihash prefix of a SHA256 digest (`0x12`, 32 bytes), the only one BitTorrent v2 uses.
+const SHA256_MULTIHASH: &str = "1220";
+
+/// A parsed `magnet:?` URI.
+///
+/// Hashes are normalized to lowercase hex, so a base32 `btih` round-trips as hex.
+///
+/// ```
+/// # use torbox_torrent_rs::magnet::MagnetLink;
+/// let magnet: MagnetLink = "magnet:?xt=urn:btih:A492F8B92A25B0399C87715FC228C864AC5A7BFB&dn=archlinux"
+///     .parse()
+///     .unwrap();
+///
+/// assert_eq!(magnet.info_hash.unwrap(), "a492f8b92a25b0399c87715fc228c864ac5a7bfb");
+/// assert_eq!(magnet.name.as_deref(), Some("archlinux"));
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct MagnetLink {
+    /// v1 info hash (`xt=urn:btih:`).
+    pub info_hash: Option<InfoHash>,
+    /// v2 info hash multihash (`xt=urn:btmh:`), `1220` followed by 64 hex characters.
+    pub multihash: Option<String>,
+    /// Display name (`dn`).
+    pub name: Option<String>,
+    /// Tracker URLs (`tr`).
+    pub trackers: Vec<String>,
+    /// Exact length in bytes (`xl`).
+    pub exact_length: Option<u64>,
+    /// Web seeds (`ws`).
+    pub web_seeds: Vec<String>,
+}
+
+impl MagnetLink {
+    /// A magnet pointing to a v1 info hash, given in hex or base32.
+    pub fn from_info_hash(hash: &str) -> Result<Self, ApiError> {
+        Ok(Self {
+            info_hash: Some(normalize_btih(hash)?),
+            ..Default::default()
+        })
+    }
+
+    pub fn with_name(mut self, name: impl Into<String>) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+
+    pub fn with_tracker(mut self, tracker: impl Into<String>) -> Self {
+        self.trackers.push(tracker.into());
+        self
+    }
+
+    pub fn parse(uri: &str) -> Result<Self, ApiError> {
+        let query = uri
+            .strip_prefix("magnet:?")
+            .ok_or_else(|| invalid("it must start with \"magnet:?\""))?;
+        let params: Vec<(String, String)> =
+            serde_urlencoded::from_str(query).map_err(|e| invalid(&e.to_string()))?;
+
+        let mut magnet = Self::default();
+        for (key, value) in params {
+            // Some clients number repeated keys, e.g. `tr.1`, `tr.2`.
+            let key = key.split_once('.').map_or(key.as_str(), |(k, _)| k);
+
+            match key {
+                "xt" => {
+                    if let Some(hash) = value.strip_prefix(BTIH_PREFIX) {
+                        magnet.info_hash = Some(normalize_btih(hash)?);
+                    } else if let Some(hash) = value.strip_prefix(BTMH_PREFIX) {
+                        magnet.multihash = Some(normalize_btmh(hash)?);
+                    }
+                }
+                "dn" => magnet.name = Some(value),
+                "tr" => magnet.trackers.push(value),
+                "ws" => magnet.web_seeds.push(value),
+                "xl" => {
+                    let length = value
+                        .parse()
+                        .map_err(|_| invalid(&format!("xl {value:?} is not a length")))?;
+                    magnet.exact_length = Some(length);
+                }
+                _ => {}
+            }
+        }
+
+        if magnet.info_hash.is_none() && magnet.multihash.is_none() {
+            return Err(invalid("no urn:btih or urn:btmh exact topic"));
+        }
+
+        Ok(magnet)
+    }
+}
+
+impl FromStr for MagnetLink {
+    type Err = ApiError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse(s)
+    }
+}
+
+impl fmt::Display for MagnetLink {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut params = Vec::new();
+        if let Some(hash) = &self.info_hash {
+            params.push(format!("xt={BTIH_PREFIX}{hash}"));
+        }
+        if let Some(hash) = &self.multihash {
+            params.push(format!("xt={BTMH_PREFIX}{hash}"));
+        }
+        if let Some(name) = &self.name {
+            params.push(encode("dn", name));
+        }
+        if let Some(length) = self.exact_length {
+            params.push(format!("xl={length}"));
+        }
+        params.extend(self.trackers.iter().map(|tr| encode("tr", tr)));
+        params.extend(self.web_seeds.iter().map(|ws| encode("ws", ws)));
+
+        write!(f, "magnet:?{}", params.join("&"))
+    }
+}
+
+impl From<MagnetLink> for String {
+    fn from(magnet: MagnetLink) -> Self {
+        magnet.to_string()
+    }
+}
+
+fn invalid(reason: &str) -> ApiError {
+    ApiError::InvalidInput(format!("Invalid magnet link: {reason}"))
+}
+
+fn encode(key: &str, value: &str) -> String {
+    serde_urlencoded::to_string([(key, value)]).unwrap_or_default()
+}
+
+/// A `btih`, given as 40 hex or 32 base32 characters.
+fn normalize_btih(hash: &str) -> Result<InfoHash, ApiError> {
+    let hash = match hash.len() {
+        32 => InfoHash::from_base32(hash),
+        _ => InfoHash::new(hash),
+    };
+
+    hash.ok()
+        .filter(InfoHash::is_v1)
+        .ok_or_else(|| invalid("xt is not a v1 info hash"))
+}
+
+fn normalize_btmh(hash: &str) -> Result<String, ApiError> {
+    let hash = hash.to_ascii_lowercase();
+    let valid = hash.len() == SHA256_MULTIHASH.len() + 64
+        && hash.starts_with(SHA256_MULTIHASH)
+        && hash.chars().all(|c| c.is_ascii_hexdigit());
+
+    if valid {
+        Ok(hash)
+    } else {
+        Err(invalid(&format!("{hash:?} is not a SHA256 multihash")))
+    }
+}
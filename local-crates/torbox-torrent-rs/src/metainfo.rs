@@ -0,0 +1,241 @@
+//! Typed view of a `.torrent` file, decoded and checked locally before it is uploaded.
+
+use std::path::Path;
+
+use serde::Serialize;
+use sha1::{Digest, Sha1};
+use sha2::Sha256;
+use torbox_core_rs::{
+    data::hash::InfoHash,
+    error::{ApiError, ApiErrorCode, ErrorValue},
+};
+
+use crate::bencode::{self, BencodeValue};
+
+/// A file listed in a torrent.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[cfg_attr(feature = "specta", derive(specta::Type))]
+pub struct TorrentMetaFile {
+    /// Path inside the torrent, components separated by `/`.
+    pub path: String,
+    pub size: u64,
+}
+
+/// The metainfo of a `.torrent` file (BEP 3, and BEP 52 for v2 and hybrid torrents).
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[cfg_attr(feature = "specta", derive(specta::Type))]
+pub struct TorrentMetainfo {
+    pub name: String,
+    /// SHA1 of the info dictionary, for v1 and hybrid torrents.
+    pub info_hash_v1: Option<InfoHash>,
+    /// SHA256 of the info dictionary, for v2 and hybrid torrents.
+    pub info_hash_v2: Option<InfoHash>,
+    pub piece_length: u64,
+    /// Files of the torrent, padding files excluded.
+    pub files: Vec<TorrentMetaFile>,
+    /// Tracker URLs from `announce` and `announce-list`, without duplicates.
+    pub trackers: Vec<String>,
+    pub private: bool,
+}
+
+impl TorrentMetainfo {
+    /// Decodes and validates a `.torrent` file.
+    pub fn parse(data: &[u8]) -> Result<Self, ApiError> {
+        let root = bencode::decode(data)?;
+        let info_bytes = bencode::raw_dict_value(data, "info")?
+            .ok_or_else(|| invalid("missing info dictionary"))?;
+        let info = root
+            .get("info")
+            .filter(|info| info.as_dict().is_some())
+            .ok_or_else(|| invalid("info is not a dictionary"))?;
+
+        let name = text(info, "name.utf-8")
+            .or_else(|| text(info, "name"))
+            .filter(|name| !name.is_empty())
+            .ok_or_else(|| invalid("missing name"))?;
+
+        let piece_length = info
+            .get("piece length")
+            .and_then(BencodeValue::as_int)
+            .filter(|len| *len > 0)
+            .map(|len| len as u64)
+            .ok_or_else(|| invalid("missing or invalid piece length"))?;
+
+        let is_v2 = info.get("meta version").and_then(BencodeValue::as_int) == Some(2);
+        let pieces = info.get("pieces").and_then(BencodeValue::as_bytes);
+        let is_v1 = pieces.is_some();
+
+        if !is_v1 && !is_v2 {
+            return Err(invalid("neither v1 pieces nor a v2 meta version"));
+        }
+        if pieces.is_some_and(|p| p.len() % 20 != 0) {
+            return Err(invalid("pieces is not a multiple of 20 bytes"));
+        }
+
+        let files = if is_v1 {
+            v1_files(info, &name)?
+        } else {
+            let tree = info
+                .get("file tree")
+                .ok_or_else(|| invalid("missing file tree"))?;
+            let mut files = Vec::new();
+            v2_files(tree, &name, &mut files)?;
+            files
+        };
+        if files.is_empty() {
+            return Err(invalid("no files"));
+        }
+
+        Ok(Self {
+            name,
+            info_hash_v1: is_v1
+                .then(|| InfoHash::from_bytes(&Sha1::digest(info_bytes)))
+                .transpose()?,
+            info_hash_v2: is_v2
+                .then(|| InfoHash::from_bytes(&Sha256::digest(info_bytes)))
+                .transpose()?,
+            piece_length,
+            files,
+            trackers: trackers(&root),
+            private: info.get("private").and_then(BencodeValue::as_int) == Some(1),
+        })
+    }
+
+    /// Reads and parses a `.torrent` file.
+    pub async fn from_path(path: impl AsRef<Path>) -> Result<Self, ApiError> {
+        let data = tokio::fs::read(path).await?;
+        Self::parse(&data)
+    }
+
+    /// The hash TorBox identifies the torrent with, the v1 one for hybrid torrents.
+    pub fn info_hash(&self) -> &str {
+        self.info_hash_v1
+            .as_ref()
+            .or(self.info_hash_v2.as_ref())
+            .map_or("", InfoHash::as_str)
+    }
+
+    /// Sum of the size of every file.
+    pub fn total_size(&self) -> u64 {
+        self.files.iter().map(|f| f.size).sum()
+    }
+
+    /// Fails with the same `DOWNLOAD_TOO_LARGE` error as TorBox if the torrent is larger than
+    /// `max_size` bytes, the download size limit of your plan.
+    ///
+    /// The API does not expose plan limits, so they are left to the caller rather than hardcoded.
+    pub fn check_size_limit(&self, max_size: u64) -> Result<(), ApiError> {
+        if self.total_size() > max_size {
+            return Err(ApiError::Failure(ErrorValue::Code(
+                ApiErrorCode::DownloadTooLarge,
+            )));
+        }
+
+        Ok(())
+    }
+}
+
+fn invalid(reason: &str) -> ApiError {
+    ApiError::InvalidInput(format!("Invalid torrent file: {reason}"))
+}
+
+fn text(value: &BencodeValue, key: &str) -> Option<String> {
+    value.get(key).and_then(BencodeValue::as_str)
+}
+
+fn size(value: &BencodeValue) -> Result<u64, ApiError> {
+    value
+        .get("length")
+        .and_then(BencodeValue::as_int)
+        .filter(|len| *len >= 0)
+        .map(|len| len as u64)
+        .ok_or_else(|| invalid("missing or invalid file length"))
+}
+
+fn v1_files(info: &BencodeValue, name: &str) -> Result<Vec<TorrentMetaFile>, ApiError> {
+    let Some(entries) = info.get("files") else {
+        return Ok(vec![TorrentMetaFile {
+            path: name.to_string(),
+            size: size(info)?,
+        }]);
+    };
+
+    let entries = entries
+        .as_list()
+        .ok_or_else(|| invalid("files is not a list"))?;
+    let mut files = Vec::with_capacity(entries.len());
+
+    for entry in entries {
+        let is_padding = text(entry, "attr").is_some_and(|attr| attr.contains('p'));
+        if is_padding {
+            continue;
+        }
+
+        let components = entry
+            .get("path.utf-8")
+            .or_else(|| entry.get("path"))
+            .and_then(BencodeValue::as_list)
+            .filter(|path| !path.is_empty())
+            .ok_or_else(|| invalid("missing file path"))?;
+        let mut path = name.to_string();
+        for component in components {
+            let component = component
+                .as_str()
+                .ok_or_else(|| invalid("file path is not a list of strings"))?;
+            path.push('/');
+            path.push_str(&component);
+        }
+
+        files.push(TorrentMetaFile {
+            path,
+            size: size(entry)?,
+        });
+    }
+
+    Ok(files)
+}
+
+/// Walks a BEP 52 file tree, where a file is a dictionary whose only key is the empty string.
+fn v2_files(
+    node: &BencodeValue,
+    path: &str,
+    files: &mut Vec<TorrentMetaFile>,
+) -> Result<(), ApiError> {
+    let dict = node
+        .as_dict()
+        .ok_or_else(|| invalid("file tree entries must be dictionaries"))?;
+
+    for (key, child) in dict {
+        if key.is_empty() {
+            files.push(TorrentMetaFile {
+                path: path.to_string(),
+                size: size(child)?,
+            });
+        } else {
+            let child_path = format!("{path}/{}", String::from_utf8_lossy(key));
+            v2_files(child, &child_path, files)?;
+        }
+    }
+
+    Ok(())
+}
+
+fn trackers(root: &BencodeValue) -> Vec<String> {
+    let announce = text(root, "announce");
+    let tiers = root
+        .get("announce-list")
+        .and_then(BencodeValue::as_list)
+        .unwrap_or_default()
+        .iter()
+        .filter_map(BencodeValue::as_list)
+        .flatten()
+        .filter_map(BencodeValue::as_str);
+
+    let mut trackers: Vec<String> = Vec::new();
+    for tracker in announce.into_iter().chain(tiers) {
+        if !tracker.is_empty() && !trackers.contains(&tracker) {
+            trackers.push(tracker);
+        }
+    }
+    trackers
+}
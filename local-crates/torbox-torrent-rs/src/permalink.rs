@@ -0,0 +1,60 @@
+//! Permalinks and `.strm` trees for media servers, built without calling the API.
+
+use std::path::Path;
+
+use torbox_core_rs::{
+    client::TorboxClient,
+    data::torrent::TorrentStatus,
+    error::ApiError,
+    link::LinkBuilder,
+    strm::{self, StrmReport, StrmTree},
+};
+
+/// Builds the `requestdl` permalink of a torrent file, or of the torrent zip without `file_id`.
+///
+/// It redirects to a fresh CDN link whenever it is opened and carries the client's token, see
+/// [`LinkBuilder::permalink`].
+pub fn permalink(
+    client: &TorboxClient,
+    torrent_id: u32,
+    file_id: Option<u64>,
+) -> Result<String, ApiError> {
+    let builder = LinkBuilder::torrent(client, torrent_id);
+    match file_id {
+        Some(file_id) => builder.file(file_id).permalink(),
+        None => builder.permalink(),
+    }
+}
+
+/// A `.strm` file per file of every torrent, under a folder named after the torrent and mirroring
+/// the file paths.
+///
+/// Files without an id, as in torrent metadata, are skipped and listed in [`StrmTree::skipped`].
+pub fn strm_files(client: &TorboxClient, torrents: &[TorrentStatus]) -> Result<StrmTree, ApiError> {
+    strm::strm_files(client, torrents)
+}
+
+/// Writes the [`strm_files`] of `torrents` under `root`, reporting the paths written and the
+/// files skipped.
+///
+/// ```no_run
+/// # use futures::TryStreamExt;
+/// # use torbox_core_rs::client::TorboxClient;
+/// # use torbox_torrent_rs::{TorrentApi, permalink::write_torrents_strm};
+/// # async fn run(client: TorboxClient) -> Result<(), torbox_core_rs::error::ApiError> {
+/// let torrents: Vec<_> = TorrentApi::new(&client)
+///     .list_all(Default::default())
+///     .try_collect()
+///     .await?;
+/// let report = write_torrents_strm(&client, &torrents, "/media/torbox").await?;
+/// println!("{} written, {} skipped", report.written.len(), report.skipped.len());
+/// # Ok(())
+/// # }
+/// ```
+pub async fn write_torrents_strm(
+    client: &TorboxClient,
+    torrents: &[TorrentStatus],
+    root: impl AsRef<Path>,
+) -> Result<StrmReport, ApiError> {
+    strm::write_strm_sources(client, torrents, root).await
+}
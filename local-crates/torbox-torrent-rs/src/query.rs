@@ -1,6 +1,7 @@
 use serde::Serialize;
+use torbox_core_rs::data::hash::InfoHash;
 
-use crate::types::TorrentExportType;
+use crate::types::{FormatType, TorrentExportType};
 
 #[derive(Debug, Serialize)]
 #[cfg_attr(feature = "specta", derive(specta::Type))]
@@ -51,7 +52,7 @@ pub struct TorrentStatusQuery {
 #[cfg_attr(feature = "specta", derive(specta::Type))]
 pub struct TorrentInfoQuery {
     /// Hash of the torrent you want to get info for. This is required.
-    pub hash: String,
+    pub hash: InfoHash,
 
     /// The amount of time you want TorBox to search for the torrent on the Bittorrent network.
     ///
@@ -64,10 +65,6 @@ pub struct TorrentInfoQuery {
 #[derive(Debug, Serialize)]
 #[cfg_attr(feature = "specta", derive(specta::Type))]
 pub struct TorrentRequestLinkQuery {
-    /// Hidden and not shown by specta because token is needed for queries here :(
-    #[cfg_attr(feature = "specta", specta(skip))]
-    pub(crate) token: String,
-
     /// The torrent's ID that you want to download
     pub torrent_id: u32,
 
@@ -98,3 +95,32 @@ pub struct TorrentExportDataQuery {
     #[serde(rename = "type")]
     pub data_type: TorrentExportType,
 }
+
+#[derive(Debug, Serialize)]
+#[cfg_attr(feature = "specta", derive(specta::Type))]
+pub struct TorrentCachedAvailabilityQuery {
+    /// Hashes to check, sent comma separated. Only around 100 fit in one request.
+    #[serde(serialize_with = "serialize_comma_separated")]
+    pub hash: Vec<InfoHash>,
+    pub format: FormatType,
+    /// Also list the files of every cached torrent.
+    pub list_files: Option<bool>,
+}
+
+fn serialize_comma_separated<S>(vec: &[InfoHash], serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: serde::Serializer,
+{
+    let joined: Vec<&str> = vec.iter().map(InfoHash::as_str).collect();
+    serializer.serialize_str(&joined.join(","))
+}
+
+impl Default for TorrentCachedAvailabilityQuery {
+    fn default() -> Self {
+        Self {
+            hash: Vec::new(),
+            format: FormatType::List,
+            list_files: None,
+        }
+    }
+}
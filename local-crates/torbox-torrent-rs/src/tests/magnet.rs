@@ -0,0 +1,75 @@
+use torbox_core_rs::error::ApiError;
+
+use crate::{body::TorrentInfoBody, magnet::MagnetLink, types::TorrentSource};
+
+const HASH: &str = "a492f8b92a25b0399c87715fc228c864ac5a7bfb";
+
+#[test]
+fn test_magnet_parameters_are_parsed() {
+    let magnet: MagnetLink = "magnet:?xt=urn:btih:A492F8B92A25B0399C87715FC228C864AC5A7BFB&dn=Arch+Linux%202025&tr=udp%3A%2F%2Ftracker.example%3A6969&tr.1=http://b/announce&xl=1024&ws=https://seed.example/arch.iso"
+        .parse()
+        .unwrap();
+
+    assert_eq!(magnet.info_hash.unwrap(), HASH);
+    assert_eq!(magnet.name.as_deref(), Some("Arch Linux 2025"));
+    assert_eq!(
+        magnet.trackers,
+        ["udp://tracker.example:6969", "http://b/announce"]
+    );
+    assert_eq!(magnet.exact_length, Some(1024));
+    assert_eq!(magnet.web_seeds, ["https://seed.example/arch.iso"]);
+}
+
+#[test]
+fn test_base32_and_multihash_are_normalized() {
+    let btmh = format!("1220{}", "AB".repeat(32));
+    let magnet = MagnetLink::parse(&format!(
+        "magnet:?xt=urn:btih:USJPROJKEWYDTHEHOFP4EKGIMSWFU673&xt=urn:btmh:{btmh}"
+    ))
+    .unwrap();
+
+    assert_eq!(magnet.info_hash.unwrap(), HASH);
+    assert_eq!(magnet.multihash, Some(btmh.to_lowercase()));
+}
+
+#[test]
+fn test_magnet_round_trips() {
+    let magnet = MagnetLink::from_info_hash(HASH)
+        .unwrap()
+        .with_name("arch linux")
+        .with_tracker("udp://tracker.example:6969");
+
+    let uri = magnet.to_string();
+    assert!(uri.starts_with(&format!("magnet:?xt=urn:btih:{HASH}&dn=arch+linux")));
+    assert_eq!(MagnetLink::parse(&uri).unwrap(), magnet);
+}
+
+#[test]
+fn test_invalid_magnets_are_rejected() {
+    for bad in [
+        "https://example.com/file.torrent",
+        "magnet:?dn=no-topic",
+        "magnet:?xt=urn:btih:abc",
+        "magnet:?xt=urn:btmh:1220abc",
+        &format!("magnet:?xt=urn:btih:{HASH}&xl=big"),
+    ] {
+        assert!(
+            matches!(MagnetLink::parse(bad), Err(ApiError::InvalidInput(_))),
+            "{bad} should be rejected"
+        );
+    }
+}
+
+#[test]
+fn test_magnet_is_usable_in_bodies() {
+    let magnet = MagnetLink::from_info_hash(HASH).unwrap();
+
+    let source = TorrentSource::from(magnet.clone());
+    assert!(matches!(source, TorrentSource::Magnet(ref uri) if uri.contains(HASH)));
+
+    let body = TorrentInfoBody::try_new(None, Some(magnet), None, None).unwrap();
+    assert_eq!(
+        body.magnet.as_deref(),
+        Some(format!("magnet:?xt=urn:btih:{HASH}").as_str())
+    );
+}
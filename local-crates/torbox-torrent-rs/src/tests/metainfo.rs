@@ -0,0 +1,121 @@
+use torbox_core_rs::{
+    data::hash::InfoHash,
+    error::{ApiError, ApiErrorCode},
+};
+
+use crate::{
+    bencode::{self, BencodeValue},
+    body::TorrentCreateBody,
+    metainfo::TorrentMetainfo,
+    types::TorrentSource,
+};
+
+const SINGLE_INFO: &[u8] =
+    b"d6:lengthi1024e4:name10:ubuntu.iso12:piece lengthi16384e6:pieces20:aaaaaaaaaaaaaaaaaaaae";
+
+fn single_file_torrent() -> Vec<u8> {
+    [
+        b"d8:announce26:udp://tracker.example:69694:info".as_slice(),
+        SINGLE_INFO,
+        b"e",
+    ]
+    .concat()
+}
+
+#[test]
+fn test_bencode_rejects_non_canonical_input() {
+    assert_eq!(bencode::decode(b"i-42e").unwrap(), BencodeValue::Int(-42));
+    assert_eq!(
+        bencode::decode(b"l4:spami7ee").unwrap(),
+        BencodeValue::List(vec![
+            BencodeValue::Bytes(b"spam".to_vec()),
+            BencodeValue::Int(7)
+        ])
+    );
+
+    for bad in [
+        &b"i03e"[..],
+        b"i-0e",
+        b"ie",
+        b"5:abc",
+        b"d3:keye",
+        b"i1ei2e",
+        b"x",
+    ] {
+        assert!(
+            matches!(bencode::decode(bad), Err(ApiError::InvalidInput(_))),
+            "{:?} should be rejected",
+            String::from_utf8_lossy(bad)
+        );
+    }
+}
+
+#[test]
+fn test_single_file_torrent_is_parsed() {
+    let meta = TorrentMetainfo::parse(&single_file_torrent()).unwrap();
+
+    assert_eq!(meta.name, "ubuntu.iso");
+    assert_eq!(
+        meta.info_hash_v1.as_ref().map(InfoHash::as_str),
+        Some("9e0551c8fc096b2fe7227856a80014331fd39c7f")
+    );
+    assert_eq!(meta.info_hash_v2, None);
+    assert_eq!(meta.info_hash(), "9e0551c8fc096b2fe7227856a80014331fd39c7f");
+    assert_eq!(meta.piece_length, 16384);
+    assert_eq!(meta.total_size(), 1024);
+    assert_eq!(meta.trackers, ["udp://tracker.example:6969"]);
+    assert!(!meta.private);
+}
+
+#[test]
+fn test_multi_file_torrent_skips_padding_and_merges_trackers() {
+    let data = b"d8:announce9:http://a/13:announce-listll9:http://a/el9:http://b/ee4:infod5:filesld6:lengthi10e4:pathl3:dir5:a.txteed4:attr1:p6:lengthi6e4:pathl4:.pad1:0eed6:lengthi5e4:pathl5:b.txteee4:name4:pack12:piece lengthi16e6:pieces20:bbbbbbbbbbbbbbbbbbbb7:privatei1eee";
+    let meta = TorrentMetainfo::parse(data).unwrap();
+
+    let paths: Vec<_> = meta.files.iter().map(|f| f.path.as_str()).collect();
+    assert_eq!(paths, ["pack/dir/a.txt", "pack/b.txt"]);
+    assert_eq!(meta.total_size(), 15);
+    assert_eq!(meta.trackers, ["http://a/", "http://b/"]);
+    assert!(meta.private);
+}
+
+#[test]
+fn test_v2_torrent_uses_file_tree_and_sha256() {
+    let data = b"d4:infod9:file treed4:docsd5:a.txtd0:d6:lengthi3eeee5:b.txtd0:d6:lengthi4eeee12:meta versioni2e4:name2:v212:piece lengthi16384eee";
+    let meta = TorrentMetainfo::parse(data).unwrap();
+
+    assert_eq!(meta.info_hash_v1, None);
+    assert_eq!(
+        meta.info_hash_v2.as_ref().map(|h| h.as_str().len()),
+        Some(64)
+    );
+    let paths: Vec<_> = meta.files.iter().map(|f| f.path.as_str()).collect();
+    assert_eq!(paths, ["v2/b.txt", "v2/docs/a.txt"]);
+}
+
+#[test]
+fn test_invalid_torrents_are_rejected() {
+    let no_pieces = b"d4:infod6:lengthi1e4:name1:a12:piece lengthi16eee";
+    let bad_pieces = b"d4:infod6:lengthi1e4:name1:a12:piece lengthi16e6:pieces3:abcee";
+    let no_info = b"d8:announce4:nonee";
+
+    for data in [&no_pieces[..], bad_pieces, no_info, b"not a torrent"] {
+        assert!(matches!(
+            TorrentMetainfo::parse(data),
+            Err(ApiError::InvalidInput(_))
+        ));
+    }
+}
+
+#[test]
+fn test_create_body_from_torrent_checks_size_limit() {
+    let (body, meta) = TorrentCreateBody::from_torrent_bytes(single_file_torrent()).unwrap();
+
+    assert!(
+        matches!(body.source, TorrentSource::File(ref bytes) if *bytes == single_file_torrent())
+    );
+    assert!(meta.check_size_limit(meta.total_size()).is_ok());
+
+    let err = meta.check_size_limit(meta.total_size() - 1).unwrap_err();
+    assert_eq!(err.code(), Some(&ApiErrorCode::DownloadTooLarge));
+}
@@ -0,0 +1,658 @@
+use std::time::Duration;
+
+use futures::StreamExt;
+use serde_json::json;
+use torbox_core_rs::{
+    cache::CacheCheck,
+    client::TorboxClient,
+    data::{
+        cache::CacheAvailability, creation::DownloadLinkResponse, hash::InfoHash,
+        torrent::TorrentDownloadState,
+    },
+    error::{ApiError, ApiErrorCode},
+    network::{rate_limit::RateLimiter, retry::RetryPolicy},
+    pagination::Pagination,
+};
+use torbox_mock::{Injection, MockServer};
+
+use crate::{
+    TorrentApi,
+    body::{TorrentControlBody, TorrentCreateBody, TorrentEditBody, TorrentInfoBody},
+    metainfo::TorrentMetainfo,
+    payload::{TorrentCreatePayload, TorrentInfoPayload},
+    permalink::{permalink, write_torrents_strm},
+    query::{ListTorrentsQuery, TorrentCachedAvailabilityQuery, TorrentRequestLinkQuery},
+    types::{
+        FormatType, TorrentControlSource, TorrentExportResponse, TorrentExportType,
+        TorrentOperation, TorrentSource, TorrentWatchEvent,
+    },
+};
+
+fn magnet_body(magnet: &str) -> TorrentCreateBody {
+    TorrentCreateBody {
+        source: TorrentSource::Magnet(magnet.into()),
+        seed: None,
+        allow_zip: true,
+        name: None,
+        as_queued: None,
+        add_only_if_cached: None,
+    }
+}
+
+fn link_query(torrent_id: u32, redirect: bool) -> TorrentRequestLinkQuery {
+    TorrentRequestLinkQuery {
+        torrent_id,
+        files_id: None,
+        zip_link: true,
+        user_ip: None,
+        redirect,
+    }
+}
+
+#[tokio::test]
+async fn test_create_then_list_torrent() {
+    let server = MockServer::start().await;
+    let client = server.client();
+    let api = TorrentApi::new(&client);
+
+    let created = api
+        .create_torrent(magnet_body(
+            "magnet:?xt=urn:btih:A492F8B92A25B0399C87715FC228C864AC5A7BFB&dn=archlinux",
+        ))
+        .await
+        .unwrap();
+
+    let Some(TorrentCreatePayload::Created(created)) = created.data else {
+        panic!("Expected a created torrent, got {:?}", created.data);
+    };
+    assert_eq!(created.hash, "a492f8b92a25b0399c87715fc228c864ac5a7bfb");
+
+    let list = api
+        .list_torrents_query(ListTorrentsQuery::default())
+        .await
+        .unwrap()
+        .into_data()
+        .unwrap()
+        .unwrap();
+    assert_eq!(list.len(), 1);
+    assert_eq!(list[0].name, "archlinux");
+
+    let status = api
+        .status_query(false, created.id as u32)
+        .await
+        .unwrap()
+        .into_data()
+        .unwrap()
+        .unwrap();
+    assert_eq!(status.id, created.id);
+}
+
+#[tokio::test]
+async fn test_control_torrent_deletes_it() {
+    let server = MockServer::start().await;
+    let id = server
+        .state()
+        .add_torrent("ubuntu", &[("ubuntu.iso", 4096)]);
+    let client = server.client();
+    let api = TorrentApi::new(&client);
+
+    let resp = api
+        .control_torrent(TorrentControlBody {
+            source: TorrentControlSource::TorrentId(id as u32),
+            operation: TorrentOperation::Delete,
+        })
+        .await
+        .unwrap();
+
+    assert!(resp.success);
+    assert!(server.state().torrent(id).is_none());
+}
+
+#[tokio::test]
+async fn test_request_download_link_follows_redirect_setting() {
+    let server = MockServer::start().await;
+    let id = server
+        .state()
+        .add_torrent("ubuntu", &[("ubuntu.iso", 4096)]) as u32;
+    let client = server.client();
+    let api = TorrentApi::new(&client);
+
+    match api.request_download_link(link_query(id, true)).await {
+        Ok(DownloadLinkResponse::Redirect(url)) => assert!(url.ends_with("zip")),
+        other => panic!("Expected a redirect, got {other:?}"),
+    }
+
+    match api.request_download_link(link_query(id, false)).await {
+        Ok(DownloadLinkResponse::Json(json)) => assert!(json.data.unwrap().starts_with("http")),
+        other => panic!("Expected a JSON link, got {other:?}"),
+    }
+}
+
+#[tokio::test]
+async fn test_injected_error_is_reported() {
+    let server = MockServer::start().await;
+    server
+        .state()
+        .inject_error("api/torrents/createtorrent", ApiErrorCode::ActiveLimit);
+    let client = server.client().with_strict_errors(true);
+    let api = TorrentApi::new(&client);
+
+    let err = api
+        .create_torrent(magnet_body(
+            "magnet:?xt=urn:btih:6cad4a4671eb622b279619868342171cf5ec1045",
+        ))
+        .await
+        .unwrap_err();
+
+    assert_eq!(err.code(), Some(&ApiErrorCode::ActiveLimit));
+}
+
+#[tokio::test]
+async fn test_bad_token_is_rejected() {
+    let server = MockServer::start().await;
+    let client = TorboxClient::new("wrong".into())
+        .with_base_url(server.base_url())
+        .with_strict_errors(true);
+    let api = TorrentApi::new(&client);
+
+    let err = api
+        .list_torrents_query(ListTorrentsQuery::default())
+        .await
+        .unwrap_err();
+
+    assert_eq!(err.code(), Some(&ApiErrorCode::BadToken));
+}
+
+#[tokio::test]
+async fn test_transient_failures_are_retried() {
+    let server = MockServer::start().await;
+    let path = "api/torrents/mylist";
+    server.state().inject(
+        path,
+        Injection::Status {
+            status: 503,
+            retry_after: None,
+        },
+    );
+    server.state().inject(
+        path,
+        Injection::Status {
+            status: 429,
+            retry_after: Some(0),
+        },
+    );
+    let client = server.client().with_retry_policy(
+        RetryPolicy::default().with_backoff(Duration::from_millis(1), Duration::from_millis(1)),
+    );
+    let api = TorrentApi::new(&client);
+
+    let resp = api
+        .list_torrents_query(ListTorrentsQuery::default())
+        .await
+        .unwrap();
+
+    assert!(resp.success);
+    assert_eq!(server.state().request_count(path), 3);
+}
+
+#[tokio::test]
+async fn test_rate_limited_after_max_retries() {
+    let server = MockServer::start().await;
+    let path = "api/torrents/mylist";
+    for _ in 0..2 {
+        server.state().inject(
+            path,
+            Injection::Status {
+                status: 429,
+                retry_after: Some(0),
+            },
+        );
+    }
+    let client = server
+        .client()
+        .with_rate_limiter(RateLimiter::new().with_max_rate_limited_retries(1));
+    let api = TorrentApi::new(&client);
+
+    let err = api
+        .list_torrents_query(ListTorrentsQuery::default())
+        .await
+        .unwrap_err();
+
+    assert!(matches!(err, ApiError::RateLimited(Some(d)) if d.is_zero()));
+}
+
+#[tokio::test]
+async fn test_rate_limited_upload_waits_and_rebuilds_form() {
+    let server = MockServer::start().await;
+    let path = "api/torrents/createtorrent";
+    server.state().inject(
+        path,
+        Injection::Status {
+            status: 429,
+            retry_after: Some(1),
+        },
+    );
+    let client = server.client();
+    let api = TorrentApi::new(&client);
+
+    let started = std::time::Instant::now();
+    let created = api
+        .create_torrent(magnet_body(
+            "magnet:?xt=urn:btih:A492F8B92A25B0399C87715FC228C864AC5A7BFB&dn=archlinux",
+        ))
+        .await
+        .unwrap();
+
+    assert!(created.success);
+    assert!(started.elapsed() >= Duration::from_secs(1));
+    assert_eq!(server.state().request_count(path), 2);
+    let list = api
+        .list_torrents_query(ListTorrentsQuery::default())
+        .await
+        .unwrap()
+        .into_data()
+        .unwrap()
+        .unwrap();
+    assert_eq!(list[0].name, "archlinux");
+}
+
+#[tokio::test]
+async fn test_watch_torrent_streams_until_finished() {
+    let server = MockServer::start().await;
+    let id = server
+        .state()
+        .add_torrent("ubuntu", &[("ubuntu.iso", 4096)]);
+    server.state().script(
+        id,
+        [
+            json!({"download_state": "metaDL", "download_finished": false, "progress": 0.0}),
+            json!({"download_state": "downloading", "progress": 0.5}),
+            json!({"download_state": "downloading", "progress": 0.9}),
+            json!({"download_state": "cached", "download_finished": true, "progress": 1.0}),
+        ],
+    );
+    let client = server.client();
+    let api = TorrentApi::new(&client);
+
+    let events: Vec<TorrentWatchEvent> = api
+        .watch_torrent(id as u32, Duration::from_millis(1))
+        .map(Result::unwrap)
+        .collect()
+        .await;
+
+    let transitions: Vec<_> = events
+        .iter()
+        .filter_map(|event| match event {
+            TorrentWatchEvent::StateChanged { from, to } => Some((from.clone(), to.clone())),
+            TorrentWatchEvent::Progress(_) => None,
+        })
+        .collect();
+    assert_eq!(
+        transitions,
+        [
+            (
+                TorrentDownloadState::MetaDl,
+                TorrentDownloadState::Downloading
+            ),
+            (
+                TorrentDownloadState::Downloading,
+                TorrentDownloadState::Cached
+            ),
+        ]
+    );
+    assert_eq!(events.len(), 6);
+    assert!(matches!(events.last(), Some(TorrentWatchEvent::Progress(s)) if s.download_finished));
+}
+
+#[tokio::test]
+async fn test_watch_torrent_ends_on_error() {
+    let server = MockServer::start().await;
+    let client = server.client();
+    let api = TorrentApi::new(&client);
+
+    let events: Vec<_> = api
+        .watch_torrent(404, Duration::from_millis(1))
+        .collect()
+        .await;
+
+    assert_eq!(events.len(), 1);
+    assert!(events[0].is_err());
+}
+
+#[tokio::test]
+async fn test_list_all_walks_every_page() {
+    let server = MockServer::start().await;
+    for i in 0..5 {
+        server
+            .state()
+            .add_torrent(&format!("torrent-{i}"), &[("file.bin", 1024)]);
+    }
+    let client = server.client();
+    let api = TorrentApi::new(&client);
+
+    let names: Vec<String> = api
+        .list_all(Pagination::default().with_page_size(2))
+        .map(|torrent| torrent.unwrap().name)
+        .collect()
+        .await;
+
+    assert_eq!(names.len(), 5);
+    assert_eq!(names[4], "torrent-4");
+    assert_eq!(server.state().request_count("api/torrents/mylist"), 3);
+}
+
+#[tokio::test]
+async fn test_edit_torrent_renames_it() {
+    let server = MockServer::start().await;
+    let id = server
+        .state()
+        .add_torrent("ubuntu", &[("ubuntu.iso", 4096)]);
+    let client = server.client();
+    let api = TorrentApi::new(&client);
+
+    api.edit_torrent(TorrentEditBody {
+        torrent_id: id as u32,
+        name: Some("Ubuntu 24.04".into()),
+        ..Default::default()
+    })
+    .await
+    .unwrap();
+
+    assert_eq!(server.state().torrent(id).unwrap()["name"], "Ubuntu 24.04");
+
+    let err = api
+        .edit_torrent(TorrentEditBody {
+            torrent_id: id as u32,
+            alternative_hashes: Some(vec!["not-a-hash".into()]),
+            ..Default::default()
+        })
+        .await
+        .unwrap_err();
+    assert!(matches!(err, ApiError::InvalidInput(_)));
+}
+
+#[tokio::test]
+async fn test_invalid_magnet_is_rejected_before_request() {
+    let server = MockServer::start().await;
+    let client = server.client();
+    let api = TorrentApi::new(&client);
+
+    let err = api
+        .create_torrent(magnet_body("magnet:?xt=urn:btih:abc"))
+        .await
+        .unwrap_err();
+
+    assert!(matches!(err, ApiError::InvalidInput(_)));
+    assert_eq!(
+        server.state().request_count("api/torrents/createtorrent"),
+        0
+    );
+}
+
+#[tokio::test]
+async fn test_check_cached_all_batches_hashes() {
+    let server = MockServer::start().await;
+    for name in ["debian", "fedora", "ubuntu"] {
+        server.state().add_torrent(name, &[("image.iso", 4096)]);
+    }
+    let client = server.client();
+    let api = TorrentApi::new(&client);
+
+    let hashes: Vec<InfoHash> = (1..=250)
+        .map(|n| InfoHash::new(&format!("{n:040x}")).unwrap())
+        .collect();
+    let cached = api
+        .check_cached_all(hashes.clone(), CacheCheck::default().with_list_files(true))
+        .await
+        .unwrap();
+
+    assert_eq!(cached.len(), 3);
+    assert_eq!(cached[&hashes[2]].name, "ubuntu");
+    assert_eq!(cached[&hashes[2]].files[0].name, "image.iso");
+    assert_eq!(server.state().request_count("api/torrents/checkcached"), 3);
+}
+
+#[tokio::test]
+async fn test_check_cached_object_format() {
+    let server = MockServer::start().await;
+    server
+        .state()
+        .add_torrent("ubuntu", &[("ubuntu.iso", 4096)]);
+    let client = server.client();
+    let api = TorrentApi::new(&client);
+    let hash = InfoHash::new(&format!("{:040x}", 1)).unwrap();
+
+    let availability = api
+        .check_cached(TorrentCachedAvailabilityQuery {
+            hash: vec![hash.clone()],
+            format: FormatType::Object,
+            list_files: None,
+        })
+        .await
+        .unwrap()
+        .into_data()
+        .unwrap()
+        .unwrap();
+
+    assert!(matches!(availability, CacheAvailability::Object(_)));
+    assert_eq!(availability.into_map()[&hash].size, 4096);
+}
+
+#[tokio::test]
+async fn test_link_builder_requests_one_link_per_file_with_bearer_token() {
+    let server = MockServer::start().await;
+    let id = server
+        .state()
+        .add_torrent("ubuntu", &[("a.iso", 1), ("b.iso", 2)]) as u32;
+    let client = server.client();
+    let api = TorrentApi::new(&client);
+
+    let links = api.link_builder(id).files([0, 1]).links().await.unwrap();
+
+    assert_eq!(links.len(), 2);
+    assert!(links[0].ends_with(&format!("/{id}/0")));
+    assert!(links[1].ends_with(&format!("/{id}/1")));
+    for request in server.state().requests() {
+        let query = request.query.unwrap_or_default();
+        assert!(!query.contains("token="), "Token leaked in {query}");
+    }
+}
+
+#[tokio::test]
+async fn test_permalinks_are_built_offline() {
+    let server = MockServer::start().await;
+    let id = server
+        .state()
+        .add_torrent("ubuntu", &[("a.iso", 1), ("b.iso", 2)]) as u32;
+    let client = server.client();
+    let builder = TorrentApi::new(&client).link_builder(id).files([0, 1]);
+
+    let permalinks = builder.permalinks().unwrap();
+    assert_eq!(permalinks.len(), 2);
+    assert_eq!(
+        permalinks[1],
+        format!(
+            "{}/api/torrents/requestdl?token={}&torrent_id={id}&file_id=1&redirect=true",
+            server.base_url(),
+            server.token()
+        )
+    );
+    assert_eq!(server.state().request_count("api/torrents/requestdl"), 0);
+    assert!(matches!(
+        builder.permalink(),
+        Err(ApiError::InvalidInput(_))
+    ));
+
+    // Opening a permalink redirects to the CDN, without any header
+    let response = client.client.get(&permalinks[1]).send().await.unwrap();
+    assert!(response.status().is_redirection());
+    let location = response.headers()["location"].to_str().unwrap();
+    assert!(location.ends_with(&format!("/{id}/1")));
+}
+
+#[tokio::test]
+async fn test_request_download_link_rejects_several_files() {
+    let server = MockServer::start().await;
+    let id = server.state().add_torrent("ubuntu", &[("a.iso", 1)]) as u32;
+    let client = server.client();
+
+    let query = TorrentRequestLinkQuery {
+        files_id: Some(vec![0, 1]),
+        zip_link: false,
+        ..link_query(id, false)
+    };
+    let result = TorrentApi::new(&client).request_download_link(query).await;
+
+    assert!(matches!(result, Err(ApiError::InvalidInput(_))));
+    assert_eq!(server.state().request_count("api/torrents/requestdl"), 0);
+}
+
+#[tokio::test]
+async fn test_write_torrents_strm_mirrors_files() {
+    let server = MockServer::start().await;
+    let id = server
+        .state()
+        .add_torrent("Show", &[("Season 1/E01.mkv", 1), ("Season 1/E02.mkv", 2)]);
+    let client = server.client();
+    let mut torrents = TorrentApi::new(&client)
+        .list_torrents_query(ListTorrentsQuery::default())
+        .await
+        .unwrap()
+        .into_data()
+        .unwrap()
+        .unwrap();
+    let root = std::env::temp_dir().join(format!("torbox-torrent-strm-{}", std::process::id()));
+
+    let written = write_torrents_strm(&client, &torrents, &root)
+        .await
+        .unwrap()
+        .written;
+
+    assert_eq!(
+        written,
+        [
+            root.join("Show/Season 1/E01.mkv.strm"),
+            root.join("Show/Season 1/E02.mkv.strm")
+        ]
+    );
+    assert_eq!(
+        std::fs::read_to_string(&written[1]).unwrap().trim(),
+        permalink(&client, id as u32, Some(1)).unwrap()
+    );
+
+    // Files listed without an id are reported instead of failing the whole tree
+    torrents[0].files[0].id = None;
+    let report = write_torrents_strm(&client, &torrents, &root)
+        .await
+        .unwrap();
+    assert_eq!(report.written, written[1..]);
+    assert_eq!(report.skipped.len(), 1);
+    assert_eq!(report.skipped[0].download_id, id);
+    assert_eq!(report.skipped[0].name, torrents[0].files[0].name);
+    assert_eq!(server.state().request_count("api/torrents/requestdl"), 0);
+    std::fs::remove_dir_all(root).unwrap();
+}
+
+#[tokio::test]
+async fn test_uploads_of_same_size_get_different_hashes() {
+    let server = MockServer::start().await;
+    let client = server.client().with_strict_errors(true);
+    let api = TorrentApi::new(&client);
+
+    let mut hashes = Vec::new();
+    for bytes in [b"d4:name1:ae".to_vec(), b"d4:name1:be".to_vec()] {
+        let mut body = magnet_body("");
+        body.source = TorrentSource::File(bytes);
+        let Some(TorrentCreatePayload::Created(created)) =
+            api.create_torrent(body).await.unwrap().data
+        else {
+            panic!("Expected a created torrent");
+        };
+        hashes.push(created.hash);
+    }
+
+    assert_ne!(hashes[0], hashes[1]);
+    assert_eq!(
+        api.list_torrents_query(ListTorrentsQuery::default())
+            .await
+            .unwrap()
+            .into_data()
+            .unwrap()
+            .unwrap()
+            .len(),
+        2
+    );
+}
+
+#[tokio::test]
+async fn test_torrent_info_by_hash_and_magnet() {
+    let server = MockServer::start().await;
+    let client = server.client().with_strict_errors(true);
+    let api = TorrentApi::new(&client);
+    let id = server
+        .state()
+        .add_torrent("ubuntu", &[("ubuntu.iso", 4096), ("ubuntu.txt", 16)]);
+    let hash = InfoHash::new(
+        server.state().torrent(id).unwrap()["hash"]
+            .as_str()
+            .unwrap(),
+    )
+    .unwrap();
+
+    let Some(TorrentInfoPayload::Meta(meta)) =
+        api.info_query(hash.clone(), None).await.unwrap().data
+    else {
+        panic!("Expected the metadata of a stored torrent");
+    };
+    assert_eq!(meta.name, "ubuntu");
+    assert_eq!(meta.size, 4112);
+    assert_eq!(meta.files.len(), 2);
+
+    let magnet = format!("magnet:?xt=urn:btih:{hash}&dn=ubuntu")
+        .parse()
+        .unwrap();
+    let body = TorrentInfoBody::try_new(None, Some(magnet), None, None).unwrap();
+    let Some(TorrentInfoPayload::Meta(meta)) = api.info_body(body).await.unwrap().data else {
+        panic!("Expected the metadata of a stored torrent");
+    };
+    assert_eq!(meta.hash, hash);
+
+    let unknown = InfoHash::new(&"f".repeat(40)).unwrap();
+    let info = api.info_query(unknown, Some(1)).await.unwrap();
+    assert!(!matches!(info.data, Some(TorrentInfoPayload::Meta(_))));
+}
+
+#[tokio::test]
+async fn test_export_magnet_and_torrent_file() {
+    let server = MockServer::start().await;
+    let client = server.client().with_strict_errors(true);
+    let api = TorrentApi::new(&client);
+    let id = server
+        .state()
+        .add_torrent("ubuntu server", &[("ubuntu.iso", 4096)]) as u32;
+
+    let magnet = api
+        .export_data_query(id, TorrentExportType::Magnet)
+        .await
+        .unwrap()
+        .magnet_link()
+        .unwrap();
+    assert_eq!(magnet.name.as_deref(), Some("ubuntu server"));
+
+    let TorrentExportResponse::File(bytes) = api
+        .export_data_query(id, TorrentExportType::File)
+        .await
+        .unwrap()
+    else {
+        panic!("Expected a torrent file");
+    };
+    let metainfo = TorrentMetainfo::parse(&bytes).unwrap();
+    assert_eq!(metainfo.name, "ubuntu server");
+    assert_eq!(metainfo.total_size(), 4096);
+
+    let err = api
+        .export_data_query(id + 1, TorrentExportType::Magnet)
+        .await
+        .unwrap_err();
+    assert_eq!(err.code(), Some(&ApiErrorCode::ItemNotFound));
+}
@@ -1,3 +1,10 @@
+#[cfg(test)]
+mod magnet;
+#[cfg(test)]
+mod metainfo;
+#[cfg(test)]
+mod mock;
+
 #[cfg(test)]
 pub mod torrent_test {
     use std::env;
@@ -5,15 +12,15 @@ pub mod torrent_test {
     use crate::{
         TorrentApi,
         body::{TorrentControlBody, TorrentCreateBody, TorrentInfoBody},
-        query::{
-            ListTorrentsQuery, TorrentExportDataQuery, TorrentInfoQuery, TorrentRequestLinkQuery,
-            TorrentStatusQuery,
-        },
-        types::{TorrentDownloadResponse, TorrentExportResponse, TorrentExportType, TorrentSource},
+        query::{ListTorrentsQuery, TorrentRequestLinkQuery},
+        types::{TorrentExportResponse, TorrentExportType, TorrentSource},
     };
 
     use dotenvy::from_filename;
-    use torbox_core_rs::{client::TorboxClient, data::creation::DownloadLinkResponse};
+    use torbox_core_rs::{
+        client::TorboxClient,
+        data::{creation::DownloadLinkResponse, hash::InfoHash},
+    };
 
     pub fn load_token_from_file() -> Option<String> {
         if let Err(err) = from_filename(".token") {
@@ -40,7 +47,7 @@ pub mod torrent_test {
             .id as u32
     }
 
-    async fn get_first_torrent_hash() -> String {
+    async fn get_first_torrent_hash() -> InfoHash {
         let client = test_client();
         let api = TorrentApi::new(&client);
 
@@ -179,7 +186,6 @@ pub mod torrent_test {
         let api = TorrentApi::new(&client);
 
         let query = TorrentRequestLinkQuery {
-            token: api.token().to_string(),
             torrent_id: get_first_torrent_id().await,
             files_id: None,
             zip_link: true,
@@ -1,9 +1,15 @@
-use std::path::PathBuf;
-
+use base64::Engine;
 use serde::{Deserialize, Serialize};
-use torbox_core_rs::{api::ApiResponse, error::ApiError, traits::FromBytes};
+use torbox_core_rs::{
+    api::ApiResponse,
+    data::torrent::{TorrentDownloadState, TorrentStatus},
+    error::ApiError,
+    traits::FromBytes,
+};
 
-#[derive(Debug, Serialize)]
+use crate::magnet::MagnetLink;
+
+#[derive(Debug, Clone, Serialize)]
 #[serde(rename_all = "snake_case")]
 #[cfg_attr(feature = "specta", derive(specta::Type))]
 pub enum TorrentSource {
@@ -22,13 +28,20 @@ impl<'de> serde::Deserialize<'de> for TorrentSource {
             Ok(TorrentSource::Magnet(val))
         } else {
             // if it's not a magnet, treat it as base64-encoded file bytes
-            let bytes = base64::decode(&val)
+            let bytes = base64::engine::general_purpose::STANDARD
+                .decode(&val)
                 .map_err(|e| serde::de::Error::custom(format!("Invalid base64: {}", e)))?;
             Ok(TorrentSource::File(bytes))
         }
     }
 }
 
+impl From<MagnetLink> for TorrentSource {
+    fn from(magnet: MagnetLink) -> Self {
+        TorrentSource::Magnet(magnet.to_string())
+    }
+}
+
 #[derive(Debug, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
 #[cfg_attr(feature = "specta", derive(specta::Type))]
@@ -66,6 +79,20 @@ pub enum TorrentExportResponse {
     File(Vec<u8>),
 }
 
+impl TorrentExportResponse {
+    /// Parses the magnet of a `TorrentExportType::Magnet` export.
+    pub fn magnet_link(&self) -> Result<MagnetLink, ApiError> {
+        match self {
+            TorrentExportResponse::Json(json) => json
+                .data
+                .as_deref()
+                .ok_or(ApiError::UnexpectedPayload)?
+                .parse(),
+            TorrentExportResponse::File(_) => Err(ApiError::UnexpectedPayload),
+        }
+    }
+}
+
 impl FromBytes for TorrentExportResponse {
     fn from_bytes(bytes: Vec<u8>) -> Result<Self, ApiError> {
         if let Ok(json) = serde_json::from_slice::<ApiResponse<String>>(&bytes) {
@@ -83,3 +110,26 @@ pub enum TorrentExportType {
     Magnet,
     File,
 }
+
+/// Event emitted by [`TorrentApi::watch_torrent`](crate::TorrentApi::watch_torrent).
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+#[cfg_attr(feature = "specta", derive(specta::Type))]
+pub enum TorrentWatchEvent {
+    /// The `download_state` changed since the previous poll, always emitted before the matching `Progress`.
+    StateChanged {
+        from: TorrentDownloadState,
+        to: TorrentDownloadState,
+    },
+    /// Latest status of the torrent, emitted on every poll.
+    Progress(Box<TorrentStatus>),
+}
+
+/// Shape of the `checkcached` data, see [`CacheAvailability`](torbox_core_rs::data::cache::CacheAvailability).
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "snake_case")]
+#[cfg_attr(feature = "specta", derive(specta::Type))]
+pub enum FormatType {
+    Object,
+    List,
+}
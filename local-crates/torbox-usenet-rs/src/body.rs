@@ -0,0 +1,87 @@
+use async_trait::async_trait;
+use reqwest::multipart::{Form, Part};
+use serde::Serialize;
+use torbox_core_rs::body::ToMultipart;
+
+use crate::types::{UsenetControlSource, UsenetOperation, UsenetSource};
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "snake_case")]
+#[cfg_attr(feature = "specta", derive(specta::Type))]
+pub struct UsenetCreateBody {
+    #[serde(flatten)]
+    pub source: UsenetSource,
+
+    /// The name you want the usenet download to be. Optional.
+    pub name: Option<String>,
+
+    /// The password of the NZB archive, if it needs one. Optional.
+    pub password: Option<String>,
+
+    /// Tells TorBox which post processing to apply once the download is done.
+    /// -1 is auto. 0 is none. 1 is repair. 2 is repair and unpack. 3 is repair, unpack and delete.
+    ///
+    /// Optional. Default is -1, or whatever the user has in their settings.
+    pub post_processing: Option<i8>,
+
+    /// Tells TorBox you want this usenet download instantly queued.
+    ///
+    /// This is **bypassed** if user is on free plan, and will process the request as normal in this case. Optional.
+    pub as_queued: Option<bool>,
+
+    /// Only adds the download if it is cached on TorBox. If not cached, it won't be added.
+    pub add_only_if_cached: Option<bool>,
+}
+
+#[async_trait]
+impl ToMultipart for UsenetCreateBody {
+    async fn to_multipart(self) -> Form {
+        let mut form = Form::new();
+
+        match self.source {
+            UsenetSource::Link(link) => {
+                form = form.text("link", link);
+            }
+            UsenetSource::File(bytes) => {
+                let part = Part::bytes(bytes)
+                    .file_name("usenet_file.nzb")
+                    .mime_str("application/x-nzb")
+                    .unwrap();
+
+                form = form.part("file", part);
+            }
+        }
+
+        if let Some(name) = self.name {
+            form = form.text("name", name);
+        }
+
+        if let Some(password) = self.password {
+            form = form.text("password", password);
+        }
+
+        if let Some(post_processing) = self.post_processing {
+            form = form.text("post_processing", post_processing.to_string());
+        }
+
+        if let Some(queued) = self.as_queued {
+            form = form.text("as_queued", queued.to_string());
+        }
+
+        if let Some(add) = self.add_only_if_cached {
+            form = form.text("add_only_if_cached", add.to_string());
+        }
+
+        form
+    }
+}
+
+#[derive(Debug, Serialize)]
+#[cfg_attr(feature = "specta", derive(specta::Type))]
+pub struct UsenetControlBody {
+    // Usenet ID or All
+    #[serde(flatten)]
+    pub source: UsenetControlSource,
+    // the operation you want to perform on the usenet download
+    pub operation: UsenetOperation,
+}
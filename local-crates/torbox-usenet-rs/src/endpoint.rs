@@ -0,0 +1,72 @@
+use reqwest::Method;
+use torbox_core_rs::{
+    client::EndpointSpec,
+    data::{cache::CacheAvailability, creation::DownloadLinkResponse, usenet::UsenetStatus},
+    enums::OneOrMany,
+};
+
+use crate::{
+    body::{UsenetControlBody, UsenetCreateBody},
+    payload::UsenetCreatePayload,
+    query::{
+        ListUsenetQuery, UsenetCachedAvailabilityQuery, UsenetRequestLinkQuery, UsenetStatusQuery,
+    },
+};
+
+pub struct UsenetCreatePostEp;
+
+impl EndpointSpec for UsenetCreatePostEp {
+    type Req = UsenetCreateBody;
+    type Resp = UsenetCreatePayload;
+    const PATH: &'static str = "api/usenet/createusenetdownload";
+    const METHOD: Method = Method::POST;
+}
+
+pub struct ListUsenetGetEp;
+
+impl EndpointSpec for ListUsenetGetEp {
+    type Req = ListUsenetQuery;
+    type Resp = Option<OneOrMany<UsenetStatus>>;
+    const PATH: &'static str = "api/usenet/mylist";
+    const METHOD: Method = Method::GET;
+}
+
+pub struct UsenetStatusGetEp;
+
+impl EndpointSpec for UsenetStatusGetEp {
+    type Req = UsenetStatusQuery;
+    type Resp = Option<UsenetStatus>;
+    const PATH: &'static str = "api/usenet/mylist";
+    const METHOD: Method = Method::GET;
+}
+
+pub struct UsenetControlPostEp;
+
+impl EndpointSpec for UsenetControlPostEp {
+    type Req = UsenetControlBody;
+    type Resp = ();
+    const PATH: &'static str = "api/usenet/controlusenetdownload";
+    const METHOD: Method = Method::POST;
+}
+
+pub struct UsenetRequestLinkGetEp;
+
+impl EndpointSpec for UsenetRequestLinkGetEp {
+    type Req = UsenetRequestLinkQuery;
+    type Resp = DownloadLinkResponse;
+    const PATH: &'static str = "api/usenet/requestdl";
+    const METHOD: Method = Method::GET;
+}
+
+/// Takes in a list of comma separated usenet hashes and checks if the usenet download is cached.
+///
+/// This endpoint only gets a max of around 100 at a time, due to http limits in queries.
+/// [`UsenetApi::check_cached_all`](crate::UsenetApi::check_cached_all) batches larger lists.
+pub struct UsenetCachedAvailabilityGetEp;
+
+impl EndpointSpec for UsenetCachedAvailabilityGetEp {
+    type Req = UsenetCachedAvailabilityQuery;
+    type Resp = Option<CacheAvailability>;
+    const PATH: &'static str = "api/usenet/checkcached";
+    const METHOD: Method = Method::GET;
+}
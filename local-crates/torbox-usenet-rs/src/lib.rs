@@ -0,0 +1,212 @@
+pub mod body;
+pub mod endpoint;
+pub mod payload;
+pub mod query;
+pub mod tests;
+pub mod types;
+
+use std::collections::HashMap;
+
+use torbox_core_rs::{
+    api::ApiResponse,
+    cache::{CacheCheck, check_cached_batched},
+    client::{Endpoint, TorboxClient},
+    data::{
+        cache::{CacheAvailability, CacheInfo},
+        creation::DownloadLinkResponse,
+        hash::InfoHash,
+        usenet::UsenetStatus,
+    },
+    enums::OneOrMany,
+    error::ApiError,
+    link::LinkBuilder,
+};
+
+use crate::{
+    body::{UsenetControlBody, UsenetCreateBody},
+    endpoint::{
+        ListUsenetGetEp, UsenetCachedAvailabilityGetEp, UsenetControlPostEp, UsenetCreatePostEp,
+        UsenetStatusGetEp,
+    },
+    payload::UsenetCreatePayload,
+    query::{
+        ListUsenetQuery, UsenetCachedAvailabilityQuery, UsenetRequestLinkQuery, UsenetStatusQuery,
+    },
+    types::FormatType,
+};
+
+/// Main interface for TorBox usenet operations
+///
+/// Provides methods for all usenet-related API calls including:
+/// - Usenet download creation (NZB file or link) and management
+/// - Status retrieval
+/// - Cache availability checks
+/// - Download link generation
+#[cfg_attr(feature = "specta", derive(specta::Type))]
+pub struct UsenetApi<'a> {
+    client: &'a TorboxClient,
+}
+
+impl<'a> UsenetApi<'a> {
+    pub fn new(client: &'a TorboxClient) -> Self {
+        Self { client }
+    }
+
+    /// Creates a usenet download under your account
+    ///
+    /// Accepts either an NZB file or a link to one. The download will begin once verified,
+    /// assuming your account has available download slots.
+    ///
+    /// # Arguments
+    ///
+    /// * `body` - Contains either an NZB file or link with optional parameters
+    ///
+    /// # Returns
+    ///
+    /// An `ApiResponse` with the usenet download creation result
+    pub async fn create_usenet(
+        &self,
+        body: UsenetCreateBody,
+    ) -> Result<ApiResponse<UsenetCreatePayload>, ApiError> {
+        Endpoint::<UsenetCreatePostEp>::new(self.client)
+            .call_multipart(body)
+            .await
+    }
+
+    /// Gets the user's usenet download list. This gives you the needed information to perform other usenet actions.
+    ///
+    /// This information only gets updated every 600 seconds, unless `bypass_cache` is set.
+    ///
+    /// # Returns
+    ///
+    /// A deserialized `ApiResponse` containing the list of usenet downloads.
+    pub async fn list_usenet_query(
+        &self,
+        query: ListUsenetQuery,
+    ) -> Result<ApiResponse<Option<Vec<UsenetStatus>>>, ApiError> {
+        let resp: ApiResponse<Option<OneOrMany<UsenetStatus>>> =
+            Endpoint::<ListUsenetGetEp>::new(self.client)
+                .call_query(query)
+                .await?;
+
+        let normalized = resp.map(|opt| {
+            opt.map(|one_or_many| match one_or_many {
+                OneOrMany::One(item) => vec![item],
+                OneOrMany::Many(list) => list,
+            })
+        });
+
+        Ok(normalized)
+    }
+
+    /// Gets detailed status for a specific usenet download
+    ///
+    /// # Arguments
+    ///
+    /// * `bypass_cache` - Whether to skip the cached data and get fresh information
+    /// * `id` - The usenet download's ID
+    ///
+    /// # Returns
+    ///
+    /// An `ApiResponse` with the usenet download's current status
+    pub async fn status_query(
+        &self,
+        bypass_cache: bool,
+        id: u64,
+    ) -> Result<ApiResponse<Option<UsenetStatus>>, ApiError> {
+        Endpoint::<UsenetStatusGetEp>::new(self.client)
+            .call_query(UsenetStatusQuery { bypass_cache, id })
+            .await
+    }
+
+    /// Controls usenet download state (pause, resume, delete)
+    ///
+    /// # Arguments
+    ///
+    /// * `body` - Contains the usenet source and operation to perform
+    ///
+    /// # Returns
+    ///
+    /// An empty `ApiResponse` on success
+    pub async fn control_usenet(
+        &self,
+        body: UsenetControlBody,
+    ) -> Result<ApiResponse<()>, ApiError> {
+        Endpoint::<UsenetControlPostEp>::new(self.client)
+            .call_json(body)
+            .await
+    }
+
+    /// Starts building download links or shareable permalinks for a usenet download, see [`LinkBuilder`].
+    pub fn link_builder(&self, usenet_id: u32) -> LinkBuilder<'a> {
+        LinkBuilder::usenet(self.client, usenet_id)
+    }
+
+    /// Requests a download link for a usenet download
+    ///
+    /// Links are valid for 3 hours. Once downloading starts, the transfer
+    /// can continue indefinitely. Permalinks can and should be created by setting
+    /// `redirect=true`.
+    ///
+    /// # Arguments
+    ///
+    /// * `query` - Contains usenet ID and download options
+    ///
+    /// # Returns
+    ///
+    /// Either a JSON response or redirect URL
+    pub async fn request_download_link(
+        &self,
+        query: UsenetRequestLinkQuery,
+    ) -> Result<DownloadLinkResponse, ApiError> {
+        let usenet_id = u32::try_from(query.usenet_id).map_err(|_| {
+            ApiError::InvalidInput(format!("Usenet id {} is too large", query.usenet_id))
+        })?;
+        let mut builder = self.link_builder(usenet_id);
+        if let (false, Some(file_id)) = (query.zip_link, query.file_id) {
+            builder = builder.file(file_id);
+        }
+        if let Some(user_ip) = query.user_ip {
+            builder = builder.user_ip(user_ip);
+        }
+
+        builder.request(query.redirect).await
+    }
+
+    /// Checks whether the given NZB hashes are cached on TorBox.
+    ///
+    /// Only around 100 hashes are accepted per call, see [`UsenetCachedAvailabilityGetEp`], use
+    /// [`check_cached_all`](Self::check_cached_all) for more.
+    pub async fn check_cached(
+        &self,
+        query: UsenetCachedAvailabilityQuery,
+    ) -> Result<ApiResponse<Option<CacheAvailability>>, ApiError> {
+        Endpoint::<UsenetCachedAvailabilityGetEp>::new(self.client)
+            .call_query(query)
+            .await
+    }
+
+    /// Checks any number of NZB hashes, split into batches of up to 100 run concurrently.
+    ///
+    /// Returns the cached downloads keyed by hash, hashes missing from the map are not cached.
+    pub async fn check_cached_all(
+        &self,
+        hashes: impl IntoIterator<Item = InfoHash>,
+        check: CacheCheck,
+    ) -> Result<HashMap<InfoHash, CacheInfo>, ApiError> {
+        let client = self.client;
+
+        check_cached_batched(hashes, check, move |hash| async move {
+            let query = UsenetCachedAvailabilityQuery {
+                hash,
+                format: FormatType::List,
+                list_files: Some(check.list_files),
+            };
+            UsenetApi::new(client)
+                .check_cached(query)
+                .await?
+                .into_data()
+        })
+        .await
+    }
+}
@@ -0,0 +1,16 @@
+use serde::Deserialize;
+use torbox_core_rs::data::{
+    limits::{ActiveLimitStatus, CooldownLimitStatus, MonthlyLimitStatus},
+    usenet::UsenetCreationResponse,
+};
+
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+pub enum UsenetCreatePayload {
+    Created(UsenetCreationResponse),
+    ActiveLimit(ActiveLimitStatus),
+    MonthlyLimit(MonthlyLimitStatus),
+    CooldownLimit(CooldownLimitStatus),
+    Message(String),
+    Empty,
+}
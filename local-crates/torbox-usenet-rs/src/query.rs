@@ -0,0 +1,100 @@
+use serde::Serialize;
+use torbox_core_rs::data::hash::InfoHash;
+
+use crate::types::FormatType;
+
+#[derive(Debug, Serialize)]
+#[cfg_attr(feature = "specta", derive(specta::Type))]
+pub struct ListUsenetQuery {
+    pub id: Option<u64>,
+    /// Allows you to bypass the cached data, and always get fresh information.
+    ///
+    /// Useful if constantly querying for fresh download stats.
+    /// Otherwise, we request that you save our database a few calls.
+    pub bypass_cache: Option<bool>,
+
+    /// Determines the offset of items to get from the database.
+    ///
+    /// Default is 0. Optional.
+    pub offset: Option<u32>,
+
+    /// Determines the number of items to recieve per request.
+    ///
+    /// Default is 1000. Optional.
+    pub limit: Option<u32>,
+}
+
+impl Default for ListUsenetQuery {
+    fn default() -> Self {
+        Self {
+            id: None,
+            bypass_cache: Some(false),
+            offset: Some(0),
+            limit: Some(1000),
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+#[cfg_attr(feature = "specta", derive(specta::Type))]
+pub struct UsenetStatusQuery {
+    /// Allows you to bypass the cached data, and always get fresh information.
+    ///
+    /// Useful if constantly querying for fresh download stats.
+    /// Otherwise, we request that you save our database a few calls.
+    pub bypass_cache: bool,
+
+    /// Determines the usenet download requested, will return an object rather than list. Not optional.
+    pub id: u64,
+}
+
+#[derive(Debug, Serialize, Default)]
+#[cfg_attr(feature = "specta", derive(specta::Type))]
+pub struct UsenetRequestLinkQuery {
+    /// The usenet download's ID that you want to download
+    pub usenet_id: u64,
+
+    /// The files's ID that you want to download.
+    pub file_id: Option<u64>,
+
+    /// If you want a zip link. Required if no file_id. Takes precedence over file_id if both are given.
+    pub zip_link: bool,
+
+    /// The user's IP to determine the closest CDN. Optional.
+    ///
+    /// Preferably check IPv4 if correct first.
+    pub user_ip: Option<String>,
+
+    /// If you want to redirect the user to the CDN link.
+    ///
+    /// This is useful for creating permalinks so that you can just make this request URL the link.
+    pub redirect: bool,
+}
+
+#[derive(Debug, Serialize)]
+#[cfg_attr(feature = "specta", derive(specta::Type))]
+pub struct UsenetCachedAvailabilityQuery {
+    #[serde(serialize_with = "serialize_comma_separated")]
+    pub hash: Vec<InfoHash>,
+    pub format: FormatType,
+    /// Also list the files of every cached download.
+    pub list_files: Option<bool>,
+}
+
+fn serialize_comma_separated<S>(vec: &[InfoHash], serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: serde::Serializer,
+{
+    let joined: Vec<&str> = vec.iter().map(InfoHash::as_str).collect();
+    serializer.serialize_str(&joined.join(","))
+}
+
+impl Default for UsenetCachedAvailabilityQuery {
+    fn default() -> Self {
+        Self {
+            hash: Vec::new(),
+            format: FormatType::List,
+            list_files: None,
+        }
+    }
+}
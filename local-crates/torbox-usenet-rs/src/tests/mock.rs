@@ -0,0 +1,168 @@
+use serde_json::json;
+use torbox_core_rs::{data::creation::DownloadLinkResponse, error::ApiErrorCode};
+use torbox_mock::MockServer;
+
+use crate::{
+    UsenetApi,
+    body::{UsenetControlBody, UsenetCreateBody},
+    payload::UsenetCreatePayload,
+    query::{ListUsenetQuery, UsenetRequestLinkQuery},
+    types::{UsenetControlSource, UsenetOperation, UsenetSource},
+};
+
+fn create_body(source: UsenetSource) -> UsenetCreateBody {
+    UsenetCreateBody {
+        source,
+        name: Some("Show.S01E01".into()),
+        password: None,
+        post_processing: None,
+        as_queued: None,
+        add_only_if_cached: None,
+    }
+}
+
+#[tokio::test]
+async fn test_create_then_list_and_status() {
+    let server = MockServer::start().await;
+    let client = server.client().with_strict_errors(true);
+    let api = UsenetApi::new(&client);
+
+    let payload = api
+        .create_usenet(create_body(UsenetSource::Link(
+            "https://example.com/show.nzb".into(),
+        )))
+        .await
+        .unwrap()
+        .into_data()
+        .unwrap();
+    let UsenetCreatePayload::Created(created) = payload else {
+        panic!("Unexpected payload {payload:?}");
+    };
+
+    let list = api
+        .list_usenet_query(ListUsenetQuery::default())
+        .await
+        .unwrap()
+        .into_data()
+        .unwrap()
+        .unwrap();
+    assert_eq!(list.len(), 1);
+    assert_eq!(list[0].id, created.usenetdownload_id);
+    assert_eq!(list[0].hash, created.hash);
+
+    let status = api
+        .status_query(true, list[0].id)
+        .await
+        .unwrap()
+        .into_data()
+        .unwrap()
+        .unwrap();
+    assert_eq!(status.name, "Show.S01E01");
+}
+
+#[tokio::test]
+async fn test_create_without_link_or_file_fails() {
+    let server = MockServer::start().await;
+    let client = server.client().with_strict_errors(true);
+
+    let err = UsenetApi::new(&client)
+        .create_usenet(create_body(UsenetSource::File(Vec::new())))
+        .await
+        .unwrap_err();
+
+    assert_eq!(err.code(), Some(&ApiErrorCode::MissingRequiredOption));
+}
+
+#[tokio::test]
+async fn test_control_pauses_then_deletes() {
+    let server = MockServer::start().await;
+    let id = server.state().add_usenet("show", &[("show.mkv", 1)]);
+    let client = server.client().with_strict_errors(true);
+    let api = UsenetApi::new(&client);
+
+    api.control_usenet(UsenetControlBody {
+        source: UsenetControlSource::UsenetId(id),
+        operation: UsenetOperation::Pause,
+    })
+    .await
+    .unwrap();
+    assert_eq!(
+        server.state().usenet(id).unwrap()["download_state"],
+        "paused"
+    );
+
+    api.control_usenet(UsenetControlBody {
+        source: UsenetControlSource::UsenetId(id),
+        operation: UsenetOperation::Delete,
+    })
+    .await
+    .unwrap();
+    assert!(server.state().usenet(id).is_none());
+}
+
+#[tokio::test]
+async fn test_request_download_link_of_a_file() {
+    let server = MockServer::start().await;
+    let id = server
+        .state()
+        .add_usenet("show", &[("show.mkv", 1), ("show.nfo", 1)]);
+    let client = server.client().with_strict_errors(true);
+    let api = UsenetApi::new(&client);
+
+    let link = api
+        .request_download_link(UsenetRequestLinkQuery {
+            usenet_id: id,
+            file_id: Some(1),
+            ..Default::default()
+        })
+        .await
+        .unwrap();
+    let DownloadLinkResponse::Json(resp) = link else {
+        panic!("Unexpected link {link:?}");
+    };
+    assert!(
+        resp.into_data()
+            .unwrap()
+            .ends_with(&format!("/usenet/{id}/1"))
+    );
+
+    let redirect = api
+        .request_download_link(UsenetRequestLinkQuery {
+            usenet_id: id,
+            zip_link: true,
+            redirect: true,
+            ..Default::default()
+        })
+        .await
+        .unwrap();
+    assert!(matches!(redirect, DownloadLinkResponse::Redirect(url) if url.ends_with("/zip")));
+}
+
+#[tokio::test]
+async fn test_odd_hash_does_not_fail_the_list() {
+    let server = MockServer::start().await;
+    let id = server.state().add_usenet("show", &[("show.mkv", 1)]);
+    server.state().add_usenet("other", &[("other.mkv", 1)]);
+    server.state().script(id, [json!({"hash": ""})]);
+    let client = server.client().with_strict_errors(true);
+    let api = UsenetApi::new(&client);
+
+    let status = api
+        .status_query(true, id)
+        .await
+        .unwrap()
+        .into_data()
+        .unwrap()
+        .unwrap();
+    assert!(!status.hash.is_valid());
+
+    let list = api
+        .list_usenet_query(ListUsenetQuery::default())
+        .await
+        .unwrap()
+        .into_data()
+        .unwrap()
+        .unwrap();
+    assert_eq!(list.len(), 2);
+    assert!(list[1].hash.is_valid());
+}
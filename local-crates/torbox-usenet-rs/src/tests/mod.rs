@@ -0,0 +1,93 @@
+#[cfg(test)]
+mod mock;
+
+#[cfg(test)]
+pub mod usenet_test {
+    use std::env;
+
+    use crate::{
+        UsenetApi,
+        query::{ListUsenetQuery, UsenetCachedAvailabilityQuery, UsenetRequestLinkQuery},
+    };
+
+    use dotenvy::from_filename;
+    use torbox_core_rs::client::TorboxClient;
+
+    pub fn load_token_from_file() -> Option<String> {
+        if let Err(err) = from_filename(".token") {
+            eprintln!(
+                "Could not load .token file, please create one before starting unit testing: {err}"
+            );
+            return None;
+        }
+        env::var("TORBOX_TOKEN").ok()
+    }
+
+    fn test_client() -> TorboxClient {
+        let token = load_token_from_file().expect("Missing TORBOX_TOKEN in .token file");
+        TorboxClient::new(token)
+    }
+
+    #[tokio::test]
+    async fn test_list_usenet_success() {
+        let client = test_client();
+        let api = UsenetApi::new(&client);
+
+        let result = api.list_usenet_query(ListUsenetQuery::default()).await;
+        match result {
+            Ok(response) => {
+                println!("Usenet List: {:?}", response);
+                assert!(response.success, "API responded with success=false");
+            }
+            Err(e) => panic!("API call failed: {e:?}"),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_check_cached_success() {
+        let client = test_client();
+        let api = UsenetApi::new(&client);
+
+        let result = api
+            .check_cached(UsenetCachedAvailabilityQuery::default())
+            .await;
+        match result {
+            Ok(response) => {
+                println!("Cache Availability: {:?}", response);
+                assert!(response.success, "API responded with success=false");
+            }
+            Err(e) => panic!("API call failed: {e:?}"),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_request_download_link_success() {
+        let client = test_client();
+        let api = UsenetApi::new(&client);
+
+        let first = api
+            .list_usenet_query(ListUsenetQuery::default())
+            .await
+            .expect("Failed to list usenet downloads")
+            .data
+            .flatten()
+            .and_then(|list| list.into_iter().next());
+
+        let Some(first) = first else {
+            println!("No usenet download on this account, skipping");
+            return;
+        };
+
+        let query = UsenetRequestLinkQuery {
+            usenet_id: first.id,
+            zip_link: true,
+            redirect: true,
+            ..Default::default()
+        };
+
+        match api.request_download_link(query).await {
+            Ok(response) => println!("Download Link: {:?}", response),
+            Err(e) => panic!("API call failed: {e:?}"),
+        }
+    }
+}
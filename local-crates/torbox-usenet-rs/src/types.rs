@@ -0,0 +1,41 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "snake_case")]
+#[cfg_attr(feature = "specta", derive(specta::Type))]
+pub enum UsenetSource {
+    /// A raw NZB file, uploaded as `file`.
+    File(Vec<u8>),
+    /// A link to an NZB file that TorBox will fetch itself.
+    Link(String),
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+#[cfg_attr(feature = "specta", derive(specta::Type))]
+pub enum UsenetOperation {
+    /// Deletes the usenet download from your account permanently
+    Delete,
+    /// Pauses an active usenet download
+    Pause,
+    /// Resumes a paused usenet download
+    Resume,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+#[cfg_attr(feature = "specta", derive(specta::Type))]
+pub enum UsenetControlSource {
+    /// the usenet download's id, optional if using "all" parameter
+    UsenetId(u64),
+    /// if you want to do this operation to all usenet downloads in your account, optional if using the "usenet_id" parameter
+    All(bool),
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "snake_case")]
+#[cfg_attr(feature = "specta", derive(specta::Type))]
+pub enum FormatType {
+    Object,
+    List,
+}
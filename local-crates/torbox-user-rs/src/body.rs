@@ -29,3 +29,10 @@ pub struct SearchEngineEditBody {
     pub apikey: Option<String>,
     pub download_type: String,
 }
+
+#[derive(Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "specta", derive(specta::Type))]
+pub struct DeviceTokenBody {
+    /// The `device_code` returned when starting the device code authentication.
+    pub device_code: String,
+}
@@ -0,0 +1,78 @@
+use std::{fmt, sync::Arc};
+
+use async_trait::async_trait;
+use tokio::sync::Mutex;
+use torbox_core_rs::{
+    client::TorboxClient,
+    data::user::SessionToken,
+    error::ApiError,
+    network::credentials::{CredentialProvider, InMemoryCredentials},
+};
+
+use crate::UserApi;
+
+/// Credentials that get a new API token from a TorBox session token once the current one is rejected.
+///
+/// ```no_run
+/// # use std::sync::Arc;
+/// # use torbox_core_rs::{client::TorboxClient, data::user::SessionToken};
+/// # use torbox_user_rs::credentials::SessionCredentials;
+/// # fn run(session: SessionToken) {
+/// let base = TorboxClient::new(String::new());
+/// let credentials = SessionCredentials::new(&base, "current_api_token", session);
+/// let client = base.with_credentials(Arc::new(credentials));
+/// # }
+/// ```
+pub struct SessionCredentials {
+    /// Client without token, only used to call the refresh endpoint.
+    client: TorboxClient,
+    session: SessionToken,
+    token: InMemoryCredentials,
+    /// Held during a refresh, so concurrent rejections wait for it instead of refreshing again.
+    refreshing: Mutex<()>,
+}
+
+impl SessionCredentials {
+    /// `client` provides the settings used to refresh the token, its own token is not sent.
+    pub fn new(client: &TorboxClient, token: impl Into<String>, session: SessionToken) -> Self {
+        Self {
+            client: client.with_credentials(Arc::new(InMemoryCredentials::default())),
+            session,
+            token: InMemoryCredentials::new(token),
+            refreshing: Mutex::new(()),
+        }
+    }
+}
+
+impl fmt::Debug for SessionCredentials {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SessionCredentials")
+            .field("base_url", &self.client.base_url)
+            .finish_non_exhaustive()
+    }
+}
+
+#[async_trait]
+impl CredentialProvider for SessionCredentials {
+    fn token(&self) -> Result<String, ApiError> {
+        self.token.token()
+    }
+
+    /// Calls `api/user/refreshtoken`, unless a concurrent request already did.
+    async fn refresh(&self, rejected: &str) -> Result<Option<String>, ApiError> {
+        let _refreshing = self.refreshing.lock().await;
+
+        // Checked once the lock is held, a request that waited for it finds the new token
+        if let Some(current) = self.token.refresh(rejected).await? {
+            return Ok(Some(current));
+        }
+
+        let token = UserApi::new(&self.client)
+            .refresh_api_token(self.session.token.clone(), self.session.expires_at)
+            .await?
+            .into_data()?;
+        self.token.set(token.clone());
+
+        Ok(Some(token))
+    }
+}
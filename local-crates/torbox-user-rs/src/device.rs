@@ -0,0 +1,172 @@
+//! Device code login (OAuth 2.0 device authorization grant, RFC 8628), for apps that cannot
+//! show a login page, such as CLIs or TV apps.
+
+use std::time::Duration;
+
+use chrono::Utc;
+use torbox_core_rs::{
+    client::{Endpoint, TorboxClient},
+    data::user::{DeviceCodeAuth, DeviceToken},
+    error::{ApiError, ErrorValue},
+};
+
+use crate::{
+    body::DeviceTokenBody,
+    endpoint::{DeviceCodeAuthGetEp, DeviceTokenPostEp},
+    query::DeviceCodeAuthQuery,
+};
+
+const AUTHORIZATION_PENDING: &str = "authorization_pending";
+const SLOW_DOWN: &str = "slow_down";
+const EXPIRED_TOKEN: &str = "expired_token";
+const ACCESS_DENIED: &str = "access_denied";
+
+/// Outcome of a single poll of the token endpoint.
+#[derive(Debug)]
+pub enum DevicePoll {
+    /// The user approved the code.
+    Authorized(DeviceToken),
+    /// The user has not approved the code yet.
+    Pending,
+    /// The token endpoint is polled too often, the interval must grow.
+    SlowDown,
+    /// The code expired before being approved.
+    Expired,
+    /// The user refused the authorization.
+    Denied,
+}
+
+impl DevicePoll {
+    /// Maps the OAuth error codes of the token endpoint, `None` for any other error.
+    fn from_error(error: &ErrorValue) -> Option<Self> {
+        let ErrorValue::Message(message) = error else {
+            return None;
+        };
+
+        match message.to_ascii_lowercase().as_str() {
+            AUTHORIZATION_PENDING => Some(Self::Pending),
+            SLOW_DOWN => Some(Self::SlowDown),
+            EXPIRED_TOKEN => Some(Self::Expired),
+            ACCESS_DENIED => Some(Self::Denied),
+            _ => None,
+        }
+    }
+}
+
+/// Logs a user in with a device code and yields a client authenticated as them.
+///
+/// The client it is created from only provides the settings (base URL, retries, ...) of the
+/// returned one, its token is not needed by the login endpoints.
+///
+/// ```no_run
+/// # async fn run() -> Result<(), torbox_core_rs::error::ApiError> {
+/// use torbox_core_rs::client::TorboxClient;
+/// use torbox_user_rs::device::DeviceLogin;
+///
+/// let anonymous = TorboxClient::new(String::new());
+/// let client = DeviceLogin::new(&anonymous, "My App")
+///     .login(|auth| println!("Enter {} at {}", auth.code, auth.verification_url))
+///     .await?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone)]
+pub struct DeviceLogin<'a> {
+    client: &'a TorboxClient,
+    app: String,
+    slow_down_step: Duration,
+    min_interval: Duration,
+}
+
+impl<'a> DeviceLogin<'a> {
+    /// `app` is the name shown to the user when they verify the code.
+    pub fn new(client: &'a TorboxClient, app: impl Into<String>) -> Self {
+        Self {
+            client,
+            app: app.into(),
+            slow_down_step: Duration::from_secs(5),
+            min_interval: Duration::from_secs(5),
+        }
+    }
+
+    /// Sets how much the polling interval grows on each `slow_down` answer, 5 seconds by default.
+    pub fn with_slow_down_step(mut self, step: Duration) -> Self {
+        self.slow_down_step = step;
+        self
+    }
+
+    /// Sets the shortest polling interval, used when TorBox sends a shorter or no `interval`. 5 seconds by
+    /// default, as in RFC 8628.
+    pub fn with_min_interval(mut self, interval: Duration) -> Self {
+        self.min_interval = interval;
+        self
+    }
+
+    /// Starts the flow, the returned code has to be shown to the user with its verification URL.
+    pub async fn start(&self) -> Result<DeviceCodeAuth, ApiError> {
+        Endpoint::<DeviceCodeAuthGetEp>::new(self.client)
+            .call_query(DeviceCodeAuthQuery {
+                app: self.app.clone(),
+            })
+            .await?
+            .into_data()
+    }
+
+    /// Polls the token endpoint once.
+    pub async fn poll(&self, device_code: &str) -> Result<DevicePoll, ApiError> {
+        let result = Endpoint::<DeviceTokenPostEp>::new(self.client)
+            .call_json(DeviceTokenBody {
+                device_code: device_code.to_string(),
+            })
+            .await
+            .and_then(|resp| resp.into_data());
+
+        match result {
+            Ok(token) => Ok(DevicePoll::Authorized(token)),
+            Err(ApiError::Failure(error)) => match DevicePoll::from_error(&error) {
+                Some(poll) => Ok(poll),
+                None => Err(ApiError::Failure(error)),
+            },
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Polls every `auth.interval` seconds, or at least every [`with_min_interval`](Self::with_min_interval),
+    /// until the user approves the code, then returns a client authenticated with the granted token.
+    ///
+    /// Each `slow_down` answer makes the interval grow. Fails with an `expired_token` or
+    /// `access_denied` `ApiError::Failure` once `auth.expires_at` is passed or the user refuses.
+    pub async fn wait(&self, auth: &DeviceCodeAuth) -> Result<TorboxClient, ApiError> {
+        let mut interval = Duration::from_secs(auth.interval).max(self.min_interval);
+
+        loop {
+            tokio::time::sleep(interval).await;
+
+            if Utc::now() >= auth.expires_at {
+                return Err(failure(EXPIRED_TOKEN));
+            }
+
+            match self.poll(&auth.device_code).await? {
+                DevicePoll::Authorized(token) => return self.client.with_token(token.access_token),
+                DevicePoll::Pending => {}
+                DevicePoll::SlowDown => interval += self.slow_down_step,
+                DevicePoll::Expired => return Err(failure(EXPIRED_TOKEN)),
+                DevicePoll::Denied => return Err(failure(ACCESS_DENIED)),
+            }
+        }
+    }
+
+    /// Runs the whole flow: starts it, hands the code to `show` and [`wait`](Self::wait)s.
+    pub async fn login(
+        &self,
+        show: impl FnOnce(&DeviceCodeAuth),
+    ) -> Result<TorboxClient, ApiError> {
+        let auth = self.start().await?;
+        show(&auth);
+        self.wait(&auth).await
+    }
+}
+
+fn failure(message: &str) -> ApiError {
+    ApiError::Failure(ErrorValue::Message(message.to_string()))
+}
@@ -2,13 +2,13 @@ use reqwest::Method;
 use torbox_core_rs::{
     client::EndpointSpec,
     data::user::{
-        DeviceCodeAuth, ReferralData, SearchEngineData, SubscriptionData, TransactionData,
-        UserProfile,
+        DeviceCodeAuth, DeviceToken, ReferralData, SearchEngineData, SubscriptionData,
+        TransactionData, UserProfile, UserSettings,
     },
 };
 
 use crate::{
-    body::{RefreshApiTokenBody, SearchEngineControlBody, SearchEngineEditBody},
+    body::{DeviceTokenBody, RefreshApiTokenBody, SearchEngineControlBody, SearchEngineEditBody},
     query::{AddReferralQuery, DeviceCodeAuthQuery, SearchEngineDataQuery, UserDataQuery},
 };
 
@@ -58,6 +58,15 @@ impl EndpointSpec for DeviceCodeAuthGetEp {
     const METHOD: Method = Method::GET;
 }
 
+pub struct DeviceTokenPostEp;
+
+impl EndpointSpec for DeviceTokenPostEp {
+    type Req = DeviceTokenBody;
+    type Resp = DeviceToken;
+    const PATH: &'static str = "api/user/auth/device/token";
+    const METHOD: Method = Method::POST;
+}
+
 pub struct ReferralDataGetEp;
 
 impl EndpointSpec for ReferralDataGetEp {
@@ -85,6 +94,15 @@ impl EndpointSpec for TransactionDataGetEp {
     const METHOD: Method = Method::GET;
 }
 
+pub struct EditSettingsPutEp;
+
+impl EndpointSpec for EditSettingsPutEp {
+    type Req = UserSettings;
+    type Resp = ();
+    const PATH: &'static str = "api/user/settings/editsettings";
+    const METHOD: Method = Method::PUT;
+}
+
 pub struct SearchEngineDataGetEp;
 
 impl EndpointSpec for SearchEngineDataGetEp {
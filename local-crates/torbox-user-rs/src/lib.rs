@@ -3,22 +3,25 @@ use torbox_core_rs::{
     client::{Endpoint, TorboxClient},
     data::user::{
         DeviceCodeAuth, ReferralData, SearchEngineData, SessionToken, SubscriptionData,
-        TransactionData, UserProfile,
+        TransactionData, UserProfile, UserSettings,
     },
     error::ApiError,
 };
 
 use crate::{
     body::{RefreshApiTokenBody, SearchEngineControlBody, SearchEngineEditBody},
+    device::DeviceLogin,
     endpoint::{
-        AddReferalPostEp, DeviceCodeAuthGetEp, GetConfirmationCodeGetEp, GetUserDataGetEp,
-        ReferralDataGetEp, RefreshApiTokenPostEp, SearchEngineControlPostEp, SearchEngineDataGetEp,
-        SearchEngineEditPostEp, SubscriptionDataGetEp, TransactionDataGetEp,
+        AddReferalPostEp, DeviceCodeAuthGetEp, EditSettingsPutEp, GetConfirmationCodeGetEp,
+        GetUserDataGetEp, ReferralDataGetEp, RefreshApiTokenPostEp, SearchEngineControlPostEp,
+        SearchEngineDataGetEp, SearchEngineEditPostEp, SubscriptionDataGetEp, TransactionDataGetEp,
     },
     query::{AddReferralQuery, DeviceCodeAuthQuery, SearchEngineDataQuery, UserDataQuery},
 };
 
 pub mod body;
+pub mod credentials;
+pub mod device;
 pub mod endpoint;
 pub mod query;
 pub mod tests;
@@ -30,6 +33,7 @@ pub mod types;
 /// - Retrieving user data
 /// - Adding referal links
 /// - Refreshing APIs
+/// - Logging in with a device code
 #[cfg_attr(feature = "specta", derive(specta::Type))]
 pub struct UserApi<'a> {
     client: &'a TorboxClient,
@@ -40,7 +44,10 @@ impl<'a> UserApi<'a> {
         Self { client }
     }
 
-    /// Not done
+    /// Exchanges a session token for a new API token, invalidating the previous one.
+    ///
+    /// The session token is the `torbox_session_token` stored by <https://torbox.app> in your browser, see
+    /// [`SessionCredentials`](crate::credentials::SessionCredentials) to refresh the token automatically.
     pub async fn refresh_api_token(
         &self,
         token: String,
@@ -68,6 +75,29 @@ impl<'a> UserApi<'a> {
             .await
     }
 
+    /// Changes the settings set in `settings`, the others keep their current value.
+    ///
+    /// ```no_run
+    /// # use torbox_core_rs::data::user::{SeedPreference, UserSettings};
+    /// # async fn run(api: torbox_user_rs::UserApi<'_>) -> Result<(), torbox_core_rs::error::ApiError> {
+    /// api.update_settings(UserSettings {
+    ///     seed_torrents: Some(SeedPreference::NoSeed),
+    ///     discord_notifications: Some(true),
+    ///     ..Default::default()
+    /// })
+    /// .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn update_settings(
+        &self,
+        settings: UserSettings,
+    ) -> Result<ApiResponse<()>, ApiError> {
+        Endpoint::<EditSettingsPutEp>::new(self.client)
+            .call_json(settings)
+            .await
+    }
+
     pub async fn add_referral_code(&self, referral: String) -> Result<ApiResponse<()>, ApiError> {
         Endpoint::<AddReferalPostEp>::new(self.client)
             .call_query(AddReferralQuery { referral })
@@ -89,6 +119,11 @@ impl<'a> UserApi<'a> {
             .await
     }
 
+    /// Starts a device code login, see [`DeviceLogin`].
+    pub fn device_login(&self, app_name: impl Into<String>) -> DeviceLogin<'a> {
+        DeviceLogin::new(self.client, app_name)
+    }
+
     pub async fn get_referral_data(&self) -> Result<ApiResponse<ReferralData>, ApiError> {
         Endpoint::<ReferralDataGetEp>::new(self.client)
             .call_query(())
@@ -0,0 +1,364 @@
+use std::{
+    sync::Arc,
+    time::{Duration, Instant},
+};
+
+use serde_json::{Value, json};
+use torbox_core_rs::{
+    client::TorboxClient,
+    data::user::{SeedPreference, SessionToken, UserSettings},
+    error::{ApiError, ApiErrorCode, ErrorValue},
+    network::credentials::InMemoryCredentials,
+};
+use torbox_mock::{MOCK_SESSION_TOKEN, MockServer};
+
+use crate::{
+    UserApi,
+    body::{SearchEngineControlBody, SearchEngineEditBody},
+    credentials::SessionCredentials,
+    device::DevicePoll,
+    types::{SearchEngineControlOperation, SearchEngineControlSource, SearchEngineEditSource},
+};
+
+/// A client without token, pointed at the mock.
+fn anonymous(server: &MockServer) -> TorboxClient {
+    TorboxClient::new(String::new())
+        .with_base_url(server.base_url())
+        .with_strict_errors(true)
+}
+
+#[tokio::test]
+async fn test_device_login_yields_authenticated_client() {
+    let server = MockServer::start().await;
+    let anonymous = anonymous(&server);
+    let login = UserApi::new(&anonymous)
+        .device_login("Test App")
+        .with_min_interval(Duration::ZERO);
+
+    let auth = login.start().await.unwrap();
+    assert_eq!(
+        server.state().device(&auth.device_code).unwrap()["app"],
+        "Test App"
+    );
+    assert!(matches!(
+        login.poll(&auth.device_code).await.unwrap(),
+        DevicePoll::Pending
+    ));
+
+    server.state().approve_device(&auth.code);
+    let client = login.wait(&auth).await.unwrap();
+    assert_eq!(client.token().unwrap(), server.token());
+    assert_eq!(client.base_url, server.base_url());
+
+    let profile = UserApi::new(&client).get_data(false).await.unwrap();
+    assert!(profile.success);
+}
+
+#[tokio::test]
+async fn test_device_login_keeps_polling_through_slow_down() {
+    let server = MockServer::start().await;
+    let anonymous = anonymous(&server);
+    let login = UserApi::new(&anonymous)
+        .device_login("Test App")
+        .with_min_interval(Duration::ZERO)
+        .with_slow_down_step(Duration::from_millis(10));
+
+    let client = login
+        .login(|auth| {
+            let id = server.state().device(&auth.device_code).unwrap()["id"]
+                .as_u64()
+                .unwrap();
+            server.state().script(
+                id,
+                [
+                    json!({"status": "authorization_pending"}),
+                    json!({"status": "slow_down"}),
+                    json!({"status": "authorization_pending"}),
+                    json!({"status": "authorized"}),
+                ],
+            );
+        })
+        .await
+        .unwrap();
+
+    assert_eq!(client.token().unwrap(), server.token());
+    assert_eq!(
+        server.state().request_count("api/user/auth/device/token"),
+        4
+    );
+}
+
+#[tokio::test]
+async fn test_device_login_waits_at_least_the_min_interval() {
+    let server = MockServer::start().await;
+    let anonymous = anonymous(&server);
+    let login = UserApi::new(&anonymous)
+        .device_login("Test App")
+        .with_min_interval(Duration::from_millis(100));
+
+    let auth = login.start().await.unwrap();
+    assert_eq!(auth.interval, 0);
+    let id = server.state().device(&auth.device_code).unwrap()["id"]
+        .as_u64()
+        .unwrap();
+    server.state().script(
+        id,
+        [
+            json!({"status": "authorization_pending"}),
+            json!({"status": "authorized"}),
+        ],
+    );
+
+    let start = Instant::now();
+    login.wait(&auth).await.unwrap();
+    assert!(start.elapsed() >= Duration::from_millis(200));
+}
+
+#[tokio::test]
+async fn test_device_login_fails_when_expired_or_denied() {
+    let server = MockServer::start().await;
+    let anonymous = anonymous(&server);
+    let login = UserApi::new(&anonymous)
+        .device_login("Test App")
+        .with_min_interval(Duration::ZERO);
+
+    let expired = login.start().await.unwrap();
+    let id = server.state().device(&expired.device_code).unwrap()["id"]
+        .as_u64()
+        .unwrap();
+    server
+        .state()
+        .script(id, [json!({"expires_at": "2020-01-01T00:00:00Z"})]);
+    let err = login.wait(&expired).await.err().unwrap();
+    assert!(
+        matches!(&err, ApiError::Failure(ErrorValue::Message(m)) if m == "expired_token"),
+        "{err:?}"
+    );
+
+    let denied = login.start().await.unwrap();
+    let id = server.state().device(&denied.device_code).unwrap()["id"]
+        .as_u64()
+        .unwrap();
+    server
+        .state()
+        .script(id, [json!({"status": "access_denied"})]);
+    let err = login.wait(&denied).await.err().unwrap();
+    assert!(
+        matches!(&err, ApiError::Failure(ErrorValue::Message(m)) if m == "access_denied"),
+        "{err:?}"
+    );
+}
+
+#[tokio::test]
+async fn test_rejected_token_is_refreshed_and_request_retried() {
+    let server = MockServer::start().await;
+    let base = server.client().with_strict_errors(true);
+    let session = SessionToken {
+        token: MOCK_SESSION_TOKEN.to_string(),
+        expires_at: 0,
+    };
+    let client = base.with_credentials(Arc::new(SessionCredentials::new(
+        &base,
+        server.token(),
+        session,
+    )));
+
+    server.state().set_token("revoked");
+    let profile = UserApi::new(&client).get_data(false).await.unwrap();
+
+    assert!(profile.success);
+    assert_eq!(client.token().unwrap(), server.token());
+    assert_eq!(server.state().request_count("api/user/refreshtoken"), 1);
+    assert_eq!(server.state().request_count("api/user/me"), 2);
+}
+
+#[tokio::test]
+async fn test_concurrent_rejections_refresh_the_token_once() {
+    let server = MockServer::start().await;
+    let base = server.client().with_strict_errors(true);
+    let session = SessionToken {
+        token: MOCK_SESSION_TOKEN.to_string(),
+        expires_at: 0,
+    };
+    let client = base.with_credentials(Arc::new(SessionCredentials::new(
+        &base,
+        server.token(),
+        session,
+    )));
+
+    server.state().set_token("revoked");
+    let api = UserApi::new(&client);
+    let (a, b, c) = tokio::join!(
+        api.get_data(false),
+        api.get_data(false),
+        api.get_data(false)
+    );
+
+    assert!(a.is_ok() && b.is_ok() && c.is_ok());
+    assert_eq!(server.state().request_count("api/user/refreshtoken"), 1);
+    assert_eq!(server.state().request_count("api/user/me"), 6);
+}
+
+#[tokio::test]
+async fn test_rejected_token_fails_when_it_cannot_be_refreshed() {
+    let server = MockServer::start().await;
+    let credentials = Arc::new(InMemoryCredentials::new(server.token()));
+    let client = server
+        .client()
+        .with_strict_errors(true)
+        .with_credentials(credentials.clone());
+
+    server.state().set_token("rotated");
+    let err = UserApi::new(&client).get_data(false).await.unwrap_err();
+    assert_eq!(err.code(), Some(&ApiErrorCode::BadToken));
+    assert_eq!(server.state().request_count("api/user/me"), 1);
+
+    // Picked up by the next request
+    credentials.set("rotated");
+    assert!(UserApi::new(&client).get_data(false).await.is_ok());
+}
+
+async fn settings(api: &UserApi<'_>) -> UserSettings {
+    api.get_data(true)
+        .await
+        .unwrap()
+        .into_data()
+        .unwrap()
+        .settings
+        .unwrap()
+}
+
+#[tokio::test]
+async fn test_settings_are_typed_with_extra_kept() {
+    let server = MockServer::start().await;
+    let client = server.client();
+    let api = UserApi::new(&client);
+
+    let settings = settings(&api).await;
+
+    assert_eq!(settings.seed_torrents, Some(SeedPreference::Auto));
+    assert_eq!(settings.stremio_seed_torrents, Some(SeedPreference::NoSeed));
+    assert_eq!(settings.allow_zipped, Some(true));
+    assert_eq!(settings.webhook_url, None);
+    assert_eq!(settings.extra["webdav_flatten"], json!(false));
+    assert!(!settings.extra.contains_key("seed_torrents"));
+
+    let profile = api.get_data(false).await.unwrap().into_data().unwrap();
+    assert!(profile.settings.is_none());
+}
+
+#[test]
+fn test_unknown_seed_preference_is_kept() {
+    let settings: UserSettings = serde_json::from_value(json!({"seed_torrents": 4})).unwrap();
+
+    assert_eq!(settings.seed_torrents, Some(SeedPreference::Unknown(4)));
+    assert_eq!(
+        serde_json::to_value(&settings).unwrap()["seed_torrents"],
+        json!(4)
+    );
+}
+
+#[tokio::test]
+async fn test_update_settings_only_sends_fields_set() {
+    let server = MockServer::start().await;
+    let client = server.client().with_strict_errors(true);
+    let api = UserApi::new(&client);
+
+    let mut changes = UserSettings {
+        seed_torrents: Some(SeedPreference::NoSeed),
+        webhook_notifications: Some(true),
+        webhook_url: Some("https://example.com/hook".into()),
+        ..Default::default()
+    };
+    changes.extra.insert("webdav_flatten".into(), Value::Null);
+    assert_eq!(
+        serde_json::to_value(&changes).unwrap(),
+        json!({
+            "seed_torrents": 3,
+            "webhook_notifications": true,
+            "webhook_url": "https://example.com/hook",
+            "webdav_flatten": null,
+        })
+    );
+    assert!(api.update_settings(changes).await.unwrap().success);
+
+    let settings = settings(&api).await;
+    assert_eq!(settings.seed_torrents, Some(SeedPreference::NoSeed));
+    assert_eq!(
+        settings.webhook_url.as_deref(),
+        Some("https://example.com/hook")
+    );
+    assert_eq!(settings.allow_zipped, Some(true));
+    assert_eq!(settings.email_notifications, Some(true));
+    assert!(!settings.extra.contains_key("webdav_flatten"));
+}
+
+#[tokio::test]
+async fn test_referral_subscription_and_transactions() {
+    let server = MockServer::start().await;
+    let client = server.client().with_strict_errors(true);
+    let api = UserApi::new(&client);
+
+    let referral = api.get_referral_data().await.unwrap().into_data().unwrap();
+    let err = api
+        .add_referral_code(referral.referral_code)
+        .await
+        .unwrap_err();
+    assert_eq!(err.code(), Some(&ApiErrorCode::InvalidOption));
+    api.add_referral_code("someone-else".into()).await.unwrap();
+
+    api.get_confirmation_code().await.unwrap();
+    let subscription = api.get_subscription().await.unwrap().into_data().unwrap();
+    assert_eq!(subscription.status, "active");
+    let transactions = api.get_transactions().await.unwrap().into_data().unwrap();
+    assert_eq!(transactions.len(), 1);
+}
+
+#[tokio::test]
+async fn test_search_engines_are_edited_and_controlled() {
+    let server = MockServer::start().await;
+    let client = server.client().with_strict_errors(true);
+    let api = UserApi::new(&client);
+    let id = server
+        .state()
+        .add_search_engine("prowlarr", "http://localhost:9696");
+
+    let engines = api
+        .get_search_engines(id as u32)
+        .await
+        .unwrap()
+        .into_data()
+        .unwrap();
+    assert_eq!(engines[0].url, "http://localhost:9696");
+
+    let edited = api
+        .edit_search_engines(SearchEngineEditBody {
+            id,
+            source: SearchEngineEditSource::Url("http://indexer:9696".into()),
+            apikey: Some("key".into()),
+            download_type: "usenet".into(),
+        })
+        .await
+        .unwrap()
+        .into_data()
+        .unwrap();
+    assert_eq!(edited.url, "http://indexer:9696");
+    assert_eq!(edited.apikey, "key");
+    assert_eq!(edited.download_type, "usenet");
+
+    api.control_search_engines(SearchEngineControlBody {
+        source: SearchEngineControlSource::Id(id as u32),
+        operation: SearchEngineControlOperation::Disable,
+    })
+    .await
+    .unwrap();
+    assert_eq!(server.state().search_engine(id).unwrap()["active"], false);
+
+    api.control_search_engines(SearchEngineControlBody {
+        source: SearchEngineControlSource::All(true),
+        operation: SearchEngineControlOperation::Delete,
+    })
+    .await
+    .unwrap();
+    assert!(server.state().search_engine(id).is_none());
+}
@@ -1,10 +1,13 @@
+#[cfg(test)]
+mod mock;
+
 #[cfg(test)]
 pub mod user_test {
     use std::env;
 
-    use crate::{UserApi, query::UserDataQuery};
+    use crate::UserApi;
     use dotenvy::from_filename;
-    use torbox_core_rs::{client::TorboxClient, data::user::UserProfile};
+    use torbox_core_rs::client::TorboxClient;
 
     pub fn load_token_from_file() -> Option<String> {
         if let Err(err) = from_filename(".token") {
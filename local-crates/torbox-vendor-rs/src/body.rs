@@ -0,0 +1,104 @@
+use serde::Serialize;
+use torbox_core_rs::{
+    error::ApiError,
+    validation::{validate_name, validate_url},
+};
+
+/// Request body of [`register`](crate::VendorApi::register).
+#[derive(Debug, Serialize, Clone)]
+#[cfg_attr(feature = "specta", derive(specta::Type))]
+pub struct VendorRegisterBody {
+    /// The name of your service, shown to your users.
+    pub vendor_name: String,
+    /// The URL of your service.
+    pub vendor_url: String,
+    /// URL TorBox posts account events to. Optional.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub vendor_webhook: Option<String>,
+}
+
+impl VendorRegisterBody {
+    pub fn new(vendor_name: impl Into<String>, vendor_url: impl Into<String>) -> Self {
+        Self {
+            vendor_name: vendor_name.into(),
+            vendor_url: vendor_url.into(),
+            vendor_webhook: None,
+        }
+    }
+
+    pub fn with_webhook(mut self, webhook: impl Into<String>) -> Self {
+        self.vendor_webhook = Some(webhook.into());
+        self
+    }
+
+    /// Checks the name and URLs before the request is sent.
+    pub fn validate(&self) -> Result<(), ApiError> {
+        validate_name("vendor_name", &self.vendor_name)?;
+        validate_url("vendor_url", &self.vendor_url)?;
+        if let Some(webhook) = &self.vendor_webhook {
+            validate_url("vendor_webhook", webhook)?;
+        }
+        Ok(())
+    }
+}
+
+/// Request body of [`update_account`](crate::VendorApi::update_account).
+///
+/// Fields left to `None` are not sent and keep their previous value.
+#[derive(Debug, Serialize, Default, Clone)]
+#[cfg_attr(feature = "specta", derive(specta::Type))]
+pub struct VendorUpdateBody {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub vendor_url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub vendor_webhook: Option<String>,
+}
+
+impl VendorUpdateBody {
+    /// Checks the URLs before the request is sent.
+    pub fn validate(&self) -> Result<(), ApiError> {
+        if let Some(url) = &self.vendor_url {
+            validate_url("vendor_url", url)?;
+        }
+        if let Some(webhook) = &self.vendor_webhook {
+            validate_url("vendor_webhook", webhook)?;
+        }
+        Ok(())
+    }
+}
+
+/// Request body of [`register_user`](crate::VendorApi::register_user).
+#[derive(Debug, Serialize, Clone)]
+#[cfg_attr(feature = "specta", derive(specta::Type))]
+pub struct VendorUserRegisterBody {
+    /// Email of the account to create, it must not be registered on TorBox yet.
+    pub user_email: String,
+}
+
+impl VendorUserRegisterBody {
+    /// Checks the email before the request is sent.
+    pub fn validate(&self) -> Result<(), ApiError> {
+        let valid = self
+            .user_email
+            .split_once('@')
+            .is_some_and(|(user, domain)| !user.is_empty() && domain.contains('.'));
+
+        if valid {
+            Ok(())
+        } else {
+            Err(ApiError::InvalidInput(format!(
+                "{:?} is not an email address",
+                self.user_email
+            )))
+        }
+    }
+}
+
+/// Request body of [`remove_user`](crate::VendorApi::remove_user) and
+/// [`refresh_user_token`](crate::VendorApi::refresh_user_token).
+#[derive(Debug, Serialize, Clone)]
+#[cfg_attr(feature = "specta", derive(specta::Type))]
+pub struct VendorUserBody {
+    /// The `auth_id` of the vendor account.
+    pub user_auth_id: String,
+}
@@ -0,0 +1,82 @@
+use reqwest::Method;
+use torbox_core_rs::{
+    client::EndpointSpec,
+    data::vendor::{VendorAccount, VendorUser, VendorUserToken},
+};
+
+use crate::{
+    body::{VendorRegisterBody, VendorUpdateBody, VendorUserBody, VendorUserRegisterBody},
+    query::VendorUserQuery,
+};
+
+pub struct VendorRegisterPostEp;
+
+impl EndpointSpec for VendorRegisterPostEp {
+    type Req = VendorRegisterBody;
+    type Resp = VendorAccount;
+    const PATH: &'static str = "api/vendors/register";
+    const METHOD: Method = Method::POST;
+}
+
+pub struct VendorAccountGetEp;
+
+impl EndpointSpec for VendorAccountGetEp {
+    type Req = ();
+    type Resp = VendorAccount;
+    const PATH: &'static str = "api/vendors/account";
+    const METHOD: Method = Method::GET;
+}
+
+pub struct VendorUpdatePutEp;
+
+impl EndpointSpec for VendorUpdatePutEp {
+    type Req = VendorUpdateBody;
+    type Resp = ();
+    const PATH: &'static str = "api/vendors/updateaccount";
+    const METHOD: Method = Method::PUT;
+}
+
+pub struct VendorUsersGetEp;
+
+impl EndpointSpec for VendorUsersGetEp {
+    type Req = ();
+    type Resp = Vec<VendorUser>;
+    const PATH: &'static str = "api/vendors/getaccounts";
+    const METHOD: Method = Method::GET;
+}
+
+pub struct VendorUserGetEp;
+
+impl EndpointSpec for VendorUserGetEp {
+    type Req = VendorUserQuery;
+    type Resp = VendorUser;
+    const PATH: &'static str = "api/vendors/getaccount";
+    const METHOD: Method = Method::GET;
+}
+
+pub struct VendorUserRegisterPostEp;
+
+impl EndpointSpec for VendorUserRegisterPostEp {
+    type Req = VendorUserRegisterBody;
+    type Resp = VendorUserToken;
+    const PATH: &'static str = "api/vendors/registeruser";
+    const METHOD: Method = Method::POST;
+}
+
+pub struct VendorUserRemoveDeleteEp;
+
+impl EndpointSpec for VendorUserRemoveDeleteEp {
+    type Req = VendorUserBody;
+    type Resp = ();
+    const PATH: &'static str = "api/vendors/removeuser";
+    const METHOD: Method = Method::DELETE;
+}
+
+pub struct VendorUserRefreshPostEp;
+
+impl EndpointSpec for VendorUserRefreshPostEp {
+    type Req = VendorUserBody;
+    type Resp = VendorUserToken;
+    const PATH: &'static str = "api/vendors/refresh";
+    const METHOD: Method = Method::POST;
+}
@@ -0,0 +1,127 @@
+pub mod body;
+pub mod endpoint;
+pub mod query;
+pub mod tests;
+
+use torbox_core_rs::{
+    api::ApiResponse,
+    client::{Endpoint, TorboxClient},
+    data::vendor::{VendorAccount, VendorUser, VendorUserToken},
+    error::ApiError,
+};
+
+use crate::{
+    body::{VendorRegisterBody, VendorUpdateBody, VendorUserBody, VendorUserRegisterBody},
+    endpoint::{
+        VendorAccountGetEp, VendorRegisterPostEp, VendorUpdatePutEp, VendorUserGetEp,
+        VendorUserRefreshPostEp, VendorUserRegisterPostEp, VendorUserRemoveDeleteEp,
+        VendorUsersGetEp,
+    },
+    query::VendorUserQuery,
+};
+
+/// Main interface for TorBox vendors (resellers)
+///
+/// Provides methods for all vendor-related API calls including:
+/// - Registering the vendor account and updating its URLs
+/// - Reading its usage, see [`VendorAccount`]
+/// - Creating, listing and removing the accounts of its users, and refreshing their tokens
+///
+/// Every call fails with `VENDOR_DISABLED` once the vendor account is disabled.
+#[cfg_attr(feature = "specta", derive(specta::Type))]
+pub struct VendorApi<'a> {
+    client: &'a TorboxClient,
+}
+
+impl<'a> VendorApi<'a> {
+    pub fn new(client: &'a TorboxClient) -> Self {
+        Self { client }
+    }
+
+    /// Turns the user into a vendor.
+    ///
+    /// An invalid name or URL returns `ApiError::InvalidInput` without sending the request.
+    pub async fn register(
+        &self,
+        body: VendorRegisterBody,
+    ) -> Result<ApiResponse<VendorAccount>, ApiError> {
+        body.validate()?;
+
+        Endpoint::<VendorRegisterPostEp>::new(self.client)
+            .call_json(body)
+            .await
+    }
+
+    /// Gets the vendor account, with the number of accounts used and the traffic of its users.
+    pub async fn account(&self) -> Result<ApiResponse<VendorAccount>, ApiError> {
+        Endpoint::<VendorAccountGetEp>::new(self.client)
+            .call_query(())
+            .await
+    }
+
+    /// Changes the URL or webhook of the vendor account.
+    pub async fn update_account(
+        &self,
+        body: VendorUpdateBody,
+    ) -> Result<ApiResponse<()>, ApiError> {
+        body.validate()?;
+
+        Endpoint::<VendorUpdatePutEp>::new(self.client)
+            .call_json(body)
+            .await
+    }
+
+    /// Gets every account registered by the vendor.
+    pub async fn list_users(&self) -> Result<ApiResponse<Vec<VendorUser>>, ApiError> {
+        Endpoint::<VendorUsersGetEp>::new(self.client)
+            .call_query(())
+            .await
+    }
+
+    /// Gets a single account registered by the vendor.
+    pub async fn get_user(&self, auth_id: &str) -> Result<ApiResponse<VendorUser>, ApiError> {
+        Endpoint::<VendorUserGetEp>::new(self.client)
+            .call_query(VendorUserQuery {
+                user_auth_id: auth_id.to_string(),
+            })
+            .await
+    }
+
+    /// Creates an account for `email` and returns its API token.
+    ///
+    /// Fails with `PLAN_RESTRICTED_FEATURE` once `VendorAccount::users_allowed` is reached.
+    pub async fn register_user(
+        &self,
+        email: impl Into<String>,
+    ) -> Result<ApiResponse<VendorUserToken>, ApiError> {
+        let body = VendorUserRegisterBody {
+            user_email: email.into(),
+        };
+        body.validate()?;
+
+        Endpoint::<VendorUserRegisterPostEp>::new(self.client)
+            .call_json(body)
+            .await
+    }
+
+    /// Removes an account registered by the vendor, freeing its slot.
+    pub async fn remove_user(&self, auth_id: &str) -> Result<ApiResponse<()>, ApiError> {
+        Endpoint::<VendorUserRemoveDeleteEp>::new(self.client)
+            .call_json(VendorUserBody {
+                user_auth_id: auth_id.to_string(),
+            })
+            .await
+    }
+
+    /// Replaces the API token of an account registered by the vendor, the previous one stops working.
+    pub async fn refresh_user_token(
+        &self,
+        auth_id: &str,
+    ) -> Result<ApiResponse<VendorUserToken>, ApiError> {
+        Endpoint::<VendorUserRefreshPostEp>::new(self.client)
+            .call_json(VendorUserBody {
+                user_auth_id: auth_id.to_string(),
+            })
+            .await
+    }
+}
@@ -0,0 +1,8 @@
+use serde::Serialize;
+
+#[derive(Debug, Serialize)]
+#[cfg_attr(feature = "specta", derive(specta::Type))]
+pub struct VendorUserQuery {
+    /// The `auth_id` of the vendor account you want to get.
+    pub user_auth_id: String,
+}
@@ -0,0 +1,51 @@
+use torbox_core_rs::error::ApiError;
+
+use crate::body::{VendorRegisterBody, VendorUpdateBody, VendorUserRegisterBody};
+
+#[test]
+fn test_register_body_validation() {
+    let body = VendorRegisterBody::new("My Service", "https://example.com")
+        .with_webhook("https://example.com/hooks/torbox");
+    assert!(body.validate().is_ok());
+
+    let cases = [
+        VendorRegisterBody::new("", "https://example.com"),
+        VendorRegisterBody::new("My/Service", "https://example.com"),
+        VendorRegisterBody::new("My Service", "example.com"),
+        VendorRegisterBody::new("My Service", "https://example.com").with_webhook("ftp://x"),
+    ];
+    for body in cases {
+        assert!(
+            matches!(body.validate(), Err(ApiError::InvalidInput(_))),
+            "{body:?} was accepted"
+        );
+    }
+}
+
+#[test]
+fn test_update_body_validation() {
+    assert!(VendorUpdateBody::default().validate().is_ok());
+
+    let body = VendorUpdateBody {
+        vendor_webhook: Some("hooks.example.com".into()),
+        ..Default::default()
+    };
+    assert!(body.validate().is_err());
+}
+
+#[test]
+fn test_user_email_validation() {
+    for email in ["user@example.com", "first.last+tag@mail.example.org"] {
+        let body = VendorUserRegisterBody {
+            user_email: email.into(),
+        };
+        assert!(body.validate().is_ok(), "{email:?} was rejected");
+    }
+
+    for email in ["", "user", "@example.com", "user@localhost"] {
+        let body = VendorUserRegisterBody {
+            user_email: email.into(),
+        };
+        assert!(body.validate().is_err(), "{email:?} was accepted");
+    }
+}
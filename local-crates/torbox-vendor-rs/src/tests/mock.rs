@@ -0,0 +1,130 @@
+use torbox_core_rs::error::{ApiError, ApiErrorCode};
+use torbox_mock::MockServer;
+
+use crate::{
+    VendorApi,
+    body::{VendorRegisterBody, VendorUpdateBody},
+};
+
+async fn registered(server: &MockServer) {
+    let client = server.client().with_strict_errors(true);
+    VendorApi::new(&client)
+        .register(VendorRegisterBody::new(
+            "Reseller",
+            "https://reseller.example.com",
+        ))
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn test_register_and_update_vendor() {
+    let server = MockServer::start().await;
+    let client = server.client().with_strict_errors(true);
+    let api = VendorApi::new(&client);
+
+    let account = api
+        .register(VendorRegisterBody::new(
+            "Reseller",
+            "https://reseller.example.com",
+        ))
+        .await
+        .unwrap()
+        .into_data()
+        .unwrap();
+    assert_eq!(account.vendor_name, "Reseller");
+    assert_eq!(account.status, "active");
+    assert_eq!(server.state().vendor().unwrap()["id"], account.id);
+
+    let err = api
+        .register(VendorRegisterBody::new(
+            "Again",
+            "https://reseller.example.com",
+        ))
+        .await
+        .unwrap_err();
+    assert_eq!(err.code(), Some(&ApiErrorCode::DuplicateItem));
+
+    api.update_account(VendorUpdateBody {
+        vendor_webhook: Some("https://reseller.example.com/hook".into()),
+        ..Default::default()
+    })
+    .await
+    .unwrap();
+    let account = api.account().await.unwrap().into_data().unwrap();
+    assert_eq!(
+        account.vendor_webhook.as_deref(),
+        Some("https://reseller.example.com/hook")
+    );
+}
+
+#[tokio::test]
+async fn test_manage_vendor_users() {
+    let server = MockServer::start().await;
+    registered(&server).await;
+    let client = server.client().with_strict_errors(true);
+    let api = VendorApi::new(&client);
+
+    let first = api
+        .register_user("first@example.com")
+        .await
+        .unwrap()
+        .into_data()
+        .unwrap();
+    api.register_user("second@example.com").await.unwrap();
+
+    let account = api.account().await.unwrap().into_data().unwrap();
+    assert_eq!(account.current_users, 2);
+    assert_eq!(account.remaining_users(), 0);
+
+    let err = api.register_user("third@example.com").await.unwrap_err();
+    assert_eq!(err.code(), Some(&ApiErrorCode::PlanRestrictedFeature));
+
+    let user = api
+        .get_user(&first.auth_id)
+        .await
+        .unwrap()
+        .into_data()
+        .unwrap();
+    assert_eq!(user.email, "first@example.com");
+
+    let refreshed = api
+        .refresh_user_token(&first.auth_id)
+        .await
+        .unwrap()
+        .into_data()
+        .unwrap();
+    assert_eq!(refreshed.auth_id, first.auth_id);
+    assert_ne!(refreshed.token, first.token);
+
+    api.remove_user(&first.auth_id).await.unwrap();
+    let users = api.list_users().await.unwrap().into_data().unwrap();
+    assert_eq!(users.len(), 1);
+    assert_eq!(users[0].email, "second@example.com");
+
+    let err = api.remove_user(&first.auth_id).await.unwrap_err();
+    assert_eq!(err.code(), Some(&ApiErrorCode::ItemNotFound));
+}
+
+#[tokio::test]
+async fn test_disabled_vendor() {
+    let server = MockServer::start().await;
+    registered(&server).await;
+    server.state().disable_vendor();
+    let client = server.client().with_strict_errors(true);
+    let api = VendorApi::new(&client);
+
+    let err = api.list_users().await.unwrap_err();
+    assert_eq!(err.code(), Some(&ApiErrorCode::VendorDisabled));
+}
+
+#[tokio::test]
+async fn test_invalid_email_is_not_sent() {
+    let server = MockServer::start().await;
+    let client = server.client().with_strict_errors(true);
+    let api = VendorApi::new(&client);
+
+    let err = api.register_user("not-an-email").await.unwrap_err();
+    assert!(matches!(err, ApiError::InvalidInput(_)));
+    assert_eq!(server.state().request_count("api/vendors/registeruser"), 0);
+}
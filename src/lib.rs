@@ -1,18 +1,45 @@
 pub use torbox_core_rs;
 pub use torbox_ddl_rs;
 pub use torbox_torrent_rs;
+pub use torbox_usenet_rs;
 pub use torbox_user_rs;
 pub use torbox_general_rs;
 pub use torbox_notifications_rs;
+pub use torbox_search_rs;
+pub use torbox_rss_rs;
+pub use torbox_integrations_rs;
+pub use torbox_queued_rs;
+pub use torbox_stream_rs;
+pub use torbox_vendor_rs;
 
 
 #[doc(inline)]
 pub use torbox_core_rs::client::TorboxClient;
 #[doc(inline)]
+pub use torbox_core_rs::builder::TorboxClientBuilder;
+#[doc(inline)]
+pub use torbox_core_rs::download::Downloader;
+#[doc(inline)]
+pub use torbox_core_rs::link::LinkBuilder;
+#[doc(inline)]
 pub use torbox_torrent_rs::TorrentApi;
 #[doc(inline)]
+pub use torbox_usenet_rs::UsenetApi;
+#[doc(inline)]
 pub use torbox_general_rs::GeneralApi;
 #[doc(inline)]
 pub use torbox_user_rs::UserApi;
 #[doc(inline)]
-pub use torbox_notifications_rs::NotificationApi;
\ No newline at end of file
+pub use torbox_notifications_rs::NotificationApi;
+#[doc(inline)]
+pub use torbox_search_rs::SearchApi;
+#[doc(inline)]
+pub use torbox_rss_rs::RssApi;
+#[doc(inline)]
+pub use torbox_integrations_rs::IntegrationsApi;
+#[doc(inline)]
+pub use torbox_queued_rs::QueuedApi;
+#[doc(inline)]
+pub use torbox_stream_rs::StreamApi;
+#[doc(inline)]
+pub use torbox_vendor_rs::VendorApi;